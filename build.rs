@@ -0,0 +1,16 @@
+use std::path::PathBuf;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-changed=migrations");
+
+    let mut config = tonic_prost_build::Config::new();
+    config.protoc_executable(protoc_bin_vendored::protoc_bin_path()?);
+
+    tonic_prost_build::configure().compile_with_config(
+        config,
+        &[PathBuf::from("proto/user_service.proto")],
+        &[PathBuf::from("proto"), protoc_bin_vendored::include_path()?],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_users_on_email_bidx;
+
+ALTER TABLE users DROP COLUMN IF EXISTS email_bidx;
@@ -0,0 +1,3 @@
+ALTER TABLE users ADD COLUMN IF NOT EXISTS email_bidx VARCHAR;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_users_on_email_bidx ON users (email_bidx);
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS user_erasures (
+  id SERIAL PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users (id),
+  requested_by VARCHAR NOT NULL,
+  erased_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_erasures_on_user_id ON user_erasures (user_id);
@@ -0,0 +1,3 @@
+DROP TRIGGER IF EXISTS user_audit_log_append_only ON user_audit_log;
+DROP FUNCTION IF EXISTS reject_user_audit_log_changes();
+DROP TABLE IF EXISTS user_audit_log;
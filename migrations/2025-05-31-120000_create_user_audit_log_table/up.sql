@@ -0,0 +1,21 @@
+CREATE TABLE IF NOT EXISTS user_audit_log (
+  id SERIAL PRIMARY KEY,
+  user_id INTEGER NOT NULL REFERENCES users (id),
+  actor VARCHAR NOT NULL,
+  action VARCHAR NOT NULL,
+  request_id VARCHAR,
+  changes JSONB NOT NULL DEFAULT '{}',
+  recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_audit_log_on_user_id ON user_audit_log (user_id, recorded_at);
+
+CREATE OR REPLACE FUNCTION reject_user_audit_log_changes() RETURNS TRIGGER AS $$
+BEGIN
+  RAISE EXCEPTION 'user_audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER user_audit_log_append_only
+  BEFORE UPDATE OR DELETE ON user_audit_log
+  FOR EACH ROW EXECUTE FUNCTION reject_user_audit_log_changes();
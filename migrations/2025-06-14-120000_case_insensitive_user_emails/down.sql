@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_users_on_lower_email;
+CREATE INDEX IF NOT EXISTS idx_users_on_email ON users (email);
@@ -0,0 +1,25 @@
+DO $$
+DECLARE
+  collisions TEXT;
+BEGIN
+  SELECT string_agg(format('(%s)', ids), ', ')
+  INTO collisions
+  FROM (
+    SELECT string_agg(id::text, ', ' ORDER BY id) AS ids
+    FROM users
+    WHERE email NOT LIKE 'enc:v1:%'
+    GROUP BY lower(email) HAVING count(*) > 1
+  ) AS colliding;
+
+  IF collisions IS NOT NULL THEN
+    RAISE EXCEPTION 'users has emails that only differ by case'
+      USING DETAIL = format('Colliding user ids: %s', collisions),
+            HINT = 'List them with SELECT lower(email), array_agg(id ORDER BY id) FROM users '
+              'GROUP BY lower(email) HAVING count(*) > 1; then change or erase all but one '
+              'user of each group (e.g. user-admin merge <survivor_id> <duplicate_id>) and re-run the migration';
+  END IF;
+END;
+$$;
+
+DROP INDEX IF EXISTS idx_users_on_email;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_users_on_lower_email ON users (lower(email));
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_users_on_email_local_part_trgm;
+DROP INDEX IF EXISTS idx_users_on_name_trgm;
+DROP INDEX IF EXISTS idx_users_on_name_tsvector;
+
+DROP EXTENSION IF EXISTS pg_trgm;
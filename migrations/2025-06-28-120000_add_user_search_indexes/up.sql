@@ -0,0 +1,5 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_users_on_name_tsvector ON users USING GIN (to_tsvector('simple', name));
+CREATE INDEX IF NOT EXISTS idx_users_on_name_trgm ON users USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_users_on_email_local_part_trgm ON users USING GIN (split_part(lower(email), '@', 1) gin_trgm_ops);
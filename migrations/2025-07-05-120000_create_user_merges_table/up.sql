@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS user_merges (
+  id SERIAL PRIMARY KEY,
+  survivor_id INTEGER NOT NULL REFERENCES users (id),
+  merged_id INTEGER NOT NULL UNIQUE REFERENCES users (id),
+  requested_by VARCHAR NOT NULL,
+  merged_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_merges_on_survivor_id ON user_merges (survivor_id);
@@ -0,0 +1 @@
+-- The masked names cannot be restored.
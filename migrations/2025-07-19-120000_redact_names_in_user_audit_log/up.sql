@@ -0,0 +1,16 @@
+-- Names used to be audited in clear text. The log is append-only, so lift the
+-- trigger just long enough to mask the entries written before that changed.
+ALTER TABLE user_audit_log DISABLE TRIGGER user_audit_log_append_only;
+
+UPDATE user_audit_log
+SET changes = jsonb_set(
+  changes,
+  '{name}',
+  jsonb_build_object(
+    'before', CASE WHEN changes #>> '{name,before}' IS NULL THEN 'null'::jsonb ELSE '"[redacted]"'::jsonb END,
+    'after', CASE WHEN changes #>> '{name,after}' IS NULL THEN 'null'::jsonb ELSE '"[redacted]"'::jsonb END
+  )
+)
+WHERE changes ? 'name';
+
+ALTER TABLE user_audit_log ENABLE TRIGGER user_audit_log_append_only;
@@ -0,0 +1,55 @@
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+
+const API_KEY_PREFIX: &str = "ak_";
+const API_KEY_VISIBLE_CHARS: usize = 8;
+
+pub struct ApiKeySecret {
+    pub key: String,
+    pub key_prefix: String,
+    pub key_hash: String,
+}
+
+impl ApiKeySecret {
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+
+        let key = format!("{API_KEY_PREFIX}{}", hex::encode(bytes));
+
+        Self {
+            key_prefix: key[..API_KEY_PREFIX.len() + API_KEY_VISIBLE_CHARS].to_string(),
+            key_hash: hash_api_key(&key),
+            key,
+        }
+    }
+}
+
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ApiKeySecret, hash_api_key};
+
+    #[test]
+    fn generate() {
+        let secret = ApiKeySecret::generate();
+
+        assert!(secret.key.starts_with("ak_"));
+        assert_eq!(secret.key.len(), 67);
+        assert!(secret.key.starts_with(&secret.key_prefix));
+        assert_eq!(secret.key_prefix.len(), 11);
+        assert_eq!(secret.key_hash, hash_api_key(&secret.key));
+        assert_ne!(secret.key, ApiKeySecret::generate().key);
+    }
+
+    #[test]
+    fn hash_api_key_is_sha256_hex() {
+        assert_eq!(
+            hash_api_key("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
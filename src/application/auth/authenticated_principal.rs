@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatedPrincipal {
+    pub subject: String,
+    pub email: Option<String>,
+    pub roles: Vec<String>,
+    pub scopes: Vec<String>,
+}
+
+impl AuthenticatedPrincipal {
+    pub fn new(subject: String, email: Option<String>, roles: Vec<String>) -> Self {
+        Self {
+            subject,
+            email,
+            roles,
+            scopes: Vec::new(),
+        }
+    }
+
+    pub fn with_scopes(mut self, scopes: Vec<String>) -> Self {
+        self.scopes = scopes;
+        self
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|granted| granted == scope)
+    }
+}
@@ -0,0 +1,289 @@
+use std::str::FromStr;
+
+use crate::{
+    application::{
+        auth::authenticated_principal::AuthenticatedPrincipal,
+        errors::authorization_error::AuthorizationError,
+    },
+    domain::{
+        entities::user::User, services::email_normalizer::normalize_email, value_objects::id::ID,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Role {
+    Admin,
+    Support,
+    SelfUser,
+}
+
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "admin" => Ok(Role::Admin),
+            "support" => Ok(Role::Support),
+            "self" => Ok(Role::SelfUser),
+            _ => Err(format!("Unknown role: {s}")),
+        }
+    }
+}
+
+pub const SCOPES: &[&str] = &["users:read", "users:write", "users:export"];
+
+#[derive(Debug, PartialEq)]
+pub struct Policy {
+    pub roles: &'static [Role],
+    pub scope: Option<&'static str>,
+}
+
+pub const READ_USER: Policy = Policy {
+    roles: &[Role::Admin, Role::SelfUser],
+    scope: Some("users:read"),
+};
+
+pub const WRITE_USER: Policy = Policy {
+    roles: &[Role::Admin, Role::Support],
+    scope: Some("users:write"),
+};
+
+pub const UPDATE_USER: Policy = Policy {
+    roles: &[Role::Admin, Role::Support, Role::SelfUser],
+    scope: Some("users:write"),
+};
+
+pub const SUSPEND_USER: Policy = Policy {
+    roles: &[Role::Admin, Role::Support],
+    scope: Some("users:write"),
+};
+
+pub const CHANGE_EMAIL: Policy = Policy {
+    roles: &[Role::Admin, Role::SelfUser],
+    scope: None,
+};
+
+pub const LIST_USERS: Policy = Policy {
+    roles: &[Role::Admin, Role::Support],
+    scope: Some("users:read"),
+};
+
+pub const SEARCH_USERS: Policy = Policy {
+    roles: &[Role::Admin, Role::Support],
+    scope: Some("users:read"),
+};
+
+pub const EXPORT_USERS: Policy = Policy {
+    roles: &[Role::Admin],
+    scope: Some("users:export"),
+};
+
+pub const EXPORT_USER: Policy = Policy {
+    roles: &[Role::Admin, Role::SelfUser],
+    scope: None,
+};
+
+pub const ERASE_USER: Policy = Policy {
+    roles: &[Role::Admin, Role::SelfUser],
+    scope: None,
+};
+
+pub const READ_USER_HISTORY: Policy = Policy {
+    roles: &[Role::Admin, Role::Support],
+    scope: None,
+};
+
+pub const REPORT_USER_DUPLICATES: Policy = Policy {
+    roles: &[Role::Admin],
+    scope: None,
+};
+
+pub const MERGE_USERS: Policy = Policy {
+    roles: &[Role::Admin],
+    scope: None,
+};
+
+pub const MANAGE_API_KEYS: Policy = Policy {
+    roles: &[Role::Admin],
+    scope: None,
+};
+
+impl AuthenticatedPrincipal {
+    pub fn roles_for(&self, target: Option<&User>) -> Vec<Role> {
+        let mut roles: Vec<Role> = self
+            .roles
+            .iter()
+            .filter_map(|role| role.parse().ok())
+            .filter(|role| *role != Role::SelfUser)
+            .collect();
+
+        // A suspended user keeps no self-service access to their own account.
+        if target.is_some_and(|user| !user.is_suspended() && self.owns(user)) {
+            roles.push(Role::SelfUser);
+        }
+
+        roles
+    }
+
+    fn owns(&self, user: &User) -> bool {
+        let owns_id = match user.id {
+            ID::Existing(user_id) => self.subject == user_id.to_string(),
+            ID::New => false,
+        };
+
+        owns_id
+            || self
+                .email
+                .as_deref()
+                .is_some_and(|email| normalize_email(email) == normalize_email(&user.email))
+    }
+}
+
+pub fn authorize(
+    principal: &AuthenticatedPrincipal,
+    policy: &Policy,
+    target: Option<&User>,
+) -> Result<(), AuthorizationError> {
+    let has_role = principal
+        .roles_for(target)
+        .iter()
+        .any(|role| policy.roles.contains(role));
+
+    let has_scope = policy.scope.is_some_and(|scope| principal.has_scope(scope));
+
+    if has_role || has_scope {
+        return Ok(());
+    }
+
+    Err(AuthorizationError::Forbidden(match policy.scope {
+        Some(scope) => format!(
+            "The principal {} requires one of the roles {:?} or the scope {scope}",
+            principal.subject, policy.roles
+        ),
+        None => format!(
+            "The principal {} requires one of the roles {:?}",
+            principal.subject, policy.roles
+        ),
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::authorization_error::AuthorizationError,
+        },
+        domain::entities::user::User,
+    };
+
+    use super::{MANAGE_API_KEYS, READ_USER, Role, WRITE_USER, authorize};
+
+    fn principal(subject: &str, email: Option<&str>, roles: &[&str]) -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new(
+            subject.to_string(),
+            email.map(str::to_string),
+            roles.iter().map(|role| role.to_string()).collect(),
+        )
+    }
+
+    fn user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn role_from_str() {
+        assert_eq!("admin".parse(), Ok(Role::Admin));
+        assert_eq!("support".parse(), Ok(Role::Support));
+        assert_eq!("self".parse(), Ok(Role::SelfUser));
+        assert_eq!(
+            "root".parse::<Role>(),
+            Err("Unknown role: root".to_string())
+        );
+    }
+
+    #[test]
+    fn roles_for_ignores_unknown_and_claimed_self_roles() {
+        let sut = principal("7", None, &["admin", "self", "root"]);
+
+        assert_eq!(sut.roles_for(Some(&user())), vec![Role::Admin]);
+    }
+
+    #[test]
+    fn roles_for_owner_by_subject() {
+        let sut = principal("42", None, &[]);
+
+        assert_eq!(sut.roles_for(Some(&user())), vec![Role::SelfUser]);
+    }
+
+    #[test]
+    fn roles_for_owner_by_email() {
+        let sut = principal("someone", Some("Andrew@Email.com"), &["support"]);
+
+        assert_eq!(
+            sut.roles_for(Some(&user())),
+            vec![Role::Support, Role::SelfUser]
+        );
+    }
+
+    #[test]
+    fn roles_for_suspended_owner() {
+        let sut = principal("42", None, &["support"]);
+        let mut suspended = user();
+        suspended.suspend(Utc::now());
+
+        assert_eq!(sut.roles_for(Some(&suspended)), vec![Role::Support]);
+    }
+
+    #[test]
+    fn authorize_ok_by_role() {
+        let sut = principal("1", None, &["support"]);
+
+        assert_eq!(authorize(&sut, &WRITE_USER, None), Ok(()));
+    }
+
+    #[test]
+    fn authorize_ok_by_scope() {
+        let sut = principal("api-key:3", None, &[]).with_scopes(vec!["users:read".to_string()]);
+
+        assert_eq!(authorize(&sut, &READ_USER, Some(&user())), Ok(()));
+    }
+
+    #[test]
+    fn authorize_forbidden() {
+        let sut = principal("1", None, &["support"]);
+
+        let result = authorize(&sut, &READ_USER, Some(&user()));
+
+        assert_eq!(
+            result,
+            Err(AuthorizationError::Forbidden(
+                "The principal 1 requires one of the roles [Admin, SelfUser] or the scope users:read"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn authorize_forbidden_without_scope_fallback() {
+        let sut = principal("1", None, &[]).with_scopes(vec!["users:write".to_string()]);
+
+        let result = authorize(&sut, &MANAGE_API_KEYS, None);
+
+        assert_eq!(
+            result,
+            Err(AuthorizationError::Forbidden(
+                "The principal 1 requires one of the roles [Admin]".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+
+pub struct EmailChangeToken {
+    pub token: String,
+    pub token_hash: String,
+}
+
+impl EmailChangeToken {
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+
+        let token = hex::encode(bytes);
+
+        Self {
+            token_hash: hash_email_change_token(&token),
+            token,
+        }
+    }
+}
+
+pub fn hash_email_change_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EmailChangeToken, hash_email_change_token};
+
+    #[test]
+    fn generate() {
+        let token = EmailChangeToken::generate();
+
+        assert_eq!(token.token.len(), 64);
+        assert_eq!(token.token_hash, hash_email_change_token(&token.token));
+        assert_ne!(token.token, EmailChangeToken::generate().token);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod api_key_secret;
+pub mod authenticated_principal;
+pub mod authorization;
+pub mod email_change_token;
+pub mod request_context;
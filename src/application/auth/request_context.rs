@@ -0,0 +1,79 @@
+use std::future::Future;
+
+const SYSTEM_ACTOR: &str = "system";
+const ANONYMOUS_ACTOR: &str = "anonymous";
+
+tokio::task_local! {
+    static REQUEST_CONTEXT: RequestContext;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestContext {
+    pub actor: String,
+    pub request_id: Option<String>,
+}
+
+impl RequestContext {
+    pub fn new(actor: String, request_id: Option<String>) -> Self {
+        Self { actor, request_id }
+    }
+
+    pub fn anonymous(request_id: String) -> Self {
+        Self::new(ANONYMOUS_ACTOR.to_string(), Some(request_id))
+    }
+
+    pub async fn scope<F: Future>(self, f: F) -> F::Output {
+        REQUEST_CONTEXT.scope(self, f).await
+    }
+
+    pub fn current() -> Self {
+        REQUEST_CONTEXT
+            .try_with(Clone::clone)
+            .unwrap_or_else(|_| Self::new(SYSTEM_ACTOR.to_string(), None))
+    }
+
+    pub fn annotate(message: String) -> String {
+        match Self::current().request_id {
+            Some(request_id) => format!("{message} (request {request_id})"),
+            None => message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RequestContext;
+
+    #[tokio::test]
+    async fn current_outside_of_a_scope() {
+        assert_eq!(
+            RequestContext::current(),
+            RequestContext::new("system".to_string(), None)
+        );
+    }
+
+    #[tokio::test]
+    async fn current_inside_of_a_scope() {
+        let context = RequestContext::new("42".to_string(), Some("req-1".to_string()));
+
+        let result = context
+            .clone()
+            .scope(async { RequestContext::current() })
+            .await;
+
+        assert_eq!(result, context);
+    }
+
+    #[tokio::test]
+    async fn annotate_with_the_request_id() {
+        let result = RequestContext::anonymous("req-1".to_string())
+            .scope(async { RequestContext::annotate("Connection lost".to_string()) })
+            .await;
+
+        assert_eq!(result, "Connection lost (request req-1)");
+        assert_eq!(
+            RequestContext::annotate("Connection lost".to_string()),
+            "Connection lost"
+        );
+    }
+}
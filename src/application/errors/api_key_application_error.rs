@@ -0,0 +1,99 @@
+use std::fmt;
+
+use crate::{
+    application::errors::authorization_error::AuthorizationError,
+    domain::errors::api_key_repository_error::ApiKeyRepositoryError,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum ApiKeyApplicationError {
+    Invalid(String),
+    Forbidden(String),
+    NotFound(i32),
+    Unexpected(String),
+}
+
+impl fmt::Display for ApiKeyApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiKeyApplicationError::Invalid(msg) => {
+                write!(f, "The API key request is invalid: {msg}")
+            }
+            ApiKeyApplicationError::Forbidden(msg) => {
+                write!(f, "The operation is not allowed: {msg}")
+            }
+            ApiKeyApplicationError::NotFound(api_key_id) => {
+                write!(f, "No active API key was found by ID: {api_key_id}")
+            }
+            ApiKeyApplicationError::Unexpected(msg) => {
+                write!(f, "An unexpected error occurred: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApiKeyApplicationError {}
+
+impl From<ApiKeyRepositoryError> for ApiKeyApplicationError {
+    fn from(value: ApiKeyRepositoryError) -> Self {
+        match value {
+            ApiKeyRepositoryError::DatabaseError(err) => Self::Unexpected(err),
+        }
+    }
+}
+
+impl From<AuthorizationError> for ApiKeyApplicationError {
+    fn from(value: AuthorizationError) -> Self {
+        match value {
+            AuthorizationError::Forbidden(err) => Self::Forbidden(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        application::errors::{
+            api_key_application_error::ApiKeyApplicationError,
+            authorization_error::AuthorizationError,
+        },
+        domain::errors::api_key_repository_error::ApiKeyRepositoryError,
+    };
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ApiKeyApplicationError::Invalid("empty name".to_string()).to_string(),
+            "The API key request is invalid: empty name"
+        );
+        assert_eq!(
+            ApiKeyApplicationError::Forbidden("admin role required".to_string()).to_string(),
+            "The operation is not allowed: admin role required"
+        );
+        assert_eq!(
+            ApiKeyApplicationError::NotFound(42).to_string(),
+            "No active API key was found by ID: 42"
+        );
+        assert_eq!(
+            ApiKeyApplicationError::Unexpected("database error".to_string()).to_string(),
+            "An unexpected error occurred: database error"
+        );
+    }
+
+    #[test]
+    fn from_api_key_repository_error() {
+        let err_msg = "database error";
+        let err: ApiKeyApplicationError =
+            ApiKeyRepositoryError::DatabaseError(err_msg.to_string()).into();
+
+        assert_eq!(err, ApiKeyApplicationError::Unexpected(err_msg.to_string()));
+    }
+
+    #[test]
+    fn from_authorization_error() {
+        let err_msg = "admin role required";
+        let err: ApiKeyApplicationError = AuthorizationError::Forbidden(err_msg.to_string()).into();
+
+        assert_eq!(err, ApiKeyApplicationError::Forbidden(err_msg.to_string()));
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum AuthenticationError {
+    MissingCredentials,
+    InvalidCredentials(String),
+    Unexpected(String),
+}
+
+impl fmt::Display for AuthenticationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthenticationError::MissingCredentials => {
+                write!(f, "No credentials were given for the request")
+            }
+            AuthenticationError::InvalidCredentials(msg) => {
+                write!(f, "The given credentials are invalid: {msg}")
+            }
+            AuthenticationError::Unexpected(msg) => {
+                write!(f, "An unexpected error occurred when authenticating: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AuthenticationError {}
+
+#[cfg(test)]
+mod test {
+    use super::AuthenticationError;
+
+    #[test]
+    fn display_missing_credentials() {
+        let err = AuthenticationError::MissingCredentials.to_string();
+
+        assert_eq!(err, "No credentials were given for the request");
+    }
+
+    #[test]
+    fn display_invalid_credentials() {
+        let err_msg = "ExpiredSignature";
+        let err = AuthenticationError::InvalidCredentials(err_msg.to_string()).to_string();
+
+        assert_eq!(err, format!("The given credentials are invalid: {err_msg}"));
+    }
+
+    #[test]
+    fn display_unexpected() {
+        let err_msg = "Connection lost";
+        let err = AuthenticationError::Unexpected(err_msg.to_string()).to_string();
+
+        assert_eq!(
+            err,
+            format!("An unexpected error occurred when authenticating: {err_msg}")
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum AuthorizationError {
+    Forbidden(String),
+}
+
+impl fmt::Display for AuthorizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthorizationError::Forbidden(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for AuthorizationError {}
+
+#[cfg(test)]
+mod test {
+    use super::AuthorizationError;
+
+    #[test]
+    fn display() {
+        let err_msg = "admin role required";
+        let err = AuthorizationError::Forbidden(err_msg.to_string()).to_string();
+
+        assert_eq!(err, err_msg);
+    }
+}
@@ -1 +1,4 @@
+pub mod api_key_application_error;
+pub mod authentication_error;
+pub mod authorization_error;
 pub mod user_application_error;
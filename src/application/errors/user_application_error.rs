@@ -1,13 +1,33 @@
 use std::fmt;
 
-use crate::domain::errors::user_repository_error::UserRepositoryError;
+use crate::{
+    application::errors::authorization_error::AuthorizationError,
+    domain::errors::{
+        user_entity_error::UserEntityError, user_repository_error::UserRepositoryError,
+    },
+};
 
 #[derive(Debug, PartialEq)]
 pub enum UserApplicationError {
     Conflict(String),
+    Forbidden(String),
+    Invalid(String),
+    NotFound(String),
     Unexpected(String),
 }
 
+impl UserApplicationError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            UserApplicationError::Conflict(_) => "conflict",
+            UserApplicationError::Forbidden(_) => "forbidden",
+            UserApplicationError::Invalid(_) => "invalid",
+            UserApplicationError::NotFound(_) => "not_found",
+            UserApplicationError::Unexpected(_) => "unexpected",
+        }
+    }
+}
+
 impl fmt::Display for UserApplicationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -17,6 +37,15 @@ impl fmt::Display for UserApplicationError {
                     "The following conflict occurred when writing a user: {msg}"
                 )
             }
+            UserApplicationError::Forbidden(msg) => {
+                write!(f, "The operation is not allowed: {msg}")
+            }
+            UserApplicationError::Invalid(msg) => {
+                write!(f, "The user input is invalid: {msg}")
+            }
+            UserApplicationError::NotFound(msg) => {
+                write!(f, "The user was not found: {msg}")
+            }
             UserApplicationError::Unexpected(msg) => {
                 write!(f, "An unexpected error occurred: {msg}")
             }
@@ -29,16 +58,35 @@ impl std::error::Error for UserApplicationError {}
 impl From<UserRepositoryError> for UserApplicationError {
     fn from(value: UserRepositoryError) -> Self {
         match value {
+            UserRepositoryError::Conflict(err) => Self::Conflict(err),
             UserRepositoryError::DatabaseError(err) => Self::Unexpected(err),
         }
     }
 }
 
+impl From<UserEntityError> for UserApplicationError {
+    fn from(value: UserEntityError) -> Self {
+        Self::Invalid(value.to_string())
+    }
+}
+
+impl From<AuthorizationError> for UserApplicationError {
+    fn from(value: AuthorizationError) -> Self {
+        match value {
+            AuthorizationError::Forbidden(err) => Self::Forbidden(err),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
-        application::errors::user_application_error::UserApplicationError,
-        domain::errors::user_repository_error::UserRepositoryError,
+        application::errors::{
+            authorization_error::AuthorizationError, user_application_error::UserApplicationError,
+        },
+        domain::errors::{
+            user_entity_error::UserEntityError, user_repository_error::UserRepositoryError,
+        },
     };
 
     #[test]
@@ -53,6 +101,33 @@ mod test {
         );
     }
 
+    #[test]
+    fn user_application_error_forbidden_display() {
+        let err_msg = "admin role required";
+        let err = UserApplicationError::Forbidden(err_msg.to_string());
+        let err = err.to_string();
+
+        assert_eq!(err, "The operation is not allowed: ".to_owned() + err_msg);
+    }
+
+    #[test]
+    fn user_application_error_invalid_display() {
+        let err_msg = "the name is empty";
+        let err = UserApplicationError::Invalid(err_msg.to_string());
+        let err = err.to_string();
+
+        assert_eq!(err, "The user input is invalid: ".to_owned() + err_msg);
+    }
+
+    #[test]
+    fn user_application_error_not_found_display() {
+        let err_msg = "no user with ID 42";
+        let err = UserApplicationError::NotFound(err_msg.to_string());
+        let err = err.to_string();
+
+        assert_eq!(err, "The user was not found: ".to_owned() + err_msg);
+    }
+
     #[test]
     fn user_application_error_unexpected_display() {
         let err_msg = "database error";
@@ -70,4 +145,31 @@ mod test {
 
         assert_eq!(err, UserApplicationError::Unexpected(err_msg.to_string()));
     }
+
+    #[test]
+    fn user_application_error_from_user_repository_conflict() {
+        let err_msg = "duplicate email";
+        let repo_err = UserRepositoryError::Conflict(err_msg.to_string());
+        let err: UserApplicationError = repo_err.into();
+
+        assert_eq!(err, UserApplicationError::Conflict(err_msg.to_string()));
+    }
+
+    #[test]
+    fn user_application_error_from_authorization_error() {
+        let err_msg = "admin role required";
+        let err: UserApplicationError = AuthorizationError::Forbidden(err_msg.to_string()).into();
+
+        assert_eq!(err, UserApplicationError::Forbidden(err_msg.to_string()));
+    }
+
+    #[test]
+    fn user_application_error_from_user_entity_error() {
+        let err: UserApplicationError = UserEntityError::InvalidId(0).into();
+
+        assert_eq!(
+            err,
+            UserApplicationError::Invalid("An invalid ID was given for a user: 0".to_string())
+        );
+    }
 }
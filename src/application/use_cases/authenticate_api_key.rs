@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        auth::{api_key_secret::hash_api_key, authenticated_principal::AuthenticatedPrincipal},
+        errors::authentication_error::AuthenticationError,
+    },
+    domain::{
+        repositories::api_key_repository::ApiKeyRepository,
+        services::clock::{Clock, SystemClock},
+        value_objects::id::ID,
+    },
+};
+
+pub struct AuthenticateApiKeyUseCase<T: ApiKeyRepository> {
+    api_key_repo: T,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: ApiKeyRepository> AuthenticateApiKeyUseCase<T> {
+    pub fn new(api_key_repo: T) -> Self {
+        Self {
+            api_key_repo,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn execute(&self, key: &str) -> Result<AuthenticatedPrincipal, AuthenticationError> {
+        let api_key = self
+            .api_key_repo
+            .find_by_hash(&hash_api_key(key))
+            .await
+            .map_err(|err| AuthenticationError::Unexpected(err.to_string()))?
+            .ok_or_else(|| {
+                AuthenticationError::InvalidCredentials("Unknown API key".to_string())
+            })?;
+
+        if api_key.is_revoked() {
+            return Err(AuthenticationError::InvalidCredentials(
+                "The API key has been revoked".to_string(),
+            ));
+        }
+
+        let ID::Existing(api_key_id) = api_key.id else {
+            return Err(AuthenticationError::Unexpected(
+                "A stored API key has no ID".to_string(),
+            ));
+        };
+
+        self.api_key_repo
+            .touch_last_used(api_key_id, self.clock.now())
+            .await
+            .map_err(|err| AuthenticationError::Unexpected(err.to_string()))?;
+
+        Ok(
+            AuthenticatedPrincipal::new(format!("api-key:{api_key_id}"), None, Vec::new())
+                .with_scopes(api_key.scopes),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, TimeZone, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            auth::{api_key_secret::hash_api_key, authenticated_principal::AuthenticatedPrincipal},
+            errors::authentication_error::AuthenticationError,
+            use_cases::authenticate_api_key::AuthenticateApiKeyUseCase,
+        },
+        domain::{
+            entities::api_key::ApiKey, errors::api_key_repository_error::ApiKeyRepositoryError,
+            repositories::api_key_repository::MockApiKeyRepository, services::clock::MockClock,
+            value_objects::id::ID,
+        },
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap()
+    }
+
+    fn clock() -> Arc<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now());
+        Arc::new(clock)
+    }
+
+    const KEY: &str = "ak_0123abcdef";
+
+    fn stored_api_key() -> ApiKey {
+        let mut api_key = ApiKey::new(
+            "batch-jobs".to_string(),
+            "ak_0123abcd".to_string(),
+            hash_api_key(KEY),
+            vec!["users:read".to_string()],
+            Utc::now(),
+        );
+        api_key.id = ID::Existing(7);
+        api_key
+    }
+
+    #[tokio::test]
+    async fn execute_unknown_key() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo
+            .expect_find_by_hash()
+            .times(1)
+            .return_const(Ok(None));
+        mock_api_key_repo.expect_touch_last_used().times(0);
+
+        let sut = AuthenticateApiKeyUseCase::new(mock_api_key_repo);
+
+        let result = sut.execute(KEY).await;
+
+        assert_eq!(
+            result,
+            Err(AuthenticationError::InvalidCredentials(
+                "Unknown API key".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_revoked_key() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        let mut api_key = stored_api_key();
+        api_key.revoked_at = Some(Utc::now());
+
+        mock_api_key_repo
+            .expect_find_by_hash()
+            .times(1)
+            .return_const(Ok(Some(api_key)));
+        mock_api_key_repo.expect_touch_last_used().times(0);
+
+        let sut = AuthenticateApiKeyUseCase::new(mock_api_key_repo);
+
+        let result = sut.execute(KEY).await;
+
+        assert_eq!(
+            result,
+            Err(AuthenticationError::InvalidCredentials(
+                "The API key has been revoked".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_api_key_repository_error() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo
+            .expect_find_by_hash()
+            .times(1)
+            .return_const(Err(ApiKeyRepositoryError::DatabaseError(
+                "Fake Error".to_string(),
+            )));
+
+        let sut = AuthenticateApiKeyUseCase::new(mock_api_key_repo);
+
+        let result = sut.execute(KEY).await;
+
+        assert!(matches!(result, Err(AuthenticationError::Unexpected(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo
+            .expect_find_by_hash()
+            .withf(|key_hash: &str| key_hash == hash_api_key(KEY))
+            .times(1)
+            .return_const(Ok(Some(stored_api_key())));
+
+        mock_api_key_repo
+            .expect_touch_last_used()
+            .with(eq(7), eq(now()))
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = AuthenticateApiKeyUseCase::new(mock_api_key_repo).with_clock(clock());
+
+        let result = sut.execute(KEY).await?;
+
+        assert_eq!(
+            result,
+            AuthenticatedPrincipal::new("api-key:7".to_string(), None, vec![])
+                .with_scopes(vec!["users:read".to_string()])
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,593 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{CHANGE_EMAIL, authorize},
+            email_change_token::{EmailChangeToken, hash_email_change_token},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        entities::{pending_email_change::PendingEmailChange, user::User},
+        repositories::{
+            email_change_repository::EmailChangeRepository, user_repository::UserRepository,
+        },
+        services::{
+            clock::{Clock, SystemClock},
+            email_normalizer::normalize_email,
+            email_notifier::EmailNotifier,
+            pii_redactor::{PiiField, redact},
+        },
+        value_objects::id::ID,
+    },
+};
+
+const TOKEN_TTL_HOURS: i64 = 24;
+
+pub struct ChangeEmailUseCase<T: UserRepository, E: EmailChangeRepository, N: EmailNotifier> {
+    user_repo: T,
+    email_change_repo: E,
+    notifier: N,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: UserRepository, E: EmailChangeRepository, N: EmailNotifier> ChangeEmailUseCase<T, E, N> {
+    pub fn new(user_repo: T, email_change_repo: E, notifier: N) -> Self {
+        Self {
+            user_repo,
+            email_change_repo,
+            notifier,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    async fn find_user(&self, user_id: i32) -> Result<Option<User>, UserApplicationError> {
+        self.user_repo
+            .find_by_id(user_id)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    async fn ensure_email_is_free(&self, email: &str) -> Result<(), UserApplicationError> {
+        if self.user_repo.exists_by_email(email).await? {
+            return Err(UserApplicationError::Conflict(format!(
+                "The email {} is already taken",
+                redact(PiiField::Email, email)
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub async fn request(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        user_id: i32,
+        new_email: String,
+    ) -> Result<(), UserApplicationError> {
+        let user = self.find_user(user_id).await?;
+
+        authorize(principal, &CHANGE_EMAIL, user.as_ref())?;
+
+        let user = user.ok_or_else(|| {
+            UserApplicationError::NotFound(format!("No user exists with ID {user_id}"))
+        })?;
+
+        let new_email = normalize_email(&new_email);
+
+        if new_email == normalize_email(&user.email) {
+            return Err(UserApplicationError::Invalid(
+                "The new email is the same as the current one".to_string(),
+            ));
+        }
+
+        self.ensure_email_is_free(&new_email).await?;
+
+        let token = EmailChangeToken::generate();
+        let now = self.clock.now();
+
+        let change = PendingEmailChange::new(
+            user_id,
+            new_email.clone(),
+            token.token_hash,
+            now,
+            now + Duration::hours(TOKEN_TTL_HOURS),
+        );
+
+        self.email_change_repo.replace_pending(&change).await?;
+
+        self.notifier
+            .send_email_change_confirmation(&new_email, &token.token)
+            .await
+            .map_err(UserApplicationError::Unexpected)?;
+
+        self.notifier
+            .send_email_change_notice(&user.email, &redact(PiiField::Email, &new_email))
+            .await
+            .map_err(UserApplicationError::Unexpected)
+    }
+
+    pub async fn confirm(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        token: &str,
+    ) -> Result<(), UserApplicationError> {
+        let change = self
+            .email_change_repo
+            .find_by_token_hash(&hash_email_change_token(token))
+            .await?
+            .ok_or_else(|| {
+                UserApplicationError::NotFound("The email change token is unknown".to_string())
+            })?;
+
+        let now = self.clock.now();
+
+        if change.is_confirmed() {
+            return Err(UserApplicationError::Conflict(
+                "The email change was already confirmed".to_string(),
+            ));
+        }
+
+        if change.is_expired(now) {
+            return Err(UserApplicationError::Invalid(
+                "The email change token has expired".to_string(),
+            ));
+        }
+
+        let user = self.find_user(change.user_id).await?;
+
+        authorize(principal, &CHANGE_EMAIL, user.as_ref())?;
+
+        let mut user = user.ok_or_else(|| {
+            UserApplicationError::NotFound(format!("No user exists with ID {}", change.user_id))
+        })?;
+
+        self.ensure_email_is_free(&change.new_email).await?;
+
+        let ID::Existing(change_id) = change.id else {
+            return Err(UserApplicationError::Unexpected(
+                "The email change was never saved".to_string(),
+            ));
+        };
+
+        // Claiming the token before applying it means a concurrent confirmation
+        // cannot apply it twice; a failure below leaves it consumed and the user
+        // requests a new change rather than the email changing unconfirmed.
+        if !self
+            .email_change_repo
+            .mark_confirmed(change_id, now)
+            .await?
+        {
+            return Err(UserApplicationError::Conflict(
+                "The email change was already confirmed".to_string(),
+            ));
+        }
+
+        user.email = change.new_email;
+
+        self.user_repo
+            .update_email(&user)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            auth::{
+                authenticated_principal::AuthenticatedPrincipal,
+                email_change_token::hash_email_change_token,
+            },
+            errors::user_application_error::UserApplicationError,
+            use_cases::change_email::ChangeEmailUseCase,
+        },
+        domain::{
+            entities::{pending_email_change::PendingEmailChange, user::User},
+            errors::user_repository_error::UserRepositoryError,
+            repositories::{
+                email_change_repository::MockEmailChangeRepository,
+                user_repository::MockUserRepository,
+            },
+            services::{clock::MockClock, email_notifier::MockEmailNotifier},
+            value_objects::id::ID,
+        },
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap()
+    }
+
+    fn clock() -> Arc<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now());
+        Arc::new(clock)
+    }
+
+    fn fake_user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn owner() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("42".to_string(), None, vec![])
+    }
+
+    fn pending_change(token: &str, expires_at: DateTime<Utc>) -> PendingEmailChange {
+        let mut change = PendingEmailChange::new(
+            42,
+            "new@email.com".to_string(),
+            hash_email_change_token(token),
+            now() - Duration::hours(1),
+            expires_at,
+        );
+        change.id = ID::Existing(7);
+        change
+    }
+
+    #[tokio::test]
+    async fn request_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_email_change_repo = MockEmailChangeRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_email_change_repo.expect_replace_pending().times(0);
+
+        let sut = ChangeEmailUseCase::new(
+            mock_user_repo,
+            mock_email_change_repo,
+            MockEmailNotifier::new(),
+        );
+
+        let principal =
+            AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()]);
+
+        let result = sut
+            .request(&principal, 42, "new@email.com".to_string())
+            .await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn request_same_email() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo.expect_exists_by_email().times(0);
+
+        let sut = ChangeEmailUseCase::new(
+            mock_user_repo,
+            MockEmailChangeRepository::new(),
+            MockEmailNotifier::new(),
+        );
+
+        let result = sut
+            .request(&owner(), 42, "Andrew@Email.com".to_string())
+            .await;
+
+        assert!(matches!(result, Err(UserApplicationError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn request_email_taken() {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_email_change_repo = MockEmailChangeRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_exists_by_email()
+            .times(1)
+            .return_const(Ok(true));
+        mock_email_change_repo.expect_replace_pending().times(0);
+
+        let sut = ChangeEmailUseCase::new(
+            mock_user_repo,
+            mock_email_change_repo,
+            MockEmailNotifier::new(),
+        );
+
+        let result = sut.request(&owner(), 42, "new@email.com".to_string()).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Conflict(
+                "The email n***@email.com is already taken".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn request_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_email_change_repo = MockEmailChangeRepository::new();
+        let mut mock_notifier = MockEmailNotifier::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_exists_by_email()
+            .withf(|email: &str| email == "new@email.com")
+            .times(1)
+            .return_const(Ok(false));
+        mock_email_change_repo
+            .expect_replace_pending()
+            .withf(|change: &PendingEmailChange| {
+                change.user_id == 42
+                    && change.new_email == "new@email.com"
+                    && change.expires_at == now() + Duration::hours(24)
+            })
+            .times(1)
+            .return_const(Ok(()));
+        mock_notifier
+            .expect_send_email_change_confirmation()
+            .withf(|to: &str, token: &str| to == "new@email.com" && token.len() == 64)
+            .times(1)
+            .return_const(Ok(()));
+        mock_notifier
+            .expect_send_email_change_notice()
+            .withf(|to: &str, new_email: &str| {
+                to == "andrew@email.com" && new_email == "n***@email.com"
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = ChangeEmailUseCase::new(mock_user_repo, mock_email_change_repo, mock_notifier)
+            .with_clock(clock());
+
+        sut.request(&owner(), 42, " New@Email.com".to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn confirm_unknown_token() {
+        let mut mock_email_change_repo = MockEmailChangeRepository::new();
+
+        mock_email_change_repo
+            .expect_find_by_token_hash()
+            .times(1)
+            .return_const(Ok(None));
+
+        let sut = ChangeEmailUseCase::new(
+            MockUserRepository::new(),
+            mock_email_change_repo,
+            MockEmailNotifier::new(),
+        );
+
+        let result = sut.confirm(&owner(), "unknown").await;
+
+        assert!(matches!(result, Err(UserApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn confirm_expired_token() {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_email_change_repo = MockEmailChangeRepository::new();
+
+        mock_email_change_repo
+            .expect_find_by_token_hash()
+            .times(1)
+            .return_const(Ok(Some(pending_change("token", now()))));
+        mock_user_repo.expect_update_email().times(0);
+
+        let sut = ChangeEmailUseCase::new(
+            mock_user_repo,
+            mock_email_change_repo,
+            MockEmailNotifier::new(),
+        )
+        .with_clock(clock());
+
+        let result = sut.confirm(&owner(), "token").await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Invalid(
+                "The email change token has expired".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn confirm_email_taken_in_the_meantime() {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_email_change_repo = MockEmailChangeRepository::new();
+
+        mock_email_change_repo
+            .expect_find_by_token_hash()
+            .times(1)
+            .return_const(Ok(Some(pending_change(
+                "token",
+                now() + Duration::hours(1),
+            ))));
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_exists_by_email()
+            .times(1)
+            .return_const(Ok(true));
+        mock_user_repo.expect_update_email().times(0);
+        mock_email_change_repo.expect_mark_confirmed().times(0);
+
+        let sut = ChangeEmailUseCase::new(
+            mock_user_repo,
+            mock_email_change_repo,
+            MockEmailNotifier::new(),
+        )
+        .with_clock(clock());
+
+        let result = sut.confirm(&owner(), "token").await;
+
+        assert!(matches!(result, Err(UserApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn confirm_claimed_concurrently() {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_email_change_repo = MockEmailChangeRepository::new();
+
+        mock_email_change_repo
+            .expect_find_by_token_hash()
+            .times(1)
+            .return_const(Ok(Some(pending_change(
+                "token",
+                now() + Duration::hours(1),
+            ))));
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_exists_by_email()
+            .times(1)
+            .return_const(Ok(false));
+        mock_email_change_repo
+            .expect_mark_confirmed()
+            .times(1)
+            .return_const(Ok(false));
+        mock_user_repo.expect_update_email().times(0);
+
+        let sut = ChangeEmailUseCase::new(
+            mock_user_repo,
+            mock_email_change_repo,
+            MockEmailNotifier::new(),
+        )
+        .with_clock(clock());
+
+        let result = sut.confirm(&owner(), "token").await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Conflict(
+                "The email change was already confirmed".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn confirm_unique_violation() {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_email_change_repo = MockEmailChangeRepository::new();
+
+        mock_email_change_repo
+            .expect_find_by_token_hash()
+            .times(1)
+            .return_const(Ok(Some(pending_change(
+                "token",
+                now() + Duration::hours(1),
+            ))));
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_exists_by_email()
+            .times(1)
+            .return_const(Ok(false));
+        mock_user_repo
+            .expect_update_email()
+            .times(1)
+            .return_const(Err(UserRepositoryError::Conflict(
+                "duplicate key".to_string(),
+            )));
+        mock_email_change_repo
+            .expect_mark_confirmed()
+            .times(1)
+            .return_const(Ok(true));
+
+        let sut = ChangeEmailUseCase::new(
+            mock_user_repo,
+            mock_email_change_repo,
+            MockEmailNotifier::new(),
+        )
+        .with_clock(clock());
+
+        let result = sut.confirm(&owner(), "token").await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Conflict("duplicate key".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn confirm_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut mock_email_change_repo = MockEmailChangeRepository::new();
+
+        mock_email_change_repo
+            .expect_find_by_token_hash()
+            .with(eq(hash_email_change_token("token")))
+            .times(1)
+            .return_const(Ok(Some(pending_change(
+                "token",
+                now() + Duration::hours(1),
+            ))));
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_exists_by_email()
+            .times(1)
+            .return_const(Ok(false));
+        mock_user_repo
+            .expect_update_email()
+            .withf(|user: &User| user.id == ID::Existing(42) && user.email == "new@email.com")
+            .times(1)
+            .return_const(Ok(()));
+        mock_email_change_repo
+            .expect_mark_confirmed()
+            .with(eq(7), eq(now()))
+            .times(1)
+            .return_const(Ok(true));
+
+        let sut = ChangeEmailUseCase::new(
+            mock_user_repo,
+            mock_email_change_repo,
+            MockEmailNotifier::new(),
+        )
+        .with_clock(clock());
+
+        sut.confirm(&owner(), "token").await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        auth::{
+            api_key_secret::ApiKeySecret,
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{MANAGE_API_KEYS, SCOPES, authorize},
+        },
+        errors::api_key_application_error::ApiKeyApplicationError,
+    },
+    domain::{
+        entities::api_key::ApiKey,
+        repositories::api_key_repository::ApiKeyRepository,
+        services::clock::{Clock, SystemClock},
+    },
+    presentation::dtos::api_key_dto::CreateApiKeyDTO,
+};
+
+pub struct CreateApiKeyUseCase<T: ApiKeyRepository> {
+    api_key_repo: T,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: ApiKeyRepository> CreateApiKeyUseCase<T> {
+    pub fn new(api_key_repo: T) -> Self {
+        Self {
+            api_key_repo,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        input: CreateApiKeyDTO,
+    ) -> Result<(ApiKey, String), ApiKeyApplicationError> {
+        authorize(principal, &MANAGE_API_KEYS, None)?;
+
+        if input.name.trim().is_empty() {
+            return Err(ApiKeyApplicationError::Invalid(
+                "The API key name must not be empty".to_string(),
+            ));
+        }
+
+        if let Some(scope) = input
+            .scopes
+            .iter()
+            .find(|scope| !SCOPES.contains(&scope.as_str()))
+        {
+            return Err(ApiKeyApplicationError::Invalid(format!(
+                "Unknown scope: {scope}"
+            )));
+        }
+
+        let secret = ApiKeySecret::generate();
+
+        let mut api_key = ApiKey::new(
+            input.name,
+            secret.key_prefix,
+            secret.key_hash,
+            input.scopes,
+            self.clock.now(),
+        );
+
+        api_key.id = self.api_key_repo.save(&api_key).await?.into();
+
+        Ok((api_key, secret.key))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use crate::{
+        application::{
+            auth::{api_key_secret::hash_api_key, authenticated_principal::AuthenticatedPrincipal},
+            errors::api_key_application_error::ApiKeyApplicationError,
+            use_cases::create_api_key::CreateApiKeyUseCase,
+        },
+        domain::{
+            entities::api_key::ApiKey, errors::api_key_repository_error::ApiKeyRepositoryError,
+            repositories::api_key_repository::MockApiKeyRepository, services::clock::MockClock,
+            value_objects::id::ID,
+        },
+        presentation::dtos::api_key_dto::CreateApiKeyDTO,
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap()
+    }
+
+    fn clock() -> Arc<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now());
+        Arc::new(clock)
+    }
+
+    fn admin() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()])
+    }
+
+    fn input(name: &str, scopes: &[&str]) -> CreateApiKeyDTO {
+        CreateApiKeyDTO {
+            name: name.to_string(),
+            scopes: scopes.iter().map(|scope| scope.to_string()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo.expect_save().times(0);
+
+        let sut = CreateApiKeyUseCase::new(mock_api_key_repo);
+
+        let principal = AuthenticatedPrincipal::new("1".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, input("batch-jobs", &[])).await;
+
+        assert!(matches!(result, Err(ApiKeyApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_empty_name() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo.expect_save().times(0);
+
+        let sut = CreateApiKeyUseCase::new(mock_api_key_repo);
+
+        let result = sut.execute(&admin(), input("  ", &[])).await;
+
+        assert_eq!(
+            result,
+            Err(ApiKeyApplicationError::Invalid(
+                "The API key name must not be empty".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_unknown_scope() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo.expect_save().times(0);
+
+        let sut = CreateApiKeyUseCase::new(mock_api_key_repo);
+
+        let result = sut
+            .execute(
+                &admin(),
+                input("batch-jobs", &["users:read", "users:delete"]),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(ApiKeyApplicationError::Invalid(
+                "Unknown scope: users:delete".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_api_key_repository_error() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo.expect_save().times(1).return_const(Err(
+            ApiKeyRepositoryError::DatabaseError("Fake Error".to_string()),
+        ));
+
+        let sut = CreateApiKeyUseCase::new(mock_api_key_repo);
+
+        let result = sut.execute(&admin(), input("batch-jobs", &[])).await;
+
+        assert!(matches!(result, Err(ApiKeyApplicationError::Unexpected(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo
+            .expect_save()
+            .withf(|api_key: &ApiKey| {
+                api_key.id == ID::New
+                    && api_key.name == "batch-jobs"
+                    && api_key.scopes == vec!["users:read".to_string()]
+                    && api_key.created_at == now()
+            })
+            .times(1)
+            .return_const(Ok(7));
+
+        let sut = CreateApiKeyUseCase::new(mock_api_key_repo).with_clock(clock());
+
+        let (api_key, key) = sut
+            .execute(&admin(), input("batch-jobs", &["users:read"]))
+            .await?;
+
+        assert_eq!(api_key.id, ID::Existing(7));
+        assert_eq!(api_key.key_hash, hash_api_key(&key));
+        assert!(key.starts_with(&api_key.key_prefix));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{ERASE_USER, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        entities::user_erasure::UserErasure,
+        repositories::user_repository::UserRepository,
+        services::clock::{Clock, SystemClock},
+    },
+};
+
+pub struct EraseUserUseCase<T: UserRepository> {
+    user_repo: T,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: UserRepository> EraseUserUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self {
+            user_repo,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        user_id: i32,
+    ) -> Result<(), UserApplicationError> {
+        let user = self.user_repo.find_by_id(user_id).await?;
+
+        authorize(principal, &ERASE_USER, user.as_ref())?;
+
+        let mut user = user.ok_or_else(|| {
+            UserApplicationError::NotFound(format!("No user exists with ID {user_id}"))
+        })?;
+
+        if !self.user_repo.find_erasures(user_id).await?.is_empty() {
+            return Err(UserApplicationError::Conflict(format!(
+                "The user {user_id} has already been erased"
+            )));
+        }
+
+        user.anonymize();
+
+        let erasure = UserErasure::new(user_id, principal.subject.clone(), self.clock.now());
+
+        self.user_repo
+            .erase(&user, &erasure)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::erase_user::EraseUserUseCase,
+        },
+        domain::{
+            entities::{user::User, user_erasure::UserErasure},
+            errors::user_repository_error::UserRepositoryError,
+            repositories::user_repository::MockUserRepository,
+            services::clock::MockClock,
+        },
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap()
+    }
+
+    fn clock() -> Arc<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now());
+        Arc::new(clock)
+    }
+
+    fn fake_user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn admin() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()])
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo.expect_erase().times(0);
+
+        let sut = EraseUserUseCase::new(mock_user_repo);
+
+        let principal =
+            AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()]);
+
+        let result = sut.execute(&principal, 42).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_not_found() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+        mock_user_repo.expect_erase().times(0);
+
+        let sut = EraseUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&admin(), 42).await;
+
+        assert!(matches!(result, Err(UserApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_already_erased() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .times(1)
+            .return_const(Ok(vec![UserErasure::new(42, "1".to_string(), Utc::now())]));
+        mock_user_repo.expect_erase().times(0);
+
+        let sut = EraseUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&admin(), 42).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Conflict(
+                "The user 42 has already been erased".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_user_repository_erase_error() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo.expect_erase().times(1).return_const(Err(
+            UserRepositoryError::DatabaseError("Fake Error".to_string()),
+        ));
+
+        let sut = EraseUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&admin(), 42).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Unexpected(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok_for_self() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_erase()
+            .withf(|user: &User, erasure: &UserErasure| {
+                let mut expected_user = fake_user();
+                expected_user.anonymize();
+
+                *user == expected_user
+                    && erasure.user_id == 42
+                    && erasure.requested_by == "42"
+                    && erasure.erased_at == now()
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = EraseUserUseCase::new(mock_user_repo).with_clock(clock());
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, 42).await;
+
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -0,0 +1,207 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{EXPORT_USER, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        entities::{user::User, user_audit_entry::UserAuditEntry, user_erasure::UserErasure},
+        repositories::{
+            user_audit_repository::UserAuditRepository, user_repository::UserRepository,
+        },
+        services::clock::{Clock, SystemClock},
+    },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserDataExport {
+    pub user: User,
+    pub erasures: Vec<UserErasure>,
+    pub history: Vec<UserAuditEntry>,
+    pub exported_at: DateTime<Utc>,
+}
+
+pub struct ExportUserDataUseCase<T: UserRepository, A: UserAuditRepository> {
+    user_repo: T,
+    audit_repo: A,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: UserRepository, A: UserAuditRepository> ExportUserDataUseCase<T, A> {
+    pub fn new(user_repo: T, audit_repo: A) -> Self {
+        Self {
+            user_repo,
+            audit_repo,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        user_id: i32,
+    ) -> Result<UserDataExport, UserApplicationError> {
+        let user = self.user_repo.find_by_id(user_id).await?;
+
+        authorize(principal, &EXPORT_USER, user.as_ref())?;
+
+        let user = user.ok_or_else(|| {
+            UserApplicationError::NotFound(format!("No user exists with ID {user_id}"))
+        })?;
+
+        let erasures = self.user_repo.find_erasures(user_id).await?;
+        let history = self.audit_repo.find_by_user(user_id).await?;
+
+        Ok(UserDataExport {
+            user,
+            erasures,
+            history,
+            exported_at: self.clock.now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, TimeZone, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::export_user_data::ExportUserDataUseCase,
+        },
+        domain::{
+            entities::{user::User, user_audit_entry::UserAuditEntry, user_erasure::UserErasure},
+            repositories::{
+                user_audit_repository::MockUserAuditRepository, user_repository::MockUserRepository,
+            },
+            services::clock::MockClock,
+        },
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap()
+    }
+
+    fn clock() -> Arc<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now());
+        Arc::new(clock)
+    }
+
+    fn fake_user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo.expect_find_erasures().times(0);
+
+        let sut = ExportUserDataUseCase::new(mock_user_repo, MockUserAuditRepository::new());
+
+        let principal = AuthenticatedPrincipal::new("7".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, 42).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_not_found() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        let sut = ExportUserDataUseCase::new(mock_user_repo, MockUserAuditRepository::new());
+
+        let principal =
+            AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()]);
+
+        let result = sut.execute(&principal, 42).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::NotFound(
+                "No user exists with ID 42".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok_for_self() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let erasures = vec![UserErasure::new(42, "1".to_string(), Utc::now())];
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(erasures.clone()));
+
+        let history = vec![UserAuditEntry::new(
+            42,
+            "1".to_string(),
+            "create",
+            None,
+            serde_json::json!({}),
+            Utc::now(),
+        )];
+
+        let mut mock_audit_repo = MockUserAuditRepository::new();
+        mock_audit_repo
+            .expect_find_by_user()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(history.clone()));
+
+        let sut = ExportUserDataUseCase::new(mock_user_repo, mock_audit_repo).with_clock(clock());
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, 42).await?;
+
+        assert_eq!(result.user, fake_user());
+        assert_eq!(result.erasures, erasures);
+        assert_eq!(result.history, history);
+        assert_eq!(result.exported_at, now());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{EXPORT_USERS, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        repositories::user_repository::{UserBatchStream, UserRepository},
+        value_objects::creation_range::CreationRange,
+    },
+};
+
+pub const EXPORT_BATCH_SIZE: usize = 1000;
+
+pub struct ExportUsersUseCase<T: UserRepository> {
+    user_repo: T,
+}
+
+impl<T: UserRepository> ExportUsersUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        created_from: Option<DateTime<Utc>>,
+        created_to: Option<DateTime<Utc>>,
+    ) -> Result<UserBatchStream, UserApplicationError> {
+        authorize(principal, &EXPORT_USERS, None)?;
+
+        let range = CreationRange::new(created_from, created_to)?;
+
+        self.user_repo
+            .stream(&range, EXPORT_BATCH_SIZE)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+    use futures_util::{StreamExt, stream};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::export_users::{EXPORT_BATCH_SIZE, ExportUsersUseCase},
+        },
+        domain::{
+            entities::user::User, repositories::user_repository::MockUserRepository,
+            value_objects::creation_range::CreationRange,
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_stream().times(0);
+
+        let sut = ExportUsersUseCase::new(mock_user_repo);
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, None, None).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden_for_support() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_stream().times(0);
+
+        let sut = ExportUsersUseCase::new(mock_user_repo);
+
+        let principal =
+            AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()])
+                .with_scopes(vec!["users:read".to_string()]);
+
+        let result = sut.execute(&principal, None, None).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok_by_export_scope() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_stream()
+            .times(1)
+            .return_once(|_, _| Ok(stream::empty().boxed()));
+
+        let sut = ExportUsersUseCase::new(mock_user_repo);
+
+        let principal = AuthenticatedPrincipal::new("api-key:7".to_string(), None, vec![])
+            .with_scopes(vec!["users:export".to_string()]);
+
+        let batches: Vec<_> = sut.execute(&principal, None, None).await?.collect().await;
+
+        assert!(batches.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let to = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        let user = User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )?;
+
+        let batch = vec![user];
+        let streamed = batch.clone();
+
+        mock_user_repo
+            .expect_stream()
+            .with(
+                eq(CreationRange::new(None, Some(to))?),
+                eq(EXPORT_BATCH_SIZE),
+            )
+            .times(1)
+            .return_once(move |_, _| Ok(stream::iter([Ok(streamed)]).boxed()));
+
+        let sut = ExportUsersUseCase::new(mock_user_repo);
+
+        let principal =
+            AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()]);
+
+        let batches: Vec<_> = sut
+            .execute(&principal, None, Some(to))
+            .await?
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(batches, vec![batch]);
+
+        Ok(())
+    }
+}
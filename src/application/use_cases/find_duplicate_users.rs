@@ -0,0 +1,140 @@
+use futures_util::StreamExt;
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{REPORT_USER_DUPLICATES, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        repositories::user_repository::UserRepository,
+        value_objects::{
+            creation_range::CreationRange,
+            duplicate_candidate::{DuplicateCandidate, DuplicateDetector},
+        },
+    },
+};
+
+const SCAN_BATCH_SIZE: usize = 1000;
+
+pub struct FindDuplicateUsersUseCase<T: UserRepository> {
+    user_repo: T,
+}
+
+impl<T: UserRepository> FindDuplicateUsersUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+    ) -> Result<Vec<DuplicateCandidate>, UserApplicationError> {
+        authorize(principal, &REPORT_USER_DUPLICATES, None)?;
+
+        let mut batches = self
+            .user_repo
+            .stream(&CreationRange::default(), SCAN_BATCH_SIZE)
+            .await?;
+
+        let mut detector = DuplicateDetector::default();
+
+        while let Some(batch) = batches.next().await {
+            detector.add(batch?);
+        }
+
+        Ok(detector.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::{StreamExt, stream};
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::find_duplicate_users::FindDuplicateUsersUseCase,
+        },
+        domain::{
+            entities::user::User, errors::user_repository_error::UserRepositoryError,
+            repositories::user_repository::MockUserRepository,
+        },
+    };
+
+    fn admin() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()])
+    }
+
+    fn user(id: i32, name: &str) -> User {
+        User::restore(
+            id,
+            name.to_string(),
+            format!("user{id}@email.com"),
+            "+5511999990000".to_string(),
+            "12 Dawn Street".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_stream().times(0);
+
+        let sut = FindDuplicateUsersUseCase::new(mock_user_repo);
+
+        let principal =
+            AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()]);
+
+        let result = sut.execute(&principal).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_across_batches() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let batches = vec![
+            Ok(vec![user(1, "Andrew Smith")]),
+            Ok(vec![user(2, "Andrew Smyth"), user(3, "Bea Souza")]),
+        ];
+
+        mock_user_repo
+            .expect_stream()
+            .times(1)
+            .return_once(move |_, _| Ok(stream::iter(batches).boxed()));
+
+        let sut = FindDuplicateUsersUseCase::new(mock_user_repo);
+
+        let candidates = sut.execute(&admin()).await?;
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].user_ids, [1, 2]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_stream_error() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let batches = vec![Err(UserRepositoryError::DatabaseError("gone".to_string()))];
+
+        mock_user_repo
+            .expect_stream()
+            .times(1)
+            .return_once(move |_, _| Ok(stream::iter(batches).boxed()));
+
+        let sut = FindDuplicateUsersUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&admin()).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Unexpected(_))));
+    }
+}
@@ -1,6 +1,15 @@
 use crate::{
-    application::errors::user_application_error::UserApplicationError,
-    domain::{entities::user::User, repositories::user_repository::UserRepository},
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{READ_USER, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        entities::user::User, repositories::user_repository::UserRepository,
+        services::email_normalizer::normalize_email,
+    },
 };
 
 pub struct FindUserByEmailUseCase<T: UserRepository> {
@@ -12,11 +21,19 @@ impl<T: UserRepository> FindUserByEmailUseCase<T> {
         Self { user_repo }
     }
 
-    pub async fn execute(&self, email: String) -> Result<Option<User>, UserApplicationError> {
-        self.user_repo
-            .find_by_email(email)
-            .await
-            .map_err(|err| err.into())
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        email: String,
+    ) -> Result<Option<User>, UserApplicationError> {
+        let user = self
+            .user_repo
+            .find_by_email(normalize_email(&email))
+            .await?;
+
+        authorize(principal, &READ_USER, user.as_ref())?;
+
+        Ok(user)
     }
 }
 
@@ -25,13 +42,32 @@ mod test {
     use mockall::predicate::eq;
 
     use crate::{
-        application::use_cases::find_user_by_email::FindUserByEmailUseCase,
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::find_user_by_email::FindUserByEmailUseCase,
+        },
         domain::{
             entities::user::User, errors::user_repository_error::UserRepositoryError,
             repositories::user_repository::MockUserRepository,
         },
     };
 
+    fn admin() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()])
+    }
+
+    fn fake_user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
     #[tokio::test]
     async fn execute_user_repository_error() {
         let mut mock_user_repository = MockUserRepository::new();
@@ -45,22 +81,76 @@ mod test {
 
         let sut = FindUserByEmailUseCase::new(mock_user_repository);
 
-        let result = sut.execute("any@email.com".to_string()).await;
+        let result = sut.execute(&admin(), "any@email.com".to_string()).await;
 
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn execute_forbidden_for_another_user() {
+        let mut mock_user_repository = MockUserRepository::new();
+
+        mock_user_repository
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+
+        let sut = FindUserByEmailUseCase::new(mock_user_repository);
+
+        let principal = AuthenticatedPrincipal::new(
+            "7".to_string(),
+            Some("someone@email.com".to_string()),
+            vec!["support".to_string()],
+        );
+
+        let result = sut.execute(&principal, fake_user().email).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden_for_missing_user_when_not_admin() {
+        let mut mock_user_repository = MockUserRepository::new();
+
+        mock_user_repository
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(None));
+
+        let sut = FindUserByEmailUseCase::new(mock_user_repository);
+
+        let principal = AuthenticatedPrincipal::new("7".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, "any@email.com".to_string()).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok_for_self() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repository = MockUserRepository::new();
+
+        mock_user_repository
+            .expect_find_by_email()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+
+        let sut = FindUserByEmailUseCase::new(mock_user_repository);
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, fake_user().email).await?;
+
+        assert_eq!(result, Some(fake_user()));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
         let mut mock_user_repository = MockUserRepository::new();
 
-        let fake_user = User::restore(
-            42,
-            "Andrew".to_string(),
-            "andrew@email.com".to_string(),
-            "+001133334444".to_string(),
-            "Dawn St.".to_string(),
-        )?;
+        let fake_user = fake_user();
 
         mock_user_repository
             .expect_find_by_email()
@@ -70,10 +160,31 @@ mod test {
 
         let sut = FindUserByEmailUseCase::new(mock_user_repository);
 
-        let result = sut.execute(fake_user.email.clone()).await?;
+        let result = sut.execute(&admin(), fake_user.email.clone()).await?;
 
         assert_eq!(result, Some(fake_user));
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn execute_normalizes_email() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repository = MockUserRepository::new();
+
+        mock_user_repository
+            .expect_find_by_email()
+            .with(eq("andrew@email.com".to_string()))
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+
+        let sut = FindUserByEmailUseCase::new(mock_user_repository);
+
+        let result = sut
+            .execute(&admin(), " Andrew@Email.COM".to_string())
+            .await?;
+
+        assert_eq!(result, Some(fake_user()));
+
+        Ok(())
+    }
 }
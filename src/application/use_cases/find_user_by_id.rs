@@ -0,0 +1,119 @@
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{READ_USER, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{entities::user::User, repositories::user_repository::UserRepository},
+};
+
+pub struct FindUserByIdUseCase<T: UserRepository> {
+    user_repo: T,
+}
+
+impl<T: UserRepository> FindUserByIdUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        id: i32,
+    ) -> Result<Option<User>, UserApplicationError> {
+        let user = self.user_repo.find_by_id(id).await?;
+
+        authorize(principal, &READ_USER, user.as_ref())?;
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::find_user_by_id::FindUserByIdUseCase,
+        },
+        domain::{
+            entities::user::User, errors::user_repository_error::UserRepositoryError,
+            repositories::user_repository::MockUserRepository,
+        },
+    };
+
+    fn admin() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()])
+    }
+
+    fn fake_user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn execute_user_repository_error() {
+        let mut mock_user_repository = MockUserRepository::new();
+
+        mock_user_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(UserRepositoryError::DatabaseError(
+                "Fake Error".to_string(),
+            )));
+
+        let sut = FindUserByIdUseCase::new(mock_user_repository);
+
+        let result = sut.execute(&admin(), 42).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden_for_another_user() {
+        let mut mock_user_repository = MockUserRepository::new();
+
+        mock_user_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+
+        let sut = FindUserByIdUseCase::new(mock_user_repository);
+
+        let principal = AuthenticatedPrincipal::new("7".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, 42).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repository = MockUserRepository::new();
+
+        mock_user_repository
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+
+        let sut = FindUserByIdUseCase::new(mock_user_repository);
+
+        let result = sut.execute(&admin(), 42).await?;
+
+        assert_eq!(result, Some(fake_user()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{READ_USER_HISTORY, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        entities::user_audit_entry::UserAuditEntry,
+        repositories::user_audit_repository::UserAuditRepository,
+    },
+};
+
+pub struct GetUserHistoryUseCase<T: UserAuditRepository> {
+    audit_repo: T,
+}
+
+impl<T: UserAuditRepository> GetUserHistoryUseCase<T> {
+    pub fn new(audit_repo: T) -> Self {
+        Self { audit_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        user_id: i32,
+    ) -> Result<Vec<UserAuditEntry>, UserApplicationError> {
+        authorize(principal, &READ_USER_HISTORY, None)?;
+
+        self.audit_repo
+            .find_by_user(user_id)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+    use mockall::predicate::eq;
+    use serde_json::json;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::get_user_history::GetUserHistoryUseCase,
+        },
+        domain::{
+            entities::user_audit_entry::UserAuditEntry,
+            repositories::user_audit_repository::MockUserAuditRepository,
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_audit_repo = MockUserAuditRepository::new();
+
+        mock_audit_repo.expect_find_by_user().times(0);
+
+        let sut = GetUserHistoryUseCase::new(mock_audit_repo);
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, 42).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_audit_repo = MockUserAuditRepository::new();
+
+        let history = vec![UserAuditEntry::new(
+            42,
+            "1".to_string(),
+            "create",
+            None,
+            json!({}),
+            Utc::now(),
+        )];
+
+        mock_audit_repo
+            .expect_find_by_user()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(history.clone()));
+
+        let sut = GetUserHistoryUseCase::new(mock_audit_repo);
+
+        let principal =
+            AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()]);
+
+        let result = sut.execute(&principal, 42).await?;
+
+        assert_eq!(result, history);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,511 @@
+use std::{collections::HashSet, pin::pin};
+
+use futures_util::{Stream, StreamExt};
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{WRITE_USER, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        entities::user::User, errors::user_entity_error::UserEntityError,
+        errors::user_repository_error::UserRepositoryError,
+        repositories::user_repository::UserRepository,
+    },
+    presentation::dtos::user_dto::CreateUserDTO,
+};
+
+pub const IMPORT_BATCH_SIZE: usize = 500;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportRowOutcome {
+    Created(i32),
+    Valid,
+    Duplicate(String),
+    Invalid(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportRowError {
+    /// The row is malformed, the rows after it are still imported.
+    Invalid(String),
+    /// The input broke off, so no row from there on can be trusted.
+    Interrupted(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRowResult {
+    pub row: usize,
+    pub outcome: ImportRowOutcome,
+}
+
+pub struct ImportUsersUseCase<T: UserRepository> {
+    user_repo: T,
+}
+
+impl<T: UserRepository> ImportUsersUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        rows: impl Stream<Item = Result<CreateUserDTO, ImportRowError>>,
+        dry_run: bool,
+    ) -> Result<Vec<ImportRowResult>, UserApplicationError> {
+        authorize(principal, &WRITE_USER, None)?;
+
+        let mut results = Vec::new();
+        let mut seen_emails = HashSet::new();
+        let mut processed = 0;
+
+        let mut batches = pin!(rows.enumerate().chunks(IMPORT_BATCH_SIZE));
+
+        while let Some(rows) = batches.next().await {
+            // The batch holding an interruption is dropped whole, so the rows
+            // imported are exactly the ones the error tells the caller about.
+            if let Some(reason) = rows.iter().find_map(|(_, row)| match row {
+                Err(ImportRowError::Interrupted(reason)) => Some(reason),
+                _ => None,
+            }) {
+                return Err(UserApplicationError::Invalid(match processed {
+                    0 => format!("{reason}. No row was imported"),
+                    processed => format!(
+                        "{reason}. Rows 1 to {processed} were processed, resend the import \
+                         from row {}",
+                        processed + 1
+                    ),
+                }));
+            }
+
+            processed += rows.len();
+
+            let batch: Vec<(usize, User)> = rows
+                .into_iter()
+                .filter_map(|(index, row)| {
+                    let row_number = index + 1;
+
+                    let user: User = match row {
+                        Ok(dto) => dto.into(),
+                        Err(
+                            ImportRowError::Invalid(reason) | ImportRowError::Interrupted(reason),
+                        ) => {
+                            results.push(ImportRowResult {
+                                row: row_number,
+                                outcome: ImportRowOutcome::Invalid(vec![reason]),
+                            });
+                            return None;
+                        }
+                    };
+
+                    if let Err(err) = user.validate() {
+                        let reasons = match err {
+                            UserEntityError::InvalidFields(reasons) => reasons,
+                            other => vec![other.to_string()],
+                        };
+
+                        results.push(ImportRowResult {
+                            row: row_number,
+                            outcome: ImportRowOutcome::Invalid(reasons),
+                        });
+                        return None;
+                    }
+
+                    if !seen_emails.insert(user.email.clone()) {
+                        results.push(ImportRowResult {
+                            row: row_number,
+                            outcome: ImportRowOutcome::Duplicate(
+                                "The email appears earlier in the import".to_string(),
+                            ),
+                        });
+                        return None;
+                    }
+
+                    Some((row_number, user))
+                })
+                .collect();
+
+            results.extend(self.import_batch(batch, dry_run).await?);
+        }
+
+        results.sort_by_key(|result| result.row);
+
+        Ok(results)
+    }
+
+    async fn import_batch(
+        &self,
+        batch: Vec<(usize, User)>,
+        dry_run: bool,
+    ) -> Result<Vec<ImportRowResult>, UserApplicationError> {
+        if batch.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let emails: Vec<String> = batch.iter().map(|(_, user)| user.email.clone()).collect();
+
+        let existing_emails: HashSet<String> = self
+            .user_repo
+            .find_existing_emails(&emails)
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut results = Vec::with_capacity(batch.len());
+        let mut new_users = Vec::with_capacity(batch.len());
+
+        for (row, user) in batch {
+            if existing_emails.contains(&user.email) {
+                results.push(ImportRowResult {
+                    row,
+                    outcome: ImportRowOutcome::Duplicate("The email is already taken".to_string()),
+                });
+            } else {
+                new_users.push((row, user));
+            }
+        }
+
+        if dry_run {
+            results.extend(new_users.into_iter().map(|(row, _)| ImportRowResult {
+                row,
+                outcome: ImportRowOutcome::Valid,
+            }));
+
+            return Ok(results);
+        }
+
+        if new_users.is_empty() {
+            return Ok(results);
+        }
+
+        let users: Vec<User> = new_users.iter().map(|(_, user)| user.clone()).collect();
+
+        match self.user_repo.save_batch(&users).await {
+            Ok(user_ids) => {
+                results.extend(new_users.iter().zip(user_ids).map(|((row, _), user_id)| {
+                    ImportRowResult {
+                        row: *row,
+                        outcome: ImportRowOutcome::Created(user_id),
+                    }
+                }));
+            }
+            Err(UserRepositoryError::Conflict(_)) => {
+                for (row, user) in new_users {
+                    let outcome = match self.user_repo.save(&user).await {
+                        Ok(user_id) => ImportRowOutcome::Created(user_id),
+                        Err(UserRepositoryError::Conflict(_)) => {
+                            ImportRowOutcome::Duplicate("The email is already taken".to_string())
+                        }
+                        Err(err) => return Err(err.into()),
+                    };
+
+                    results.push(ImportRowResult { row, outcome });
+                }
+            }
+            Err(err) => return Err(err.into()),
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use futures_util::{StreamExt, stream};
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::import_users::{
+                IMPORT_BATCH_SIZE, ImportRowError, ImportRowOutcome, ImportRowResult,
+                ImportUsersUseCase,
+            },
+        },
+        domain::{
+            entities::user::User, errors::user_repository_error::UserRepositoryError,
+            repositories::user_repository::MockUserRepository,
+        },
+        presentation::dtos::user_dto::CreateUserDTO,
+    };
+
+    fn support() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["support".to_string()])
+    }
+
+    fn row(email: &str) -> Result<CreateUserDTO, ImportRowError> {
+        Ok(CreateUserDTO {
+            name: "Andrew".to_string(),
+            email: email.to_string(),
+            phone: "+001122223333".to_string(),
+            address: "Dawn St.".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_save_batch().times(0);
+
+        let sut = ImportUsersUseCase::new(mock_user_repo);
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![]);
+
+        let result = sut
+            .execute(&principal, stream::iter(vec![row("a@email.com")]), false)
+            .await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_reports_every_row() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_existing_emails()
+            .withf(|emails: &[String]| emails == ["a@email.com", "taken@email.com"])
+            .times(1)
+            .return_const(Ok(vec!["taken@email.com".to_string()]));
+        mock_user_repo
+            .expect_save_batch()
+            .withf(|users: &[User]| users.len() == 1 && users[0].email == "a@email.com")
+            .times(1)
+            .return_const(Ok(vec![42]));
+
+        let sut = ImportUsersUseCase::new(mock_user_repo);
+
+        let rows = vec![
+            row("A@Email.com"),
+            Err(ImportRowError::Invalid("missing column email".to_string())),
+            row("not-an-email"),
+            row("a@email.com"),
+            row("taken@email.com"),
+        ];
+
+        let result = sut.execute(&support(), stream::iter(rows), false).await?;
+
+        assert_eq!(
+            result,
+            vec![
+                ImportRowResult {
+                    row: 1,
+                    outcome: ImportRowOutcome::Created(42),
+                },
+                ImportRowResult {
+                    row: 2,
+                    outcome: ImportRowOutcome::Invalid(vec!["missing column email".to_string()]),
+                },
+                ImportRowResult {
+                    row: 3,
+                    outcome: ImportRowOutcome::Invalid(vec![
+                        "email is not a valid address".to_string()
+                    ]),
+                },
+                ImportRowResult {
+                    row: 4,
+                    outcome: ImportRowOutcome::Duplicate(
+                        "The email appears earlier in the import".to_string()
+                    ),
+                },
+                ImportRowResult {
+                    row: 5,
+                    outcome: ImportRowOutcome::Duplicate("The email is already taken".to_string()),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_dry_run_does_not_save() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_existing_emails()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo.expect_save_batch().times(0);
+        mock_user_repo.expect_save().times(0);
+
+        let sut = ImportUsersUseCase::new(mock_user_repo);
+
+        let result = sut
+            .execute(&support(), stream::iter(vec![row("a@email.com")]), true)
+            .await?;
+
+        assert_eq!(
+            result,
+            vec![ImportRowResult {
+                row: 1,
+                outcome: ImportRowOutcome::Valid,
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_in_batches() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_existing_emails()
+            .times(2)
+            .return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_save_batch()
+            .times(2)
+            .returning(|users| Ok((1..=users.len() as i32).collect()));
+
+        let sut = ImportUsersUseCase::new(mock_user_repo);
+
+        let rows: Vec<_> = (0..IMPORT_BATCH_SIZE + 1)
+            .map(|index| row(&format!("user{index}@email.com")))
+            .collect();
+
+        let result = sut.execute(&support(), stream::iter(rows), false).await?;
+
+        assert_eq!(result.len(), IMPORT_BATCH_SIZE + 1);
+        assert!(
+            result
+                .iter()
+                .all(|result| matches!(result.outcome, ImportRowOutcome::Created(_)))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_drops_the_batch_that_was_interrupted() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_existing_emails()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_save_batch()
+            .times(1)
+            .returning(|users| Ok((1..=users.len() as i32).collect()));
+
+        let sut = ImportUsersUseCase::new(mock_user_repo);
+
+        let rows = (0..IMPORT_BATCH_SIZE + 1)
+            .map(|index| row(&format!("user{index}@email.com")))
+            .chain([Err(ImportRowError::Interrupted(
+                "The upload was cut off".to_string(),
+            ))]);
+
+        let result = sut.execute(&support(), stream::iter(rows), false).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Invalid(format!(
+                "The upload was cut off. Rows 1 to {IMPORT_BATCH_SIZE} were processed, resend \
+                 the import from row {}",
+                IMPORT_BATCH_SIZE + 1
+            )))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_saves_each_batch_as_it_fills() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+        let saved = Arc::new(AtomicUsize::new(0));
+
+        mock_user_repo
+            .expect_find_existing_emails()
+            .times(2)
+            .return_const(Ok(vec![]));
+        mock_user_repo.expect_save_batch().times(2).returning({
+            let saved = saved.clone();
+            move |users| {
+                saved.fetch_add(users.len(), Ordering::SeqCst);
+                Ok((1..=users.len() as i32).collect())
+            }
+        });
+
+        let sut = ImportUsersUseCase::new(mock_user_repo);
+
+        let first_batch =
+            (0..IMPORT_BATCH_SIZE).map(|index| row(&format!("user{index}@email.com")));
+        let last_row = stream::once({
+            let saved = saved.clone();
+            async move {
+                assert_eq!(saved.load(Ordering::SeqCst), IMPORT_BATCH_SIZE);
+                row("last@email.com")
+            }
+        });
+
+        let result = sut
+            .execute(&support(), stream::iter(first_batch).chain(last_row), false)
+            .await?;
+
+        assert_eq!(result.len(), IMPORT_BATCH_SIZE + 1);
+        assert_eq!(saved.load(Ordering::SeqCst), IMPORT_BATCH_SIZE + 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_falls_back_to_single_inserts_on_conflict()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_existing_emails()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_save_batch()
+            .times(1)
+            .return_const(Err(UserRepositoryError::Conflict(
+                "duplicate key".to_string(),
+            )));
+        mock_user_repo.expect_save().times(2).returning(|user| {
+            if user.email == "b@email.com" {
+                Err(UserRepositoryError::Conflict("duplicate key".to_string()))
+            } else {
+                Ok(42)
+            }
+        });
+
+        let sut = ImportUsersUseCase::new(mock_user_repo);
+
+        let result = sut
+            .execute(
+                &support(),
+                stream::iter(vec![row("a@email.com"), row("b@email.com")]),
+                false,
+            )
+            .await?;
+
+        assert_eq!(
+            result,
+            vec![
+                ImportRowResult {
+                    row: 1,
+                    outcome: ImportRowOutcome::Created(42),
+                },
+                ImportRowResult {
+                    row: 2,
+                    outcome: ImportRowOutcome::Duplicate("The email is already taken".to_string()),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+}
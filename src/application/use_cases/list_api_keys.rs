@@ -0,0 +1,89 @@
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{MANAGE_API_KEYS, authorize},
+        },
+        errors::api_key_application_error::ApiKeyApplicationError,
+    },
+    domain::{entities::api_key::ApiKey, repositories::api_key_repository::ApiKeyRepository},
+};
+
+pub struct ListApiKeysUseCase<T: ApiKeyRepository> {
+    api_key_repo: T,
+}
+
+impl<T: ApiKeyRepository> ListApiKeysUseCase<T> {
+    pub fn new(api_key_repo: T) -> Self {
+        Self { api_key_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+    ) -> Result<Vec<ApiKey>, ApiKeyApplicationError> {
+        authorize(principal, &MANAGE_API_KEYS, None)?;
+
+        self.api_key_repo.list().await.map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::api_key_application_error::ApiKeyApplicationError,
+            use_cases::list_api_keys::ListApiKeysUseCase,
+        },
+        domain::{
+            entities::api_key::ApiKey, repositories::api_key_repository::MockApiKeyRepository,
+        },
+    };
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo.expect_list().times(0);
+
+        let sut = ListApiKeysUseCase::new(mock_api_key_repo);
+
+        let principal = AuthenticatedPrincipal::new("1".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal).await;
+
+        assert!(matches!(result, Err(ApiKeyApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        let api_keys = vec![ApiKey::new(
+            "batch-jobs".to_string(),
+            "ak_0123abcd".to_string(),
+            "hash".to_string(),
+            vec![],
+            Utc::now(),
+        )];
+
+        mock_api_key_repo
+            .expect_list()
+            .times(1)
+            .return_const(Ok(api_keys.clone()));
+
+        let sut = ListApiKeysUseCase::new(mock_api_key_repo);
+
+        let principal =
+            AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()]);
+
+        let result = sut.execute(&principal).await?;
+
+        assert_eq!(result, api_keys);
+
+        Ok(())
+    }
+}
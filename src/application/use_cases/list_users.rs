@@ -0,0 +1,192 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{LIST_USERS, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        entities::user::User,
+        repositories::user_repository::UserRepository,
+        value_objects::{creation_range::CreationRange, user_cursor::UserCursor},
+    },
+};
+
+const DEFAULT_LIMIT: i64 = 100;
+pub const MAX_LIMIT: i64 = 1000;
+
+pub struct ListUsersUseCase<T: UserRepository> {
+    user_repo: T,
+}
+
+impl<T: UserRepository> ListUsersUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        created_from: Option<DateTime<Utc>>,
+        created_to: Option<DateTime<Utc>>,
+        after: Option<UserCursor>,
+        limit: Option<i64>,
+    ) -> Result<Vec<User>, UserApplicationError> {
+        authorize(principal, &LIST_USERS, None)?;
+
+        let range = CreationRange::new(created_from, created_to)?;
+
+        let limit = match limit {
+            None => DEFAULT_LIMIT,
+            Some(limit) if (1..=MAX_LIMIT).contains(&limit) => limit,
+            Some(limit) => {
+                return Err(UserApplicationError::Invalid(format!(
+                    "The limit must be between 1 and {MAX_LIMIT}, got {limit}"
+                )));
+            }
+        };
+
+        self.user_repo
+            .list(&range, after, limit)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::list_users::ListUsersUseCase,
+        },
+        domain::{
+            entities::user::User,
+            repositories::user_repository::MockUserRepository,
+            value_objects::{creation_range::CreationRange, user_cursor::UserCursor},
+        },
+    };
+
+    fn support() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()])
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_list().times(0);
+
+        let sut = ListUsersUseCase::new(mock_user_repo);
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, None, None, None, None).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_inverted_range() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_list().times(0);
+
+        let sut = ListUsersUseCase::new(mock_user_repo);
+
+        let from = Utc.with_ymd_and_hms(2025, 6, 8, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        let result = sut
+            .execute(&support(), Some(from), Some(to), None, None)
+            .await;
+
+        assert!(matches!(result, Err(UserApplicationError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_limit_out_of_bounds() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_list().times(0);
+
+        let sut = ListUsersUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), None, None, None, Some(0)).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Invalid(
+                "The limit must be between 1 and 1000, got 0".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let from = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        let users = vec![User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )?];
+
+        mock_user_repo
+            .expect_list()
+            .with(eq(CreationRange::new(Some(from), None)?), eq(None), eq(100))
+            .times(1)
+            .return_const(Ok(users.clone()));
+
+        let sut = ListUsersUseCase::new(mock_user_repo);
+
+        let result = sut
+            .execute(&support(), Some(from), None, None, None)
+            .await?;
+
+        assert_eq!(result, users);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_after_a_cursor() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let cursor = UserCursor {
+            created_at: Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+            id: 42,
+        };
+
+        mock_user_repo
+            .expect_list()
+            .with(
+                eq(CreationRange::default()),
+                eq(Some(cursor.clone())),
+                eq(10),
+            )
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let sut = ListUsersUseCase::new(mock_user_repo);
+
+        let result = sut
+            .execute(&support(), None, None, Some(cursor), Some(10))
+            .await?;
+
+        assert!(result.is_empty());
+
+        Ok(())
+    }
+}
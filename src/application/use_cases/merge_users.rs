@@ -0,0 +1,308 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{MERGE_USERS, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        entities::{user::User, user_merge::UserMerge},
+        repositories::user_repository::UserRepository,
+        services::{
+            clock::{Clock, SystemClock},
+            user_merger::merge_users,
+        },
+    },
+};
+
+pub struct MergeUsersUseCase<T: UserRepository> {
+    user_repo: T,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: UserRepository> MergeUsersUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self {
+            user_repo,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        survivor_id: i32,
+        duplicate_id: i32,
+    ) -> Result<User, UserApplicationError> {
+        authorize(principal, &MERGE_USERS, None)?;
+
+        if survivor_id == duplicate_id {
+            return Err(UserApplicationError::Invalid(format!(
+                "The user {survivor_id} cannot be merged into itself"
+            )));
+        }
+
+        let survivor = self.find_mergeable(survivor_id).await?;
+        let duplicate = self.find_mergeable(duplicate_id).await?;
+
+        let merged = merge_users(&survivor, &duplicate);
+
+        let mut tombstone = duplicate;
+        tombstone.tombstone();
+
+        let merge = UserMerge::new(
+            survivor_id,
+            duplicate_id,
+            principal.subject.clone(),
+            self.clock.now(),
+        );
+
+        self.user_repo.merge(&merged, &tombstone, &merge).await?;
+
+        Ok(merged)
+    }
+
+    async fn find_mergeable(&self, user_id: i32) -> Result<User, UserApplicationError> {
+        let user = self.user_repo.find_by_id(user_id).await?.ok_or_else(|| {
+            UserApplicationError::NotFound(format!("No user exists with ID {user_id}"))
+        })?;
+
+        if !self.user_repo.find_erasures(user_id).await?.is_empty() {
+            return Err(UserApplicationError::Conflict(format!(
+                "The user {user_id} has been erased"
+            )));
+        }
+
+        if let Some(merge) = self
+            .user_repo
+            .find_merges(user_id)
+            .await?
+            .into_iter()
+            .find(|merge| merge.merged_id == user_id)
+        {
+            return Err(UserApplicationError::Conflict(format!(
+                "The user {user_id} has already been merged into {}",
+                merge.survivor_id
+            )));
+        }
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, TimeZone, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::merge_users::MergeUsersUseCase,
+        },
+        domain::{
+            entities::{user::User, user_erasure::UserErasure, user_merge::UserMerge},
+            repositories::user_repository::MockUserRepository,
+            services::clock::MockClock,
+        },
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap()
+    }
+
+    fn clock() -> Arc<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now());
+        Arc::new(clock)
+    }
+
+    fn admin() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()])
+    }
+
+    fn user(id: i32, name: &str, email: &str) -> User {
+        User::restore(
+            id,
+            name.to_string(),
+            email.to_string(),
+            "+5511999990000".to_string(),
+            "12 Dawn Street".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_find_by_id().times(0);
+        mock_user_repo.expect_merge().times(0);
+
+        let sut = MergeUsersUseCase::new(mock_user_repo);
+
+        let principal =
+            AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()]);
+
+        let result = sut.execute(&principal, 1, 2).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_into_itself() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_merge().times(0);
+
+        let sut = MergeUsersUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&admin(), 1, 1).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Invalid(
+                "The user 1 cannot be merged into itself".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_not_found() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(1))
+            .times(1)
+            .return_const(Ok(None));
+        mock_user_repo.expect_merge().times(0);
+
+        let sut = MergeUsersUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&admin(), 1, 2).await;
+
+        assert!(matches!(result, Err(UserApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_erased_duplicate() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .returning(|id| Ok(Some(user(id, "Andrew", "andrew@email.com"))));
+        mock_user_repo
+            .expect_find_erasures()
+            .returning(|id| match id {
+                2 => Ok(vec![UserErasure::new(2, "1".to_string(), Utc::now())]),
+                _ => Ok(vec![]),
+            });
+        mock_user_repo
+            .expect_find_merges()
+            .returning(|_| Ok(vec![]));
+        mock_user_repo.expect_merge().times(0);
+
+        let sut = MergeUsersUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&admin(), 1, 2).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Conflict(
+                "The user 2 has been erased".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_already_merged() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .returning(|id| Ok(Some(user(id, "Andrew", "andrew@email.com"))));
+        mock_user_repo
+            .expect_find_erasures()
+            .returning(|_| Ok(vec![]));
+        mock_user_repo
+            .expect_find_merges()
+            .returning(|id| Ok(vec![UserMerge::new(3, id, "1".to_string(), Utc::now())]));
+        mock_user_repo.expect_merge().times(0);
+
+        let sut = MergeUsersUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&admin(), 1, 2).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Conflict(
+                "The user 1 has already been merged into 3".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(1))
+            .times(1)
+            .return_const(Ok(Some(user(1, "Andrew", "andrew@email.com"))));
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(2))
+            .times(1)
+            .return_const(Ok(Some(user(2, "Andrew Smith", "andrew.smith@email.com"))));
+        mock_user_repo
+            .expect_find_erasures()
+            .times(2)
+            .returning(|_| Ok(vec![]));
+        mock_user_repo
+            .expect_find_merges()
+            .times(2)
+            .returning(|survivor_id| {
+                Ok(vec![UserMerge::new(
+                    survivor_id,
+                    9,
+                    "1".to_string(),
+                    Utc::now(),
+                )])
+            });
+        mock_user_repo
+            .expect_merge()
+            .withf(|survivor: &User, merged: &User, merge: &UserMerge| {
+                survivor.name == "Andrew Smith"
+                    && survivor.email == "andrew@email.com"
+                    && merged.email == "merged-2@merged.invalid"
+                    && merge.survivor_id == 1
+                    && merge.merged_id == 2
+                    && merge.requested_by == "1"
+                    && merge.merged_at == now()
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = MergeUsersUseCase::new(mock_user_repo).with_clock(clock());
+
+        let merged = sut.execute(&admin(), 1, 2).await?;
+
+        assert_eq!(merged.name, "Andrew Smith");
+        assert_eq!(merged.email, "andrew@email.com");
+
+        Ok(())
+    }
+}
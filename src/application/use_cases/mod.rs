@@ -1,2 +1,19 @@
+pub mod authenticate_api_key;
+pub mod change_email;
+pub mod create_api_key;
+pub mod erase_user;
+pub mod export_user_data;
+pub mod export_users;
+pub mod find_duplicate_users;
 pub mod find_user_by_email;
+pub mod find_user_by_id;
+pub mod get_user_history;
+pub mod import_users;
+pub mod list_api_keys;
+pub mod list_users;
+pub mod merge_users;
 pub mod register_user;
+pub mod revoke_api_key;
+pub mod search_users;
+pub mod suspend_user;
+pub mod update_user;
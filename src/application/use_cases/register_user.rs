@@ -1,6 +1,9 @@
+use crate::application::auth::authenticated_principal::AuthenticatedPrincipal;
+use crate::application::auth::authorization::{WRITE_USER, authorize};
 use crate::application::errors::user_application_error::UserApplicationError;
 use crate::domain::entities::user::User;
 use crate::domain::repositories::user_repository::UserRepository;
+use crate::domain::services::pii_redactor::{PiiField, redact};
 use crate::presentation::dtos::user_dto::CreateUserDTO;
 
 pub struct RegisterUserUseCase<T: UserRepository> {
@@ -12,16 +15,24 @@ impl<T: UserRepository> RegisterUserUseCase<T> {
         Self { user_repo }
     }
 
-    pub async fn execute(&self, user: CreateUserDTO) -> Result<i32, UserApplicationError> {
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        user: CreateUserDTO,
+    ) -> Result<i32, UserApplicationError> {
+        authorize(principal, &WRITE_USER, None)?;
+
+        let user: User = user.into();
+
+        user.validate()?;
+
         if self.user_repo.exists_by_email(&user.email).await? {
             return Err(UserApplicationError::Conflict(format!(
                 "The email {} is already taken",
-                user.email
+                redact(PiiField::Email, &user.email)
             )));
         }
 
-        let user: User = user.into();
-
         self.user_repo.save(&user).await.map_err(|err| err.into())
     }
 }
@@ -30,6 +41,7 @@ impl<T: UserRepository> RegisterUserUseCase<T> {
 mod test {
     use crate::{
         application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
             errors::user_application_error::UserApplicationError,
             use_cases::register_user::RegisterUserUseCase,
         },
@@ -40,6 +52,59 @@ mod test {
         presentation::dtos::user_dto::CreateUserDTO,
     };
 
+    fn support() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["support".to_string()])
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_exists_by_email().times(0);
+        mock_user_repo.expect_save().times(0);
+
+        let sut = RegisterUserUseCase::new(mock_user_repo);
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![]);
+
+        let fake_user = CreateUserDTO {
+            name: "Andrew".to_string(),
+            email: "andrew@email.com".to_string(),
+            phone: "+001122223333".to_string(),
+            address: "Dawn St.".to_string(),
+        };
+
+        let result = sut.execute(&principal, fake_user).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_invalid_user() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_exists_by_email().times(0);
+        mock_user_repo.expect_save().times(0);
+
+        let sut = RegisterUserUseCase::new(mock_user_repo);
+
+        let fake_user = CreateUserDTO {
+            name: "Andrew".to_string(),
+            email: "not-an-email".to_string(),
+            phone: "+001122223333".to_string(),
+            address: "Dawn St.".to_string(),
+        };
+
+        let result = sut.execute(&support(), fake_user).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Invalid(
+                "The user has invalid fields: email is not a valid address".to_string()
+            ))
+        );
+    }
+
     #[tokio::test]
     async fn execute_user_repository_exists_by_email_error() {
         let mut mock_user_repo = MockUserRepository::new();
@@ -62,7 +127,7 @@ mod test {
 
         let sut = RegisterUserUseCase::new(mock_user_repo);
 
-        let result = sut.execute(fake_user.clone()).await;
+        let result = sut.execute(&support(), fake_user.clone()).await;
 
         assert!(result.is_err());
     }
@@ -89,16 +154,42 @@ mod test {
 
         let sut = RegisterUserUseCase::new(mock_user_repo);
 
-        let result = sut.execute(fake_user.clone()).await;
+        let result = sut.execute(&support(), fake_user.clone()).await;
 
         assert_eq!(
             result,
-            Err(UserApplicationError::Conflict(format!(
-                "The email {email} is already taken"
-            )))
+            Err(UserApplicationError::Conflict(
+                "The email a***@email.com is already taken".to_string()
+            ))
         )
     }
 
+    #[tokio::test]
+    async fn execute_email_taken_in_another_case() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let fake_user = CreateUserDTO {
+            name: "Andrew".to_string(),
+            email: "Andrew@Email.com".to_string(),
+            phone: "+001122223333".to_string(),
+            address: "Dawn St.".to_string(),
+        };
+
+        mock_user_repo
+            .expect_exists_by_email()
+            .withf(|expected_email: &str| expected_email == "andrew@email.com")
+            .times(1)
+            .return_const(Ok(true));
+
+        mock_user_repo.expect_save().times(0);
+
+        let sut = RegisterUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), fake_user).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Conflict(_))));
+    }
+
     #[tokio::test]
     async fn execute_user_repository_save_error() {
         let mut mock_user_repo = MockUserRepository::new();
@@ -121,7 +212,7 @@ mod test {
 
         let sut = RegisterUserUseCase::new(mock_user_repo);
 
-        let result = sut.execute(fake_user.clone()).await;
+        let result = sut.execute(&support(), fake_user.clone()).await;
 
         assert!(result.is_err());
     }
@@ -157,7 +248,7 @@ mod test {
 
         let sut = RegisterUserUseCase::new(mock_user_repo);
 
-        let result = sut.execute(fake_user.clone()).await?;
+        let result = sut.execute(&support(), fake_user.clone()).await?;
 
         assert_eq!(result, new_user_id);
 
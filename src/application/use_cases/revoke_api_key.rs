@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{MANAGE_API_KEYS, authorize},
+        },
+        errors::api_key_application_error::ApiKeyApplicationError,
+    },
+    domain::{
+        repositories::api_key_repository::ApiKeyRepository,
+        services::clock::{Clock, SystemClock},
+    },
+};
+
+pub struct RevokeApiKeyUseCase<T: ApiKeyRepository> {
+    api_key_repo: T,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: ApiKeyRepository> RevokeApiKeyUseCase<T> {
+    pub fn new(api_key_repo: T) -> Self {
+        Self {
+            api_key_repo,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        api_key_id: i32,
+    ) -> Result<(), ApiKeyApplicationError> {
+        authorize(principal, &MANAGE_API_KEYS, None)?;
+
+        if !self
+            .api_key_repo
+            .revoke(api_key_id, self.clock.now())
+            .await?
+        {
+            return Err(ApiKeyApplicationError::NotFound(api_key_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, TimeZone, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::api_key_application_error::ApiKeyApplicationError,
+            use_cases::revoke_api_key::RevokeApiKeyUseCase,
+        },
+        domain::{
+            repositories::api_key_repository::MockApiKeyRepository, services::clock::MockClock,
+        },
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap()
+    }
+
+    fn clock() -> Arc<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now());
+        Arc::new(clock)
+    }
+
+    fn admin() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()])
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo.expect_revoke().times(0);
+
+        let sut = RevokeApiKeyUseCase::new(mock_api_key_repo);
+
+        let principal = AuthenticatedPrincipal::new("1".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, 7).await;
+
+        assert!(matches!(result, Err(ApiKeyApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_not_found() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo
+            .expect_revoke()
+            .times(1)
+            .return_const(Ok(false));
+
+        let sut = RevokeApiKeyUseCase::new(mock_api_key_repo);
+
+        let result = sut.execute(&admin(), 7).await;
+
+        assert_eq!(result, Err(ApiKeyApplicationError::NotFound(7)));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() {
+        let mut mock_api_key_repo = MockApiKeyRepository::new();
+
+        mock_api_key_repo
+            .expect_revoke()
+            .with(eq(7), eq(now()))
+            .times(1)
+            .return_const(Ok(true));
+
+        let sut = RevokeApiKeyUseCase::new(mock_api_key_repo).with_clock(clock());
+
+        let result = sut.execute(&admin(), 7).await;
+
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{SEARCH_USERS, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        repositories::user_repository::UserRepository,
+        value_objects::user_search_hit::UserSearchHit,
+    },
+};
+
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+const MAX_QUERY_LENGTH: usize = 255;
+
+pub struct SearchUsersUseCase<T: UserRepository> {
+    user_repo: T,
+}
+
+impl<T: UserRepository> SearchUsersUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        query: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<UserSearchHit>, UserApplicationError> {
+        authorize(principal, &SEARCH_USERS, None)?;
+
+        let query = query.trim();
+
+        if query.is_empty() || query.chars().count() > MAX_QUERY_LENGTH {
+            return Err(UserApplicationError::Invalid(format!(
+                "The search query must have between 1 and {MAX_QUERY_LENGTH} characters"
+            )));
+        }
+
+        let limit = match limit {
+            None => DEFAULT_LIMIT,
+            Some(limit) if (1..=MAX_LIMIT).contains(&limit) => limit,
+            Some(limit) => {
+                return Err(UserApplicationError::Invalid(format!(
+                    "The limit must be between 1 and {MAX_LIMIT}, got {limit}"
+                )));
+            }
+        };
+
+        let hits = self.user_repo.search(query, limit).await?;
+
+        Ok(hits.into_iter().map(|hit| hit.highlighted(query)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use mockall::predicate::eq;
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::search_users::SearchUsersUseCase,
+        },
+        domain::{
+            entities::user::User, repositories::user_repository::MockUserRepository,
+            value_objects::user_search_hit::UserSearchHit,
+        },
+    };
+
+    fn support() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()])
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_search().times(0);
+
+        let sut = SearchUsersUseCase::new(mock_user_repo);
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, "andrew", None).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_blank_query() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_search().times(0);
+
+        let sut = SearchUsersUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), "   ", None).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Invalid(
+                "The search query must have between 1 and 255 characters".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_limit_out_of_bounds() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo.expect_search().times(0);
+
+        let sut = SearchUsersUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), "andrew", Some(101)).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let user = User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )?;
+
+        mock_user_repo
+            .expect_search()
+            .with(eq("andr"), eq(20))
+            .times(1)
+            .return_const(Ok(vec![UserSearchHit::new(user.clone(), 0.8)]));
+
+        let sut = SearchUsersUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), " andr ", None).await?;
+
+        assert_eq!(
+            result,
+            vec![UserSearchHit {
+                user,
+                score: 0.8,
+                highlights: BTreeMap::from([
+                    (
+                        "email".to_string(),
+                        "<mark>andr</mark>ew@email.com".to_string()
+                    ),
+                    ("name".to_string(), "<mark>Andr</mark>ew".to_string()),
+                ]),
+            }]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{SUSPEND_USER, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{
+        entities::user::User,
+        repositories::user_repository::UserRepository,
+        services::clock::{Clock, SystemClock},
+    },
+};
+
+pub struct SuspendUserUseCase<T: UserRepository> {
+    user_repo: T,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: UserRepository> SuspendUserUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self {
+            user_repo,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Suspends the user, or reinstates them when `suspended` is false.
+    /// Repeating either is a no-op that keeps the original suspension time.
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        user_id: i32,
+        suspended: bool,
+    ) -> Result<User, UserApplicationError> {
+        let user = self.user_repo.find_by_id(user_id).await?;
+
+        authorize(principal, &SUSPEND_USER, user.as_ref())?;
+
+        let user = user.ok_or_else(|| {
+            UserApplicationError::NotFound(format!("No user exists with ID {user_id}"))
+        })?;
+
+        if user.is_suspended() == suspended {
+            return Ok(user);
+        }
+
+        if !self.user_repo.find_erasures(user_id).await?.is_empty() {
+            return Err(UserApplicationError::Conflict(format!(
+                "The user {user_id} has been erased"
+            )));
+        }
+
+        if let Some(merge) = self
+            .user_repo
+            .find_merges(user_id)
+            .await?
+            .into_iter()
+            .find(|merge| merge.merged_id == user_id)
+        {
+            return Err(UserApplicationError::Conflict(format!(
+                "The user {user_id} has been merged into {}",
+                merge.survivor_id
+            )));
+        }
+
+        let mut updated = user.clone();
+
+        if suspended {
+            updated.suspend(self.clock.now());
+        } else {
+            updated.reinstate();
+        }
+
+        self.user_repo.update_suspension(&updated).await?;
+
+        Ok(self.user_repo.find_by_id(user_id).await?.unwrap_or(updated))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, TimeZone, Utc};
+    use mockall::{Sequence, predicate::eq};
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::suspend_user::SuspendUserUseCase,
+        },
+        domain::{
+            entities::{user::User, user_erasure::UserErasure},
+            repositories::user_repository::MockUserRepository,
+            services::clock::MockClock,
+        },
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap()
+    }
+
+    fn clock() -> Arc<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now());
+        Arc::new(clock)
+    }
+
+    fn support() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()])
+    }
+
+    fn fake_user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn suspended_user() -> User {
+        let mut user = fake_user();
+        user.suspend(Utc::now());
+        user
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden_for_the_user_themselves() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo.expect_update_suspension().never();
+
+        let sut = SuspendUserUseCase::new(mock_user_repo);
+
+        let result = sut
+            .execute(
+                &AuthenticatedPrincipal::new("42".to_string(), None, vec![]),
+                42,
+                false,
+            )
+            .await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_not_found() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+
+        let sut = SuspendUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), 42, true).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::NotFound(
+                "No user exists with ID 42".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_erased_user_is_a_conflict() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .times(1)
+            .return_const(Ok(vec![UserErasure::new(42, "1".to_string(), Utc::now())]));
+        mock_user_repo.expect_update_suspension().never();
+
+        let sut = SuspendUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), 42, true).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Conflict(
+                "The user 42 has been erased".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_suspends_the_user() -> Result<(), UserApplicationError> {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut seq = Sequence::new();
+        let stored = suspended_user();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_find_merges()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_update_suspension()
+            .withf(|user| user.suspended_at == Some(now()) && user.email == "andrew@email.com")
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_const(Ok(()));
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_const(Ok(Some(stored.clone())));
+
+        let sut = SuspendUserUseCase::new(mock_user_repo).with_clock(clock());
+
+        assert_eq!(sut.execute(&support(), 42, true).await?, stored);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_reinstates_the_user() -> Result<(), UserApplicationError> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(2)
+            .returning(|_| Ok(Some(suspended_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_find_merges()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_update_suspension()
+            .withf(|user| !user.is_suspended())
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = SuspendUserUseCase::new(mock_user_repo);
+
+        sut.execute(&support(), 42, false).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_already_suspended_is_a_no_op() -> Result<(), UserApplicationError> {
+        let mut mock_user_repo = MockUserRepository::new();
+        let stored = suspended_user();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(stored.clone())));
+        mock_user_repo.expect_update_suspension().never();
+
+        let sut = SuspendUserUseCase::new(mock_user_repo);
+
+        assert_eq!(sut.execute(&support(), 42, true).await?, stored);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal,
+            authorization::{UPDATE_USER, authorize},
+        },
+        errors::user_application_error::UserApplicationError,
+    },
+    domain::{entities::user::User, repositories::user_repository::UserRepository},
+    presentation::dtos::user_dto::UpdateUserDTO,
+};
+
+pub struct UpdateUserUseCase<T: UserRepository> {
+    user_repo: T,
+}
+
+impl<T: UserRepository> UpdateUserUseCase<T> {
+    pub fn new(user_repo: T) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        principal: &AuthenticatedPrincipal,
+        user_id: i32,
+        changes: UpdateUserDTO,
+    ) -> Result<User, UserApplicationError> {
+        let user = self.user_repo.find_by_id(user_id).await?;
+
+        authorize(principal, &UPDATE_USER, user.as_ref())?;
+
+        let user = user.ok_or_else(|| {
+            UserApplicationError::NotFound(format!("No user exists with ID {user_id}"))
+        })?;
+
+        if !self.user_repo.find_erasures(user_id).await?.is_empty() {
+            return Err(UserApplicationError::Conflict(format!(
+                "The user {user_id} has been erased"
+            )));
+        }
+
+        if let Some(merge) = self
+            .user_repo
+            .find_merges(user_id)
+            .await?
+            .into_iter()
+            .find(|merge| merge.merged_id == user_id)
+        {
+            return Err(UserApplicationError::Conflict(format!(
+                "The user {user_id} has been merged into {}",
+                merge.survivor_id
+            )));
+        }
+
+        let mut updated = user.clone();
+        updated.name = changes.name.unwrap_or(updated.name);
+        updated.phone = changes.phone.unwrap_or(updated.phone);
+        updated.address = changes.address.unwrap_or(updated.address);
+
+        if updated == user {
+            return Ok(user);
+        }
+
+        updated.validate()?;
+
+        self.user_repo.update_profile(&updated).await?;
+
+        Ok(self.user_repo.find_by_id(user_id).await?.unwrap_or(updated))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+    use mockall::{Sequence, predicate::eq};
+
+    use crate::{
+        application::{
+            auth::authenticated_principal::AuthenticatedPrincipal,
+            errors::user_application_error::UserApplicationError,
+            use_cases::update_user::UpdateUserUseCase,
+        },
+        domain::{
+            entities::{user::User, user_erasure::UserErasure, user_merge::UserMerge},
+            repositories::user_repository::MockUserRepository,
+        },
+        presentation::dtos::user_dto::UpdateUserDTO,
+    };
+
+    fn support() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("7".to_string(), None, vec!["support".to_string()])
+            .with_scopes(vec!["users:write".to_string()])
+    }
+
+    fn fake_user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn rename(name: &str) -> UpdateUserDTO {
+        UpdateUserDTO {
+            name: Some(name.to_string()),
+            ..UpdateUserDTO::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_forbidden_for_another_user() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo.expect_update_profile().times(0);
+
+        let sut = UpdateUserUseCase::new(mock_user_repo);
+
+        let principal = AuthenticatedPrincipal::new("7".to_string(), None, vec![]);
+
+        let result = sut.execute(&principal, 42, rename("Drew")).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_not_found() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+        mock_user_repo.expect_update_profile().times(0);
+
+        let sut = UpdateUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), 42, rename("Drew")).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::NotFound(
+                "No user exists with ID 42".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_erased_user() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .times(1)
+            .return_const(Ok(vec![UserErasure::new(42, "1".to_string(), Utc::now())]));
+        mock_user_repo.expect_update_profile().times(0);
+
+        let sut = UpdateUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), 42, rename("Drew")).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Conflict(
+                "The user 42 has been erased".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_merged_user() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .times(1)
+            .return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_find_merges()
+            .times(1)
+            .return_const(Ok(vec![UserMerge::new(7, 42, "1".to_string(), Utc::now())]));
+        mock_user_repo.expect_update_profile().times(0);
+
+        let sut = UpdateUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), 42, rename("Drew")).await;
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Conflict(
+                "The user 42 has been merged into 7".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_invalid_fields() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .return_const(Ok(vec![]));
+        mock_user_repo.expect_find_merges().return_const(Ok(vec![]));
+        mock_user_repo.expect_update_profile().times(0);
+
+        let sut = UpdateUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), 42, rename("  ")).await;
+
+        assert!(matches!(result, Err(UserApplicationError::Invalid(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_without_changes() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .return_const(Ok(vec![]));
+        mock_user_repo.expect_find_merges().return_const(Ok(vec![]));
+        mock_user_repo.expect_update_profile().times(0);
+
+        let sut = UpdateUserUseCase::new(mock_user_repo);
+
+        let result = sut.execute(&support(), 42, rename("Andrew")).await?;
+
+        assert_eq!(result, fake_user());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_ok_for_self() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+        let mut sequence = Sequence::new();
+
+        let mut updated = fake_user();
+        updated.address = "Dusk St.".to_string();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Ok(Some(fake_user())));
+        mock_user_repo
+            .expect_find_erasures()
+            .return_const(Ok(vec![]));
+        mock_user_repo.expect_find_merges().return_const(Ok(vec![]));
+        mock_user_repo
+            .expect_update_profile()
+            .with(eq(updated.clone()))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Ok(()));
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Ok(Some(updated.clone())));
+
+        let sut = UpdateUserUseCase::new(mock_user_repo);
+
+        let principal = AuthenticatedPrincipal::new("42".to_string(), None, vec![])
+            .with_scopes(vec!["users:write".to_string()]);
+        let changes = UpdateUserDTO {
+            address: Some("Dusk St.".to_string()),
+            ..UpdateUserDTO::default()
+        };
+
+        let result = sut.execute(&principal, 42, changes).await?;
+
+        assert_eq!(result, updated);
+
+        Ok(())
+    }
+}
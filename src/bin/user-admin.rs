@@ -0,0 +1,18 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+use dotenv::dotenv;
+use first_ddd_project_with_rust::presentation::cli::user_admin::{UserAdminCli, run};
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    dotenv().ok();
+
+    match run(UserAdminCli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
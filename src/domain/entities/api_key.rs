@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use diesel::{Queryable, prelude::Insertable};
+
+use crate::{domain::value_objects::id::ID, schema::api_keys};
+
+#[derive(Debug, Clone, Insertable, Queryable, PartialEq)]
+#[diesel(table_name = api_keys)]
+pub struct ApiKey {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub name: String,
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    pub fn new(
+        name: String,
+        key_prefix: String,
+        key_hash: String,
+        scopes: Vec<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: ID::New,
+            name,
+            key_prefix,
+            key_hash,
+            scopes,
+            created_at,
+            last_used_at: None,
+            revoked_at: None,
+        }
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use crate::domain::{entities::api_key::ApiKey, value_objects::id::ID};
+
+    #[test]
+    fn new() {
+        let created_at = Utc::now();
+
+        let api_key = ApiKey::new(
+            "batch-jobs".to_string(),
+            "ak_0123abcd".to_string(),
+            "hash".to_string(),
+            vec!["users:read".to_string()],
+            created_at,
+        );
+
+        assert_eq!(api_key.id, ID::New);
+        assert_eq!(api_key.name, "batch-jobs");
+        assert_eq!(api_key.key_prefix, "ak_0123abcd");
+        assert_eq!(api_key.key_hash, "hash");
+        assert_eq!(api_key.scopes, vec!["users:read".to_string()]);
+        assert_eq!(api_key.created_at, created_at);
+        assert_eq!(api_key.last_used_at, None);
+        assert!(!api_key.is_revoked());
+    }
+
+    #[test]
+    fn is_revoked() {
+        let mut api_key = ApiKey::new(
+            "batch-jobs".to_string(),
+            "ak_0123abcd".to_string(),
+            "hash".to_string(),
+            vec![],
+            Utc::now(),
+        );
+
+        api_key.revoked_at = Some(Utc::now());
+
+        assert!(api_key.is_revoked());
+    }
+}
@@ -1 +1,6 @@
+pub mod api_key;
+pub mod pending_email_change;
 pub mod user;
+pub mod user_audit_entry;
+pub mod user_erasure;
+pub mod user_merge;
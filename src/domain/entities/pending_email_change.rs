@@ -0,0 +1,107 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    services::pii_redactor::{PiiField, redact},
+    value_objects::id::ID,
+};
+
+#[derive(Clone, PartialEq)]
+pub struct PendingEmailChange {
+    pub id: ID,
+    pub user_id: i32,
+    pub new_email: String,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+}
+
+impl PendingEmailChange {
+    pub fn new(
+        user_id: i32,
+        new_email: String,
+        token_hash: String,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: ID::New,
+            user_id,
+            new_email,
+            token_hash,
+            expires_at,
+            created_at,
+            confirmed_at: None,
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+}
+
+impl fmt::Debug for PendingEmailChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PendingEmailChange")
+            .field("id", &self.id)
+            .field("user_id", &self.user_id)
+            .field("new_email", &redact(PiiField::Email, &self.new_email))
+            .field("expires_at", &self.expires_at)
+            .field("created_at", &self.created_at)
+            .field("confirmed_at", &self.confirmed_at)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, Utc};
+
+    use crate::domain::{
+        entities::pending_email_change::PendingEmailChange, value_objects::id::ID,
+    };
+
+    #[test]
+    fn new() {
+        let now = Utc::now();
+
+        let change = PendingEmailChange::new(
+            42,
+            "new@email.com".to_string(),
+            "hash".to_string(),
+            now,
+            now + Duration::hours(24),
+        );
+
+        assert_eq!(change.id, ID::New);
+        assert_eq!(change.user_id, 42);
+        assert_eq!(change.new_email, "new@email.com");
+        assert!(!change.is_confirmed());
+        assert!(!change.is_expired(now));
+        assert!(change.is_expired(now + Duration::hours(24)));
+    }
+
+    #[test]
+    fn debug_redacts_pii_and_token() {
+        let now = Utc::now();
+
+        let change = PendingEmailChange::new(
+            42,
+            "new@email.com".to_string(),
+            "hash".to_string(),
+            now,
+            now,
+        );
+
+        let debug = format!("{change:?}");
+
+        assert!(debug.contains("n***@email.com"));
+        assert!(!debug.contains("hash"));
+    }
+}
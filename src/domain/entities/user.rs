@@ -1,20 +1,39 @@
-use diesel::{Queryable, prelude::Insertable};
+use std::{fmt, sync::LazyLock};
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
 
 use crate::{
-    domain::{errors::user_entity_error::UserEntityError, value_objects::id::ID},
+    domain::{
+        errors::user_entity_error::UserEntityError,
+        services::{
+            email_normalizer::normalize_email,
+            pii_redactor::{PiiField, redact},
+        },
+        value_objects::id::ID,
+    },
     presentation::dtos::user_dto::CreateUserDTO,
-    schema::users,
 };
 
-#[derive(Debug, Clone, Insertable, Queryable, PartialEq)]
-#[diesel(table_name = users)]
+static EMAIL_FORMAT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap());
+
+static PHONE_FORMAT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\+?[\d\s()-]+$").unwrap());
+
+const MAX_FIELD_LENGTH: usize = 255;
+const MIN_PHONE_DIGITS: usize = 7;
+const MAX_PHONE_DIGITS: usize = 15;
+
+#[derive(Clone, PartialEq)]
 pub struct User {
-    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
     pub id: ID,
     pub name: String,
     pub email: String,
     pub phone: String,
     pub address: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub suspended_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -22,9 +41,12 @@ impl User {
         Self {
             id: ID::New,
             name,
-            email,
+            email: normalize_email(&email),
             phone,
             address,
+            created_at: None,
+            updated_at: None,
+            suspended_at: None,
         }
     }
 
@@ -45,8 +67,98 @@ impl User {
             email,
             phone,
             address,
+            created_at: None,
+            updated_at: None,
+            suspended_at: None,
         })
     }
+
+    pub fn validate(&self) -> Result<(), UserEntityError> {
+        let mut reasons = Vec::new();
+
+        for (field, value) in [
+            ("name", &self.name),
+            ("email", &self.email),
+            ("phone", &self.phone),
+            ("address", &self.address),
+        ] {
+            if value.trim().is_empty() {
+                reasons.push(format!("{field} must not be empty"));
+            } else if value.chars().count() > MAX_FIELD_LENGTH {
+                reasons.push(format!(
+                    "{field} must be at most {MAX_FIELD_LENGTH} characters long"
+                ));
+            }
+        }
+
+        if !self.email.is_empty() && !EMAIL_FORMAT.is_match(&self.email) {
+            reasons.push("email is not a valid address".to_string());
+        }
+
+        let phone_digits = self.phone.chars().filter(char::is_ascii_digit).count();
+
+        if !self.phone.is_empty()
+            && (!PHONE_FORMAT.is_match(&self.phone)
+                || !(MIN_PHONE_DIGITS..=MAX_PHONE_DIGITS).contains(&phone_digits))
+        {
+            reasons.push(format!(
+                "phone must have between {MIN_PHONE_DIGITS} and {MAX_PHONE_DIGITS} digits"
+            ));
+        }
+
+        if reasons.is_empty() {
+            Ok(())
+        } else {
+            Err(UserEntityError::InvalidFields(reasons))
+        }
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+
+    pub fn suspend(&mut self, at: DateTime<Utc>) {
+        self.suspended_at.get_or_insert(at);
+    }
+
+    pub fn reinstate(&mut self) {
+        self.suspended_at = None;
+    }
+
+    pub fn anonymize(&mut self) {
+        self.blank("Erased User", "erased");
+    }
+
+    pub fn tombstone(&mut self) {
+        self.blank("Merged User", "merged");
+    }
+
+    fn blank(&mut self, name: &str, label: &str) {
+        let suffix = match self.id {
+            ID::Existing(id) => id.to_string(),
+            ID::New => "new".to_string(),
+        };
+
+        self.name = name.to_string();
+        self.email = format!("{label}-{suffix}@{label}.invalid");
+        self.phone = String::new();
+        self.address = String::new();
+    }
+}
+
+impl fmt::Debug for User {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("User")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("email", &redact(PiiField::Email, &self.email))
+            .field("phone", &redact(PiiField::Phone, &self.phone))
+            .field("address", &redact(PiiField::Address, &self.address))
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .field("suspended_at", &self.suspended_at)
+            .finish()
+    }
 }
 
 impl From<CreateUserDTO> for User {
@@ -57,6 +169,8 @@ impl From<CreateUserDTO> for User {
 
 #[cfg(test)]
 mod test {
+    use chrono::{Duration, Utc};
+
     use crate::{
         domain::{
             entities::user::User, errors::user_entity_error::UserEntityError, value_objects::id::ID,
@@ -85,6 +199,18 @@ mod test {
         assert_eq!(user.address, address);
     }
 
+    #[test]
+    fn new_normalizes_email() {
+        let user = User::new(
+            "Andrew".to_string(),
+            " Andrew@Email.com".to_string(),
+            "+550011111-2222".to_string(),
+            "Dawn St.".to_string(),
+        );
+
+        assert_eq!(user.email, "andrew@email.com");
+    }
+
     #[test]
     fn restore_non_positive_id() {
         let id = 0;
@@ -141,4 +267,133 @@ mod test {
         assert_eq!(user.phone, dto.phone);
         assert_eq!(user.address, dto.address);
     }
+
+    #[test]
+    fn validate_ok() {
+        let user = User::new(
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+55 (11) 3333-4444".to_string(),
+            "Dawn St.".to_string(),
+        );
+
+        assert_eq!(user.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_reports_every_invalid_field() {
+        let user = User::new(
+            " ".to_string(),
+            "andrew.email.com".to_string(),
+            "call me".to_string(),
+            "".to_string(),
+        );
+
+        assert_eq!(
+            user.validate(),
+            Err(UserEntityError::InvalidFields(vec![
+                "name must not be empty".to_string(),
+                "address must not be empty".to_string(),
+                "email is not a valid address".to_string(),
+                "phone must have between 7 and 15 digits".to_string(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn validate_too_long_field() {
+        let user = User::new(
+            "A".repeat(256),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        );
+
+        assert_eq!(
+            user.validate(),
+            Err(UserEntityError::InvalidFields(vec![
+                "name must be at most 255 characters long".to_string()
+            ]))
+        );
+    }
+
+    #[test]
+    fn anonymize() {
+        let mut user = User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+550011111-2222".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap();
+
+        user.anonymize();
+
+        assert_eq!(user.id, ID::Existing(42));
+        assert_eq!(user.name, "Erased User");
+        assert_eq!(user.email, "erased-42@erased.invalid");
+        assert_eq!(user.phone, "");
+        assert_eq!(user.address, "");
+    }
+
+    #[test]
+    fn tombstone() {
+        let mut user = User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+550011111-2222".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap();
+
+        user.tombstone();
+
+        assert_eq!(user.id, ID::Existing(42));
+        assert_eq!(user.name, "Merged User");
+        assert_eq!(user.email, "merged-42@merged.invalid");
+        assert_eq!(user.phone, "");
+        assert_eq!(user.address, "");
+    }
+
+    #[test]
+    fn suspend_keeps_the_first_suspension_until_reinstated() {
+        let mut user = User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+550011111-2222".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap();
+        let at = Utc::now();
+
+        user.suspend(at);
+        user.suspend(at + Duration::hours(1));
+
+        assert!(user.is_suspended());
+        assert_eq!(user.suspended_at, Some(at));
+
+        user.reinstate();
+
+        assert!(!user.is_suspended());
+    }
+
+    #[test]
+    fn debug_redacts_pii() {
+        let user = User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+550011111-2222".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            format!("{user:?}"),
+            "User { id: Existing(42), name: \"Andrew\", email: \"a***@email.com\", phone: \"***22\", address: \"[redacted]\", created_at: None, updated_at: None, suspended_at: None }"
+        );
+    }
 }
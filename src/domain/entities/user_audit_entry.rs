@@ -0,0 +1,196 @@
+use chrono::{DateTime, Utc};
+use diesel::{Queryable, prelude::Insertable};
+use serde_json::{Map, Value, json};
+
+use crate::{
+    domain::{
+        entities::user::User,
+        services::pii_redactor::{PiiField, RedactionConfig},
+        value_objects::id::ID,
+    },
+    schema::user_audit_log,
+};
+
+// Every audited field is PII, and the log is append-only: storing any of them
+// in full would outlive an erasure, so the diff only keeps masked values.
+const AUDITED_FIELDS: [(&str, PiiField); 4] = [
+    ("name", PiiField::Name),
+    ("email", PiiField::Email),
+    ("phone", PiiField::Phone),
+    ("address", PiiField::Address),
+];
+
+#[derive(Debug, Clone, Insertable, Queryable, PartialEq)]
+#[diesel(table_name = user_audit_log)]
+pub struct UserAuditEntry {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub user_id: i32,
+    pub actor: String,
+    pub action: String,
+    pub request_id: Option<String>,
+    pub changes: Value,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl UserAuditEntry {
+    pub fn new(
+        user_id: i32,
+        actor: String,
+        action: &str,
+        request_id: Option<String>,
+        changes: Value,
+        recorded_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: ID::New,
+            user_id,
+            actor,
+            action: action.to_string(),
+            request_id,
+            changes,
+            recorded_at,
+        }
+    }
+
+    pub fn diff(before: Option<&User>, after: Option<&User>) -> Value {
+        let masked = RedactionConfig::default();
+
+        let mut changes: Map<String, Value> = AUDITED_FIELDS
+            .into_iter()
+            .zip(snapshot(before).into_iter().zip(snapshot(after)))
+            .filter_map(|((field, pii), (before, after))| {
+                if before == after {
+                    return None;
+                }
+
+                let mask = |value: &str| match value {
+                    "" => String::new(),
+                    value => masked.redact(pii, value),
+                };
+
+                Some((
+                    field.to_string(),
+                    json!({ "before": before.map(mask), "after": after.map(mask) }),
+                ))
+            })
+            .collect();
+
+        let suspended_at = |user: Option<&User>| user.and_then(|user| user.suspended_at);
+
+        if suspended_at(before) != suspended_at(after) {
+            changes.insert(
+                "suspended_at".to_string(),
+                json!({ "before": suspended_at(before), "after": suspended_at(after) }),
+            );
+        }
+
+        Value::Object(changes)
+    }
+}
+
+fn snapshot(user: Option<&User>) -> [Option<&str>; 4] {
+    match user {
+        Some(user) => [
+            Some(user.name.as_str()),
+            Some(user.email.as_str()),
+            Some(user.phone.as_str()),
+            Some(user.address.as_str()),
+        ],
+        None => [None; 4],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+
+    use crate::domain::{
+        entities::{user::User, user_audit_entry::UserAuditEntry},
+        value_objects::id::ID,
+    };
+
+    fn user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let recorded_at = Utc::now();
+
+        let entry = UserAuditEntry::new(
+            42,
+            "1".to_string(),
+            "create",
+            Some("req-1".to_string()),
+            json!({}),
+            recorded_at,
+        );
+
+        assert_eq!(entry.id, ID::New);
+        assert_eq!(entry.user_id, 42);
+        assert_eq!(entry.actor, "1");
+        assert_eq!(entry.action, "create");
+        assert_eq!(entry.request_id.as_deref(), Some("req-1"));
+        assert_eq!(entry.recorded_at, recorded_at);
+    }
+
+    #[test]
+    fn diff_on_creation_masks_pii() {
+        assert_eq!(
+            UserAuditEntry::diff(None, Some(&user())),
+            json!({
+                "name": { "before": null, "after": "[redacted]" },
+                "email": { "before": null, "after": "a***@email.com" },
+                "phone": { "before": null, "after": "***44" },
+                "address": { "before": null, "after": "[redacted]" },
+            })
+        );
+    }
+
+    #[test]
+    fn diff_only_includes_changed_fields() {
+        let mut after = user();
+        after.anonymize();
+        after.phone = user().phone;
+
+        assert_eq!(
+            UserAuditEntry::diff(Some(&user()), Some(&after)),
+            json!({
+                "name": { "before": "[redacted]", "after": "[redacted]" },
+                "email": { "before": "a***@email.com", "after": "e***@erased.invalid" },
+                "address": { "before": "[redacted]", "after": "" },
+            })
+        );
+    }
+
+    #[test]
+    fn diff_records_the_suspension() {
+        let suspended_at = Utc.with_ymd_and_hms(2025, 7, 12, 9, 30, 0).unwrap();
+        let mut after = user();
+        after.suspend(suspended_at);
+
+        assert_eq!(
+            UserAuditEntry::diff(Some(&user()), Some(&after)),
+            json!({
+                "suspended_at": { "before": null, "after": "2025-07-12T09:30:00Z" },
+            })
+        );
+    }
+
+    #[test]
+    fn diff_without_changes() {
+        assert_eq!(
+            UserAuditEntry::diff(Some(&user()), Some(&user())),
+            json!({})
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use diesel::{Queryable, prelude::Insertable};
+
+use crate::{domain::value_objects::id::ID, schema::user_erasures};
+
+#[derive(Debug, Clone, Insertable, Queryable, PartialEq)]
+#[diesel(table_name = user_erasures)]
+pub struct UserErasure {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub user_id: i32,
+    pub requested_by: String,
+    pub erased_at: DateTime<Utc>,
+}
+
+impl UserErasure {
+    pub fn new(user_id: i32, requested_by: String, erased_at: DateTime<Utc>) -> Self {
+        Self {
+            id: ID::New,
+            user_id,
+            requested_by,
+            erased_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use crate::domain::{entities::user_erasure::UserErasure, value_objects::id::ID};
+
+    #[test]
+    fn new() {
+        let erased_at = Utc::now();
+
+        let erasure = UserErasure::new(42, "1".to_string(), erased_at);
+
+        assert_eq!(erasure.id, ID::New);
+        assert_eq!(erasure.user_id, 42);
+        assert_eq!(erasure.requested_by, "1");
+        assert_eq!(erasure.erased_at, erased_at);
+    }
+}
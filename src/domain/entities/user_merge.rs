@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use diesel::{Queryable, prelude::Insertable};
+
+use crate::{domain::value_objects::id::ID, schema::user_merges};
+
+#[derive(Debug, Clone, Insertable, Queryable, PartialEq)]
+#[diesel(table_name = user_merges)]
+pub struct UserMerge {
+    #[diesel(serialize_as = Option<i32>, deserialize_as = i32)]
+    pub id: ID,
+    pub survivor_id: i32,
+    pub merged_id: i32,
+    pub requested_by: String,
+    pub merged_at: DateTime<Utc>,
+}
+
+impl UserMerge {
+    pub fn new(
+        survivor_id: i32,
+        merged_id: i32,
+        requested_by: String,
+        merged_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: ID::New,
+            survivor_id,
+            merged_id,
+            requested_by,
+            merged_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use crate::domain::{entities::user_merge::UserMerge, value_objects::id::ID};
+
+    #[test]
+    fn new() {
+        let merged_at = Utc::now();
+
+        let merge = UserMerge::new(42, 43, "1".to_string(), merged_at);
+
+        assert_eq!(merge.id, ID::New);
+        assert_eq!(merge.survivor_id, 42);
+        assert_eq!(merge.merged_id, 43);
+        assert_eq!(merge.requested_by, "1");
+        assert_eq!(merge.merged_at, merged_at);
+    }
+}
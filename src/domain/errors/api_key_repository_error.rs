@@ -0,0 +1,35 @@
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub enum ApiKeyRepositoryError {
+    DatabaseError(String),
+}
+
+impl fmt::Display for ApiKeyRepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiKeyRepositoryError::DatabaseError(msg) => {
+                write!(f, "A database error occurred when handling API keys: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApiKeyRepositoryError {}
+
+#[cfg(test)]
+mod test {
+    use super::ApiKeyRepositoryError;
+
+    #[test]
+    fn display() {
+        let error_msg = "Connection lost";
+        let err = ApiKeyRepositoryError::DatabaseError(error_msg.to_string());
+        let err = err.to_string();
+
+        assert_eq!(
+            err,
+            "A database error occurred when handling API keys: ".to_owned() + error_msg
+        );
+    }
+}
@@ -1,2 +1,3 @@
+pub mod api_key_repository_error;
 pub mod user_entity_error;
 pub mod user_repository_error;
@@ -3,6 +3,9 @@ use std::fmt;
 #[derive(Debug, PartialEq)]
 pub enum UserEntityError {
     InvalidId(i32),
+    InvalidCreationRange(String),
+    InvalidCursor(String),
+    InvalidFields(Vec<String>),
 }
 
 impl fmt::Display for UserEntityError {
@@ -11,6 +14,15 @@ impl fmt::Display for UserEntityError {
             UserEntityError::InvalidId(user_id) => {
                 write!(f, "An invalid ID was given for a user: {user_id}")
             }
+            UserEntityError::InvalidCreationRange(msg) => {
+                write!(f, "An invalid creation range was given for users: {msg}")
+            }
+            UserEntityError::InvalidCursor(cursor) => {
+                write!(f, "An invalid cursor was given for users: {cursor}")
+            }
+            UserEntityError::InvalidFields(reasons) => {
+                write!(f, "The user has invalid fields: {}", reasons.join("; "))
+            }
         }
     }
 }
@@ -32,4 +44,30 @@ mod test {
             format!("An invalid ID was given for a user: {user_id}")
         );
     }
+
+    #[test]
+    fn display_invalid_creation_range() {
+        let err_msg = "the start is after the end";
+        let err = UserEntityError::InvalidCreationRange(err_msg.to_string());
+        let err = err.to_string();
+
+        assert_eq!(
+            err,
+            format!("An invalid creation range was given for users: {err_msg}")
+        );
+    }
+
+    #[test]
+    fn display_invalid_fields() {
+        let err = UserEntityError::InvalidFields(vec![
+            "name must not be empty".to_string(),
+            "address must not be empty".to_string(),
+        ]);
+        let err = err.to_string();
+
+        assert_eq!(
+            err,
+            "The user has invalid fields: name must not be empty; address must not be empty"
+        );
+    }
 }
@@ -2,12 +2,16 @@ use std::fmt;
 
 #[derive(Debug, Clone)]
 pub enum UserRepositoryError {
+    Conflict(String),
     DatabaseError(String),
 }
 
 impl fmt::Display for UserRepositoryError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            UserRepositoryError::Conflict(msg) => {
+                write!(f, "A conflicting user already exists: {msg}")
+            }
             UserRepositoryError::DatabaseError(msg) => {
                 write!(f, "A database error occurred when handling users: {msg}")
             }
@@ -32,4 +36,16 @@ mod test {
             "A database error occurred when handling users: ".to_owned() + error_msg
         );
     }
+
+    #[test]
+    fn display_conflict() {
+        let error_msg = "duplicate email";
+        let err = UserRepositoryError::Conflict(error_msg.to_string());
+        let err = err.to_string();
+
+        assert_eq!(
+            err,
+            "A conflicting user already exists: ".to_owned() + error_msg
+        );
+    }
 }
@@ -0,0 +1,24 @@
+use crate::domain::{
+    entities::api_key::ApiKey, errors::api_key_repository_error::ApiKeyRepositoryError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait ApiKeyRepository {
+    async fn save(&self, api_key: &ApiKey) -> Result<i32, ApiKeyRepositoryError>;
+    async fn list(&self) -> Result<Vec<ApiKey>, ApiKeyRepositoryError>;
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, ApiKeyRepositoryError>;
+    async fn revoke(
+        &self,
+        id: i32,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<bool, ApiKeyRepositoryError>;
+    async fn touch_last_used(
+        &self,
+        id: i32,
+        last_used_at: DateTime<Utc>,
+    ) -> Result<(), ApiKeyRepositoryError>;
+}
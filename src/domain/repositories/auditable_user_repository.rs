@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    entities::{
+        user::User, user_audit_entry::UserAuditEntry, user_erasure::UserErasure,
+        user_merge::UserMerge,
+    },
+    errors::user_repository_error::UserRepositoryError,
+    repositories::user_repository::UserRepository,
+};
+
+/// Builds the audit entry of a write, given the user as it was before the write
+/// and as it was written.
+pub trait UserAuditor: Send + Sync {
+    fn entry(
+        &self,
+        user_id: i32,
+        action: &str,
+        before: Option<&User>,
+        after: &User,
+        recorded_at: DateTime<Utc>,
+    ) -> UserAuditEntry;
+}
+
+/// A backend that stores the entries of an auditor in the same transaction as
+/// the write they describe, so a change and its history commit together.
+#[async_trait]
+pub trait AuditableUserRepository: UserRepository {
+    async fn save_audited(
+        &self,
+        user: &User,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<i32, UserRepositoryError>;
+    async fn save_batch_audited(
+        &self,
+        users: &[User],
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<Vec<i32>, UserRepositoryError>;
+    async fn update_email_audited(
+        &self,
+        user: &User,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError>;
+    async fn update_profile_audited(
+        &self,
+        user: &User,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError>;
+    async fn update_suspension_audited(
+        &self,
+        user: &User,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError>;
+    async fn erase_audited(
+        &self,
+        user: &User,
+        erasure: &UserErasure,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError>;
+    async fn merge_audited(
+        &self,
+        survivor: &User,
+        merged: &User,
+        merge: &UserMerge,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError>;
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    entities::pending_email_change::PendingEmailChange,
+    errors::user_repository_error::UserRepositoryError,
+};
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait EmailChangeRepository {
+    async fn replace_pending(&self, change: &PendingEmailChange)
+    -> Result<(), UserRepositoryError>;
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PendingEmailChange>, UserRepositoryError>;
+    async fn mark_confirmed(
+        &self,
+        id: i32,
+        confirmed_at: DateTime<Utc>,
+    ) -> Result<bool, UserRepositoryError>;
+}
@@ -1 +1,5 @@
+pub mod api_key_repository;
+pub mod auditable_user_repository;
+pub mod email_change_repository;
+pub mod user_audit_repository;
 pub mod user_repository;
@@ -0,0 +1,11 @@
+use crate::domain::{
+    entities::user_audit_entry::UserAuditEntry, errors::user_repository_error::UserRepositoryError,
+};
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait UserAuditRepository {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<UserAuditEntry>, UserRepositoryError>;
+}
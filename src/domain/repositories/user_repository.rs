@@ -1,11 +1,57 @@
-use crate::domain::{entities::user::User, errors::user_repository_error::UserRepositoryError};
+use crate::domain::{
+    entities::{user::User, user_erasure::UserErasure, user_merge::UserMerge},
+    errors::user_repository_error::UserRepositoryError,
+    services::user_search::naive_search,
+    value_objects::{
+        creation_range::CreationRange, user_cursor::UserCursor, user_search_hit::UserSearchHit,
+    },
+};
 use async_trait::async_trait;
+use futures_util::stream::BoxStream;
 use mockall::automock;
 
+pub type UserBatchStream = BoxStream<'static, Result<Vec<User>, UserRepositoryError>>;
+
 #[automock]
 #[async_trait]
-pub trait UserRepository {
+pub trait UserRepository: Sync {
     async fn save(&self, user: &User) -> Result<i32, UserRepositoryError>;
+    async fn save_batch(&self, users: &[User]) -> Result<Vec<i32>, UserRepositoryError>;
     async fn exists_by_email(&self, email: &str) -> Result<bool, UserRepositoryError>;
     async fn find_by_email(&self, email: String) -> Result<Option<User>, UserRepositoryError>;
+    async fn find_existing_emails(
+        &self,
+        emails: &[String],
+    ) -> Result<Vec<String>, UserRepositoryError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<User>, UserRepositoryError>;
+    async fn list(
+        &self,
+        range: &CreationRange,
+        after: Option<UserCursor>,
+        limit: i64,
+    ) -> Result<Vec<User>, UserRepositoryError>;
+    async fn stream(
+        &self,
+        range: &CreationRange,
+        batch_size: usize,
+    ) -> Result<UserBatchStream, UserRepositoryError>;
+    async fn search(
+        &self,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<UserSearchHit>, UserRepositoryError> {
+        naive_search(self, query, limit).await
+    }
+    async fn update_email(&self, user: &User) -> Result<(), UserRepositoryError>;
+    async fn update_profile(&self, user: &User) -> Result<(), UserRepositoryError>;
+    async fn update_suspension(&self, user: &User) -> Result<(), UserRepositoryError>;
+    async fn erase(&self, user: &User, erasure: &UserErasure) -> Result<(), UserRepositoryError>;
+    async fn find_erasures(&self, user_id: i32) -> Result<Vec<UserErasure>, UserRepositoryError>;
+    async fn merge(
+        &self,
+        survivor: &User,
+        merged: &User,
+        merge: &UserMerge,
+    ) -> Result<(), UserRepositoryError>;
+    async fn find_merges(&self, user_id: i32) -> Result<Vec<UserMerge>, UserRepositoryError>;
 }
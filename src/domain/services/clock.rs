@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+#[automock]
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use super::{Clock, SystemClock};
+
+    #[test]
+    fn system_clock_now() {
+        let before = Utc::now();
+        let now = SystemClock.now();
+
+        assert!(now >= before && now <= Utc::now());
+    }
+}
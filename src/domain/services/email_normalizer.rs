@@ -0,0 +1,14 @@
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod test {
+    use super::normalize_email;
+
+    #[test]
+    fn normalize_email_lowercases_and_trims() {
+        assert_eq!(normalize_email(" Andrew@Email.COM "), "andrew@email.com");
+        assert_eq!(normalize_email("andrew@email.com"), "andrew@email.com");
+    }
+}
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait EmailNotifier {
+    async fn send_email_change_confirmation(&self, to: &str, token: &str) -> Result<(), String>;
+    async fn send_email_change_notice(&self, to: &str, new_email: &str) -> Result<(), String>;
+}
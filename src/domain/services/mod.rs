@@ -1 +1,7 @@
-
+pub mod clock;
+pub mod email_normalizer;
+pub mod email_notifier;
+pub mod phone_normalizer;
+pub mod pii_redactor;
+pub mod user_merger;
+pub mod user_search;
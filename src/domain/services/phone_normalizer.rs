@@ -0,0 +1,15 @@
+pub fn normalize_phone(phone: &str) -> String {
+    phone.chars().filter(char::is_ascii_digit).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::normalize_phone;
+
+    #[test]
+    fn normalize_phone_keeps_digits_only() {
+        assert_eq!(normalize_phone("+55 (11) 99999-0000"), "5511999990000");
+        assert_eq!(normalize_phone("5511999990000"), "5511999990000");
+        assert_eq!(normalize_phone(""), "");
+    }
+}
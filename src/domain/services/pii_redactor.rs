@@ -0,0 +1,262 @@
+use std::{
+    str::FromStr,
+    sync::{LazyLock, OnceLock},
+};
+
+use regex::{Captures, Regex};
+
+static EMAIL_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+(?:@|%40)[A-Za-z0-9.-]+\.[A-Za-z]{2,}").unwrap()
+});
+
+static PHONE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\+\d[\d\s()-]{6,}\d|\(\d{2,4}\)\s?\d[\d\s-]{4,}\d|\b\d{2,4}[\s-]\d{3,4}[\s-]\d{4}\b",
+    )
+    .unwrap()
+});
+
+const PHONE_MIN_DIGITS: usize = 9;
+
+static KEY_DETAIL_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\((phone|address)\)=\(([^)]*)\)").unwrap());
+
+static REDACTION_CONFIG: OnceLock<RedactionConfig> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PiiField {
+    Name,
+    Email,
+    Phone,
+    Address,
+}
+
+impl FromStr for PiiField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "name" => Ok(PiiField::Name),
+            "email" => Ok(PiiField::Email),
+            "phone" => Ok(PiiField::Phone),
+            "address" => Ok(PiiField::Address),
+            other => Err(format!("Unknown PII field: {other}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RedactionConfig {
+    allowed: Vec<PiiField>,
+}
+
+impl RedactionConfig {
+    pub fn new(allowed: Vec<PiiField>) -> Self {
+        Self { allowed }
+    }
+
+    pub fn from_allow_list(allow_list: &str) -> Result<Self, String> {
+        let allowed = allow_list
+            .split(',')
+            .filter(|field| !field.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<PiiField>, String>>()?;
+
+        Ok(Self::new(allowed))
+    }
+
+    pub fn is_allowed(&self, field: PiiField) -> bool {
+        self.allowed.contains(&field)
+    }
+
+    pub fn redact(&self, field: PiiField, value: &str) -> String {
+        if self.is_allowed(field) {
+            return value.to_string();
+        }
+
+        match field {
+            PiiField::Email => mask_email(value),
+            PiiField::Phone => mask_phone(value),
+            PiiField::Name | PiiField::Address => "[redacted]".to_string(),
+        }
+    }
+
+    pub fn redact_text(&self, text: &str) -> String {
+        let mut text = text.to_string();
+
+        if !self.is_allowed(PiiField::Email) {
+            text = EMAIL_PATTERN
+                .replace_all(&text, |caps: &Captures| mask_email(&caps[0]))
+                .into_owned();
+        }
+
+        text = KEY_DETAIL_PATTERN
+            .replace_all(&text, |caps: &Captures| {
+                let field = caps[1].parse().unwrap_or(PiiField::Address);
+                format!("({})=({})", &caps[1], self.redact(field, &caps[2]))
+            })
+            .into_owned();
+
+        if !self.is_allowed(PiiField::Phone) {
+            text = PHONE_PATTERN
+                .replace_all(&text, |caps: &Captures| {
+                    let digits = caps[0].chars().filter(char::is_ascii_digit).count();
+
+                    if digits < PHONE_MIN_DIGITS {
+                        return caps[0].to_string();
+                    }
+
+                    mask_phone(&caps[0])
+                })
+                .into_owned();
+        }
+
+        text
+    }
+}
+
+fn mask_email(email: &str) -> String {
+    let (local, domain) = email
+        .split_once('@')
+        .or_else(|| email.split_once("%40"))
+        .unwrap_or((email, ""));
+
+    let first = local.chars().next().map(String::from).unwrap_or_default();
+
+    if domain.is_empty() {
+        return format!("{first}***");
+    }
+
+    format!("{first}***@{domain}")
+}
+
+fn mask_phone(phone: &str) -> String {
+    let digits: Vec<char> = phone.chars().filter(char::is_ascii_digit).collect();
+    let visible: String = digits[digits.len().saturating_sub(2)..].iter().collect();
+
+    format!("***{visible}")
+}
+
+pub fn init(config: RedactionConfig) {
+    let _ = REDACTION_CONFIG.set(config);
+}
+
+pub fn config() -> &'static RedactionConfig {
+    REDACTION_CONFIG.get_or_init(RedactionConfig::default)
+}
+
+pub fn redact(field: PiiField, value: &str) -> String {
+    config().redact(field, value)
+}
+
+pub fn redact_text(text: &str) -> String {
+    config().redact_text(text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PiiField, RedactionConfig};
+
+    #[test]
+    fn from_allow_list() {
+        assert_eq!(
+            RedactionConfig::from_allow_list("email, phone"),
+            Ok(RedactionConfig::new(vec![PiiField::Email, PiiField::Phone]))
+        );
+        assert_eq!(
+            RedactionConfig::from_allow_list(""),
+            Ok(RedactionConfig::default())
+        );
+        assert_eq!(
+            RedactionConfig::from_allow_list("email,ssn"),
+            Err("Unknown PII field: ssn".to_string())
+        );
+    }
+
+    #[test]
+    fn redact_fields() {
+        let sut = RedactionConfig::default();
+
+        assert_eq!(
+            sut.redact(PiiField::Email, "andrew@email.com"),
+            "a***@email.com"
+        );
+        assert_eq!(sut.redact(PiiField::Phone, "+001133334444"), "***44");
+        assert_eq!(sut.redact(PiiField::Address, "Dawn St."), "[redacted]");
+        assert_eq!(sut.redact(PiiField::Name, "Andrew"), "[redacted]");
+    }
+
+    #[test]
+    fn redact_allowed_fields() {
+        let sut = RedactionConfig::new(vec![PiiField::Email]);
+
+        assert_eq!(
+            sut.redact(PiiField::Email, "andrew@email.com"),
+            "andrew@email.com"
+        );
+        assert_eq!(sut.redact(PiiField::Phone, "+001133334444"), "***44");
+    }
+
+    #[test]
+    fn redact_text() {
+        let sut = RedactionConfig::default();
+
+        assert_eq!(
+            sut.redact_text("GET /api/v1/users/andrew@email.com HTTP/1.1"),
+            "GET /api/v1/users/a***@email.com HTTP/1.1"
+        );
+        assert_eq!(
+            sut.redact_text("GET /api/v1/users/andrew%40email.com HTTP/1.1"),
+            "GET /api/v1/users/a***@email.com HTTP/1.1"
+        );
+        assert_eq!(
+            sut.redact_text("Key (phone)=(+55 11 3333-4444) already exists"),
+            "Key (phone)=(***44) already exists"
+        );
+        assert_eq!(
+            sut.redact_text("Key (address)=(Dawn St.) already exists"),
+            "Key (address)=([redacted]) already exists"
+        );
+        assert_eq!(sut.redact_text("Call +001133334444 now"), "Call ***44 now");
+    }
+
+    #[test]
+    fn redact_text_keeps_short_numbers() {
+        let sut = RedactionConfig::default();
+
+        assert_eq!(
+            sut.redact_text("127.0.0.1 GET /api/v1/api-keys/42 HTTP/1.1 took 0.001234"),
+            "127.0.0.1 GET /api/v1/api-keys/42 HTTP/1.1 took 0.001234"
+        );
+    }
+
+    #[test]
+    fn redact_text_grouped_phones() {
+        let sut = RedactionConfig::default();
+
+        assert_eq!(sut.redact_text("Call 555-123-4567"), "Call ***67");
+        assert_eq!(sut.redact_text("Call (11) 3333-4444"), "Call ***44");
+    }
+
+    #[test]
+    fn redact_text_keeps_timestamps() {
+        let sut = RedactionConfig::default();
+
+        for text in [
+            "Failed at 2025-06-14 12:00:00",
+            "Failed at 2025-06-14T12:00:00.123456+00:00",
+            "Cursor 1792394467:300015 expired",
+        ] {
+            assert_eq!(sut.redact_text(text), text);
+        }
+    }
+
+    #[test]
+    fn redact_text_allowed() {
+        let sut = RedactionConfig::new(vec![PiiField::Email, PiiField::Phone]);
+
+        let text = "andrew@email.com +001133334444";
+
+        assert_eq!(sut.redact_text(text), text);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::domain::entities::user::User;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MergeRule {
+    KeepSurvivor,
+    PreferLonger,
+    PreferMostRecent,
+}
+
+pub const NAME_RULE: MergeRule = MergeRule::PreferLonger;
+pub const EMAIL_RULE: MergeRule = MergeRule::KeepSurvivor;
+pub const PHONE_RULE: MergeRule = MergeRule::KeepSurvivor;
+pub const ADDRESS_RULE: MergeRule = MergeRule::PreferMostRecent;
+
+impl MergeRule {
+    pub fn apply(&self, kept: &str, other: &str, other_is_newer: bool) -> String {
+        let (kept, other) = (kept.trim(), other.trim());
+
+        let take_other = match self {
+            _ if other.is_empty() => false,
+            _ if kept.is_empty() => true,
+            MergeRule::KeepSurvivor => false,
+            MergeRule::PreferLonger => other.chars().count() > kept.chars().count(),
+            MergeRule::PreferMostRecent => other_is_newer,
+        };
+
+        if take_other { other } else { kept }.to_string()
+    }
+}
+
+pub fn merge_users(survivor: &User, duplicate: &User) -> User {
+    let duplicate_is_newer = duplicate.updated_at > survivor.updated_at;
+
+    let mut merged = survivor.clone();
+
+    merged.name = NAME_RULE.apply(&survivor.name, &duplicate.name, duplicate_is_newer);
+    merged.email = EMAIL_RULE.apply(&survivor.email, &duplicate.email, duplicate_is_newer);
+    merged.phone = PHONE_RULE.apply(&survivor.phone, &duplicate.phone, duplicate_is_newer);
+    merged.address = ADDRESS_RULE.apply(&survivor.address, &duplicate.address, duplicate_is_newer);
+
+    merged
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+
+    use crate::domain::{
+        entities::user::User,
+        services::user_merger::{MergeRule, merge_users},
+        value_objects::id::ID,
+    };
+
+    fn user(id: i32, name: &str, email: &str, phone: &str, address: &str, day: u32) -> User {
+        let mut user = User::restore(
+            id,
+            name.to_string(),
+            email.to_string(),
+            phone.to_string(),
+            address.to_string(),
+        )
+        .unwrap();
+
+        user.updated_at = Some(Utc.with_ymd_and_hms(2025, 6, day, 0, 0, 0).unwrap());
+
+        user
+    }
+
+    #[test]
+    fn apply() {
+        assert_eq!(MergeRule::KeepSurvivor.apply("a", "b", true), "a");
+        assert_eq!(MergeRule::KeepSurvivor.apply(" ", "b", false), "b");
+        assert_eq!(
+            MergeRule::PreferLonger.apply("Andrew", "Andrew Smith", false),
+            "Andrew Smith"
+        );
+        assert_eq!(
+            MergeRule::PreferLonger.apply("Andrew Smith", "Andrew", true),
+            "Andrew Smith"
+        );
+        assert_eq!(
+            MergeRule::PreferMostRecent.apply("Dawn St.", "Dusk Av.", true),
+            "Dusk Av."
+        );
+        assert_eq!(
+            MergeRule::PreferMostRecent.apply("Dawn St.", "Dusk Av.", false),
+            "Dawn St."
+        );
+        assert_eq!(
+            MergeRule::PreferMostRecent.apply("Dawn St.", "", true),
+            "Dawn St."
+        );
+    }
+
+    #[test]
+    fn merge_users_applies_field_rules() {
+        let survivor = user(1, "Andrew", "andrew@email.com", "", "Dawn St.", 1);
+        let duplicate = user(
+            2,
+            "Andrew Smith",
+            "andrew.smith@email.com",
+            "+5511999990000",
+            "Dusk Av.",
+            8,
+        );
+
+        let merged = merge_users(&survivor, &duplicate);
+
+        assert_eq!(merged.id, ID::Existing(1));
+        assert_eq!(merged.name, "Andrew Smith");
+        assert_eq!(merged.email, "andrew@email.com");
+        assert_eq!(merged.phone, "+5511999990000");
+        assert_eq!(merged.address, "Dusk Av.");
+    }
+}
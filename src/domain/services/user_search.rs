@@ -0,0 +1,258 @@
+use std::collections::HashSet;
+
+use futures_util::StreamExt;
+
+use crate::domain::{
+    entities::user::User,
+    errors::user_repository_error::UserRepositoryError,
+    repositories::user_repository::UserRepository,
+    value_objects::{creation_range::CreationRange, user_search_hit::UserSearchHit},
+};
+
+pub const MIN_SIMILARITY: f64 = 0.3;
+
+const SCAN_BATCH_SIZE: usize = 1000;
+
+pub fn search_terms(query: &str) -> Vec<String> {
+    words(query)
+        .into_iter()
+        .map(|(_, word)| word.to_lowercase())
+        .collect()
+}
+
+pub fn score(query: &str, user: &User) -> f64 {
+    let terms = search_terms(query);
+
+    if terms.is_empty() {
+        return 0.0;
+    }
+
+    field_score(&terms, &user.name).max(similarity(local_part(query), local_part(&user.email)))
+}
+
+pub fn highlight(query: &str, value: &str) -> Option<String> {
+    let terms = search_terms(query);
+
+    let mut fragment = String::new();
+    let mut cursor = 0;
+    let mut matched = false;
+
+    for (start, word) in words(value) {
+        let Some((from, to)) = match_in_word(&terms, word) else {
+            continue;
+        };
+
+        fragment.push_str(&escape(&value[cursor..start + from]));
+        fragment.push_str("<mark>");
+        fragment.push_str(&escape(&word[from..to]));
+        fragment.push_str("</mark>");
+
+        cursor = start + to;
+        matched = true;
+    }
+
+    fragment.push_str(&escape(&value[cursor..]));
+
+    matched.then_some(fragment)
+}
+
+pub async fn naive_search<R: UserRepository + ?Sized>(
+    user_repo: &R,
+    query: &str,
+    limit: i64,
+) -> Result<Vec<UserSearchHit>, UserRepositoryError> {
+    let limit = usize::try_from(limit).unwrap_or_default();
+
+    let mut batches = user_repo
+        .stream(&CreationRange::default(), SCAN_BATCH_SIZE)
+        .await?;
+
+    let mut hits = vec![];
+
+    while let Some(batch) = batches.next().await {
+        hits.extend(batch?.into_iter().filter_map(|user| {
+            let score = score(query, &user);
+            (score >= MIN_SIMILARITY).then(|| UserSearchHit::new(user, score))
+        }));
+
+        UserSearchHit::rank(&mut hits, limit);
+    }
+
+    Ok(hits)
+}
+
+fn words(value: &str) -> Vec<(usize, &str)> {
+    let mut words = vec![];
+    let mut start = None;
+
+    for (index, c) in value.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(from)) => {
+                words.push((from, &value[from..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(from) = start {
+        words.push((from, &value[from..]));
+    }
+
+    words
+}
+
+fn local_part(email: &str) -> &str {
+    email.trim().split('@').next().unwrap_or_default()
+}
+
+fn trigrams(value: &str) -> HashSet<[char; 3]> {
+    search_terms(value)
+        .iter()
+        .flat_map(|word| {
+            let padded: Vec<char> = format!("  {word} ").chars().collect();
+
+            padded
+                .windows(3)
+                .map(|window| [window[0], window[1], window[2]])
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (trigrams(a), trigrams(b));
+    let union = a.union(&b).count();
+
+    if union == 0 {
+        return 0.0;
+    }
+
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+fn term_score(term: &str, word: &str) -> f64 {
+    if word.to_lowercase().contains(term) {
+        1.0
+    } else {
+        similarity(term, word)
+    }
+}
+
+fn field_score(terms: &[String], value: &str) -> f64 {
+    let words = words(value);
+
+    let total: f64 = terms
+        .iter()
+        .map(|term| {
+            words
+                .iter()
+                .map(|(_, word)| term_score(term, word))
+                .fold(0.0, f64::max)
+        })
+        .sum();
+
+    total / terms.len() as f64
+}
+
+fn match_in_word(terms: &[String], word: &str) -> Option<(usize, usize)> {
+    let lowercase = word.to_lowercase();
+
+    for term in terms {
+        if let Some(from) = lowercase.find(term.as_str())
+            && word.is_char_boundary(from)
+            && word.is_char_boundary(from + term.len())
+            && lowercase.len() == word.len()
+        {
+            return Some((from, from + term.len()));
+        }
+    }
+
+    terms
+        .iter()
+        .any(|term| similarity(term, word) >= MIN_SIMILARITY)
+        .then_some((0, word.len()))
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::{StreamExt, stream};
+
+    use crate::domain::{
+        entities::user::User,
+        repositories::user_repository::MockUserRepository,
+        services::user_search::{highlight, naive_search, score},
+    };
+
+    fn user(id: i32, name: &str, email: &str) -> User {
+        User::restore(
+            id,
+            name.to_string(),
+            email.to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn score_partial_and_misspelled() {
+        let andrew = user(1, "Andrew Smith", "andrew.smith@email.com");
+
+        assert_eq!(score("andr", &andrew), 1.0);
+        assert!(score("smiht", &andrew) >= 0.3);
+        assert!(score("andrew.smtih@email.com", &andrew) > score("bea", &andrew));
+        assert!(score("zoe", &andrew) < 0.3);
+        assert_eq!(score("", &andrew), 0.0);
+    }
+
+    #[test]
+    fn highlight_substrings_and_fuzzy_words() {
+        assert_eq!(
+            highlight("smi andrw", "Andrew Smith"),
+            Some("<mark>Andrew</mark> <mark>Smi</mark>th".to_string())
+        );
+        assert_eq!(
+            highlight("<b>", "<b>Bea</b>"),
+            Some("&lt;<mark>b</mark>&gt;<mark>B</mark>ea&lt;/<mark>b</mark>&gt;".to_string())
+        );
+        assert_eq!(highlight("zoe", "Andrew Smith"), None);
+    }
+
+    #[tokio::test]
+    async fn naive_search_ranks_every_batch() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let batches = vec![
+            Ok(vec![
+                user(1, "Andrew Smith", "andrew@email.com"),
+                user(2, "Bea Andrews", "bea@email.com"),
+            ]),
+            Ok(vec![
+                user(3, "Zoe", "zoe@email.com"),
+                user(4, "Andrea Smyth", "andrea@email.com"),
+            ]),
+        ];
+
+        mock_user_repo
+            .expect_stream()
+            .times(1)
+            .return_once(move |_, _| Ok(stream::iter(batches).boxed()));
+
+        let hits = naive_search(&mock_user_repo, "andrew smyth", 2).await?;
+
+        let names: Vec<&str> = hits.iter().map(|hit| hit.user.name.as_str()).collect();
+
+        assert_eq!(names, vec!["Andrea Smyth", "Andrew Smith"]);
+
+        Ok(())
+    }
+}
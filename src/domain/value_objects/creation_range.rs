@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::errors::user_entity_error::UserEntityError;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CreationRange {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl CreationRange {
+    pub fn new(
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Self, UserEntityError> {
+        if let (Some(from), Some(to)) = (from, to)
+            && from > to
+        {
+            return Err(UserEntityError::InvalidCreationRange(format!(
+                "{from} is after {to}"
+            )));
+        }
+
+        Ok(Self { from, to })
+    }
+
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        self.from.is_none_or(|from| instant >= from) && self.to.is_none_or(|to| instant < to)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+
+    use crate::domain::{
+        errors::user_entity_error::UserEntityError, value_objects::creation_range::CreationRange,
+    };
+
+    #[test]
+    fn new_inverted_range() {
+        let from = Utc.with_ymd_and_hms(2025, 6, 8, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        let result = CreationRange::new(Some(from), Some(to));
+
+        assert_eq!(
+            result,
+            Err(UserEntityError::InvalidCreationRange(
+                "2025-06-08 00:00:00 UTC is after 2025-06-01 00:00:00 UTC".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn contains_is_half_open() -> Result<(), UserEntityError> {
+        let from = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2025, 6, 8, 0, 0, 0).unwrap();
+
+        let sut = CreationRange::new(Some(from), Some(to))?;
+
+        assert!(sut.contains(from));
+        assert!(sut.contains(Utc.with_ymd_and_hms(2025, 6, 7, 23, 59, 59).unwrap()));
+        assert!(!sut.contains(to));
+        assert!(CreationRange::default().contains(to));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::user::User,
+    services::{phone_normalizer::normalize_phone, user_search::similarity},
+    value_objects::id::ID,
+};
+
+pub const MIN_DUPLICATE_SIMILARITY: f64 = 0.4;
+
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub struct DuplicateCandidate {
+    pub user_ids: [i32; 2],
+    pub name_similarity: f64,
+    pub address_similarity: f64,
+}
+
+impl DuplicateCandidate {
+    pub fn detect(users: impl IntoIterator<Item = User>) -> Vec<Self> {
+        let mut detector = DuplicateDetector::default();
+        detector.add(users);
+        detector.finish()
+    }
+}
+
+/// Groups users by normalized phone as they arrive, keeping only what the
+/// comparison needs so a full scan does not hold every user in memory.
+#[derive(Default)]
+pub struct DuplicateDetector {
+    by_phone: HashMap<String, Vec<(i32, String, String)>>,
+    candidates: Vec<DuplicateCandidate>,
+}
+
+impl DuplicateDetector {
+    pub fn add(&mut self, users: impl IntoIterator<Item = User>) {
+        for user in users {
+            let ID::Existing(user_id) = user.id else {
+                continue;
+            };
+
+            let phone = normalize_phone(&user.phone);
+
+            if phone.is_empty() {
+                continue;
+            }
+
+            let group = self.by_phone.entry(phone).or_default();
+
+            for (other_id, other_name, other_address) in group.iter() {
+                let name_similarity = similarity(other_name, &user.name);
+                let address_similarity = similarity(other_address, &user.address);
+
+                if name_similarity >= MIN_DUPLICATE_SIMILARITY
+                    && address_similarity >= MIN_DUPLICATE_SIMILARITY
+                {
+                    self.candidates.push(DuplicateCandidate {
+                        user_ids: [user_id.min(*other_id), user_id.max(*other_id)],
+                        name_similarity,
+                        address_similarity,
+                    });
+                }
+            }
+
+            group.push((user_id, user.name, user.address));
+        }
+    }
+
+    pub fn finish(mut self) -> Vec<DuplicateCandidate> {
+        self.candidates.sort_by_key(|candidate| candidate.user_ids);
+        self.candidates
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::entities::user::User;
+
+    use super::{DuplicateCandidate, DuplicateDetector};
+
+    fn user(id: i32, name: &str, phone: &str, address: &str) -> User {
+        User::restore(
+            id,
+            name.to_string(),
+            format!("user{id}@email.com"),
+            phone.to_string(),
+            address.to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn detect() {
+        let users = vec![
+            user(3, "Andrew Smith", "+55 (11) 99999-0000", "12 Dawn Street"),
+            user(1, "Andrew Smyth", "5511999990000", "12 Dawn St."),
+            user(2, "Bea Souza", "+5511999990000", "40 Dusk Avenue"),
+            user(4, "Andrew Smith", "+5511888880000", "12 Dawn Street"),
+            user(5, "Erased User", "", ""),
+        ];
+
+        let candidates = DuplicateCandidate::detect(users);
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].user_ids, [1, 3]);
+        assert!(candidates[0].name_similarity >= 0.4);
+        assert!(candidates[0].address_similarity >= 0.4);
+    }
+
+    #[test]
+    fn detect_without_candidates() {
+        let users = vec![
+            user(1, "Andrew Smith", "+5511999990000", "12 Dawn Street"),
+            user(2, "Andrew Smith", "+5511888880000", "12 Dawn Street"),
+        ];
+
+        assert!(DuplicateCandidate::detect(users).is_empty());
+    }
+
+    #[test]
+    fn detector_matches_across_batches() {
+        let mut sut = DuplicateDetector::default();
+
+        sut.add(vec![user(
+            3,
+            "Andrew Smith",
+            "+55 (11) 99999-0000",
+            "12 Dawn Street",
+        )]);
+        sut.add(vec![
+            user(2, "Bea Souza", "+5511999990000", "40 Dusk Avenue"),
+            user(1, "Andrew Smyth", "5511999990000", "12 Dawn St."),
+        ]);
+
+        let candidates = sut.finish();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].user_ids, [1, 3]);
+    }
+}
@@ -1 +1,5 @@
+pub mod creation_range;
+pub mod duplicate_candidate;
 pub mod id;
+pub mod user_cursor;
+pub mod user_search_hit;
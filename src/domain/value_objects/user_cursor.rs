@@ -0,0 +1,109 @@
+use std::{fmt, str::FromStr};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
+
+use crate::domain::{entities::user::User, errors::user_entity_error::UserEntityError};
+
+use super::id::ID;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: i32,
+}
+
+impl UserCursor {
+    pub fn of(user: &User) -> Option<Self> {
+        match (&user.id, user.created_at) {
+            (ID::Existing(id), Some(created_at)) => Some(Self {
+                created_at,
+                id: *id,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for UserCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let raw = format!("{}:{}", self.created_at.timestamp_micros(), self.id);
+
+        write!(f, "{}", URL_SAFE_NO_PAD.encode(raw))
+    }
+}
+
+impl FromStr for UserCursor {
+    type Err = UserEntityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || UserEntityError::InvalidCursor(s.to_string());
+
+        let raw = URL_SAFE_NO_PAD.decode(s).map_err(|_| invalid())?;
+        let raw = String::from_utf8(raw).map_err(|_| invalid())?;
+        let (micros, id) = raw.split_once(':').ok_or_else(invalid)?;
+
+        Ok(Self {
+            created_at: micros
+                .parse()
+                .ok()
+                .and_then(DateTime::from_timestamp_micros)
+                .ok_or_else(invalid)?,
+            id: id.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+
+    use crate::domain::{
+        entities::user::User, errors::user_entity_error::UserEntityError,
+        value_objects::user_cursor::UserCursor,
+    };
+
+    #[test]
+    fn round_trips_through_its_string_form() -> Result<(), UserEntityError> {
+        let cursor = UserCursor {
+            created_at: Utc.with_ymd_and_hms(2025, 6, 1, 12, 30, 0).unwrap(),
+            id: 42,
+        };
+
+        assert_eq!(cursor.to_string().parse::<UserCursor>()?, cursor);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_cursors() {
+        for cursor in ["", "not base64!", "MTIz", "YWJjOmRlZg"] {
+            assert_eq!(
+                cursor.parse::<UserCursor>(),
+                Err(UserEntityError::InvalidCursor(cursor.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn of_a_stored_user_only() {
+        let mut user = User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(UserCursor::of(&user), None);
+
+        let created_at = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        user.created_at = Some(created_at);
+
+        assert_eq!(
+            UserCursor::of(&user),
+            Some(UserCursor { created_at, id: 42 })
+        );
+    }
+}
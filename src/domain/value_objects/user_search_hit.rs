@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+
+use crate::domain::{entities::user::User, services::user_search::highlight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSearchHit {
+    pub user: User,
+    pub score: f64,
+    pub highlights: BTreeMap<String, String>,
+}
+
+impl UserSearchHit {
+    pub fn new(user: User, score: f64) -> Self {
+        Self {
+            user,
+            score,
+            highlights: BTreeMap::new(),
+        }
+    }
+
+    pub fn highlighted(mut self, query: &str) -> Self {
+        for (field, value) in [("name", &self.user.name), ("email", &self.user.email)] {
+            if let Some(fragment) = highlight(query, value) {
+                self.highlights.insert(field.to_string(), fragment);
+            }
+        }
+
+        self
+    }
+
+    pub fn rank(hits: &mut Vec<Self>, limit: usize) {
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(limit);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use crate::domain::{entities::user::User, value_objects::user_search_hit::UserSearchHit};
+
+    fn user(id: i32, name: &str) -> User {
+        User::restore(
+            id,
+            name.to_string(),
+            format!("user{id}@email.com"),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn highlighted() {
+        let hit = UserSearchHit::new(user(1, "Andrew Smith"), 1.0).highlighted("andr");
+
+        assert_eq!(
+            hit.highlights,
+            BTreeMap::from([("name".to_string(), "<mark>Andr</mark>ew Smith".to_string())])
+        );
+    }
+
+    #[test]
+    fn rank_keeps_the_best_hits_in_a_stable_order() {
+        let mut hits = vec![
+            UserSearchHit::new(user(1, "a"), 0.4),
+            UserSearchHit::new(user(2, "b"), 0.9),
+            UserSearchHit::new(user(3, "c"), 0.4),
+            UserSearchHit::new(user(4, "d"), 0.1),
+        ];
+
+        UserSearchHit::rank(&mut hits, 3);
+
+        let ranked: Vec<String> = hits.into_iter().map(|hit| hit.user.name).collect();
+
+        assert_eq!(ranked, vec!["b", "a", "c"]);
+    }
+}
@@ -0,0 +1,502 @@
+use std::fs;
+
+use jsonwebtoken::{
+    Algorithm, DecodingKey, Validation, decode, decode_header,
+    jwk::{AlgorithmParameters, EllipticCurve, Jwk, JwkSet},
+};
+use serde::Deserialize;
+
+use crate::application::{
+    auth::authenticated_principal::AuthenticatedPrincipal,
+    errors::authentication_error::AuthenticationError,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct JwtClaims {
+    pub sub: String,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub scope: String,
+    pub exp: usize,
+}
+
+impl From<JwtClaims> for AuthenticatedPrincipal {
+    fn from(value: JwtClaims) -> Self {
+        let scopes = value.scope.split_whitespace().map(str::to_string).collect();
+
+        Self::new(value.sub, value.email, value.roles).with_scopes(scopes)
+    }
+}
+
+const ALL_ALGORITHMS: [Algorithm; 12] = [
+    Algorithm::HS256,
+    Algorithm::HS384,
+    Algorithm::HS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::EdDSA,
+];
+
+struct JwtKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    decoding_key: DecodingKey,
+}
+
+pub struct JwtValidator {
+    keys: Vec<JwtKey>,
+    issuer: Option<String>,
+    audience: Option<String>,
+    allowed_algorithms: Vec<Algorithm>,
+}
+
+impl JwtValidator {
+    pub fn new(issuer: Option<String>, audience: Option<String>) -> Self {
+        Self {
+            keys: Vec::new(),
+            issuer,
+            audience,
+            allowed_algorithms: ALL_ALGORITHMS.to_vec(),
+        }
+    }
+
+    pub fn with_allowed_algorithms(mut self, algorithms: Vec<Algorithm>) -> Self {
+        self.allowed_algorithms = algorithms;
+        self
+    }
+
+    pub fn with_hs256_secret(mut self, secret: &[u8]) -> Self {
+        self.keys.push(JwtKey {
+            kid: None,
+            algorithm: Algorithm::HS256,
+            decoding_key: DecodingKey::from_secret(secret),
+        });
+        self
+    }
+
+    pub fn with_rs256_public_key(mut self, pem: &[u8]) -> Result<Self, String> {
+        let decoding_key = DecodingKey::from_rsa_pem(pem).map_err(|err| err.to_string())?;
+
+        self.keys.push(JwtKey {
+            kid: None,
+            algorithm: Algorithm::RS256,
+            decoding_key,
+        });
+
+        Ok(self)
+    }
+
+    pub fn with_jwks(mut self, jwks: &str) -> Result<Self, String> {
+        let jwks: JwkSet = serde_json::from_str(jwks).map_err(|err| err.to_string())?;
+
+        for jwk in jwks.keys {
+            let keys = self.keys_from_jwk(&jwk)?;
+            self.keys.extend(keys);
+        }
+
+        Ok(self)
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    pub fn from_env() -> Result<Self, String> {
+        let mut validator = Self::new(
+            std::env::var("JWT_ISSUER").ok(),
+            std::env::var("JWT_AUDIENCE").ok(),
+        );
+
+        if let Ok(algorithms) = std::env::var("JWT_ALLOWED_ALGORITHMS") {
+            validator = validator.with_allowed_algorithms(
+                algorithms
+                    .split(',')
+                    .map(|algorithm| algorithm.trim().parse::<Algorithm>())
+                    .collect::<Result<_, _>>()
+                    .map_err(|err| format!("JWT_ALLOWED_ALGORITHMS: {err}"))?,
+            );
+        }
+
+        if let Ok(secret) = std::env::var("JWT_HS256_SECRET") {
+            validator = validator.with_hs256_secret(secret.as_bytes());
+        }
+
+        if let Ok(path) = std::env::var("JWT_RS256_PUBLIC_KEY_PATH") {
+            let pem = fs::read(&path).map_err(|err| format!("{path}: {err}"))?;
+            validator = validator.with_rs256_public_key(&pem)?;
+        }
+
+        if let Ok(path) = std::env::var("JWT_JWKS_PATH") {
+            let jwks = fs::read_to_string(&path).map_err(|err| format!("{path}: {err}"))?;
+            validator = validator.with_jwks(&jwks)?;
+        }
+
+        if validator.keys.is_empty() {
+            return Err(
+                "No JWT keys configured, set JWT_HS256_SECRET, JWT_RS256_PUBLIC_KEY_PATH or JWT_JWKS_PATH"
+                    .to_string(),
+            );
+        }
+
+        Ok(validator)
+    }
+
+    pub fn validate(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthenticationError> {
+        let header = decode_header(token)
+            .map_err(|err| AuthenticationError::InvalidCredentials(err.to_string()))?;
+
+        let candidates = self.keys.iter().filter(|key| {
+            key.algorithm == header.alg
+                && match (&header.kid, &key.kid) {
+                    (Some(token_kid), Some(key_kid)) => token_kid == key_kid,
+                    _ => true,
+                }
+        });
+
+        let mut last_err = AuthenticationError::InvalidCredentials(format!(
+            "No key configured for algorithm {:?}",
+            header.alg
+        ));
+
+        for key in candidates {
+            match decode::<JwtClaims>(token, &key.decoding_key, &self.validation(key.algorithm)) {
+                Ok(data) => return Ok(data.claims.into()),
+                Err(err) => last_err = AuthenticationError::InvalidCredentials(err.to_string()),
+            }
+        }
+
+        Err(last_err)
+    }
+
+    fn validation(&self, algorithm: Algorithm) -> Validation {
+        let mut validation = Validation::new(algorithm);
+
+        if let Some(issuer) = &self.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+
+        match &self.audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+
+        validation
+    }
+
+    fn keys_from_jwk(&self, jwk: &Jwk) -> Result<Vec<JwtKey>, String> {
+        let kid = jwk.common.key_id.clone().unwrap_or_default();
+        let key_type_algorithms = Self::key_type_algorithms(jwk);
+
+        let algorithms = match jwk.common.key_algorithm {
+            Some(key_algorithm) => {
+                let algorithm = key_algorithm
+                    .to_string()
+                    .parse::<Algorithm>()
+                    .map_err(|err| err.to_string())?;
+
+                if !key_type_algorithms.contains(&algorithm) {
+                    return Err(format!(
+                        "JWK {kid:?} declares {algorithm:?}, which does not fit its key type"
+                    ));
+                }
+
+                if !self.allowed_algorithms.contains(&algorithm) {
+                    return Err(format!(
+                        "JWK {kid:?} declares {algorithm:?}, which is not an allowed algorithm"
+                    ));
+                }
+
+                vec![algorithm]
+            }
+            None => key_type_algorithms
+                .iter()
+                .filter(|algorithm| self.allowed_algorithms.contains(algorithm))
+                .copied()
+                .collect(),
+        };
+
+        if algorithms.is_empty() {
+            return Err(format!(
+                "JWK {kid:?} has no alg and none of the allowed algorithms fit its key type"
+            ));
+        }
+
+        let decoding_key = DecodingKey::from_jwk(jwk).map_err(|err| err.to_string())?;
+
+        Ok(algorithms
+            .into_iter()
+            .map(|algorithm| JwtKey {
+                kid: jwk.common.key_id.clone(),
+                algorithm,
+                decoding_key: decoding_key.clone(),
+            })
+            .collect())
+    }
+
+    fn key_type_algorithms(jwk: &Jwk) -> &'static [Algorithm] {
+        match &jwk.algorithm {
+            AlgorithmParameters::RSA(_) => &[
+                Algorithm::RS256,
+                Algorithm::RS384,
+                Algorithm::RS512,
+                Algorithm::PS256,
+                Algorithm::PS384,
+                Algorithm::PS512,
+            ],
+            AlgorithmParameters::EllipticCurve(params) => match params.curve {
+                EllipticCurve::P256 => &[Algorithm::ES256],
+                EllipticCurve::P384 => &[Algorithm::ES384],
+                _ => &[],
+            },
+            AlgorithmParameters::OctetKey(_) => {
+                &[Algorithm::HS256, Algorithm::HS384, Algorithm::HS512]
+            }
+            AlgorithmParameters::OctetKeyPair(params) => match params.curve {
+                EllipticCurve::Ed25519 => &[Algorithm::EdDSA],
+                _ => &[],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+    use serde_json::json;
+
+    use crate::application::{
+        auth::authenticated_principal::AuthenticatedPrincipal,
+        errors::authentication_error::AuthenticationError,
+    };
+
+    use super::JwtValidator;
+
+    const SECRET: &[u8] = b"super-secret";
+
+    fn token(claims: serde_json::Value, header: Header, secret: &[u8]) -> String {
+        encode(&header, &claims, &EncodingKey::from_secret(secret)).unwrap()
+    }
+
+    fn future_exp() -> usize {
+        (std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 3600) as usize
+    }
+
+    #[test]
+    fn validate_hs256_ok() {
+        let sut = JwtValidator::new(None, None).with_hs256_secret(SECRET);
+
+        let token = token(
+            json!({
+                "sub": "42",
+                "email": "andrew@email.com",
+                "roles": ["admin"],
+                "scope": "users:read users:write",
+                "exp": future_exp(),
+            }),
+            Header::default(),
+            SECRET,
+        );
+
+        let result = sut.validate(&token);
+
+        assert_eq!(
+            result,
+            Ok(AuthenticatedPrincipal::new(
+                "42".to_string(),
+                Some("andrew@email.com".to_string()),
+                vec!["admin".to_string()],
+            )
+            .with_scopes(vec!["users:read".to_string(), "users:write".to_string()]))
+        );
+    }
+
+    #[test]
+    fn validate_wrong_secret() {
+        let sut = JwtValidator::new(None, None).with_hs256_secret(SECRET);
+
+        let token = token(
+            json!({ "sub": "42", "exp": future_exp() }),
+            Header::default(),
+            b"another-secret",
+        );
+
+        let result = sut.validate(&token);
+
+        assert_eq!(
+            result,
+            Err(AuthenticationError::InvalidCredentials(
+                "InvalidSignature".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_expired_token() {
+        let sut = JwtValidator::new(None, None).with_hs256_secret(SECRET);
+
+        let token = token(json!({ "sub": "42", "exp": 1 }), Header::default(), SECRET);
+
+        let result = sut.validate(&token);
+
+        assert_eq!(
+            result,
+            Err(AuthenticationError::InvalidCredentials(
+                "ExpiredSignature".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_wrong_issuer() {
+        let sut =
+            JwtValidator::new(Some("user-service".to_string()), None).with_hs256_secret(SECRET);
+
+        let token = token(
+            json!({ "sub": "42", "iss": "someone-else", "exp": future_exp() }),
+            Header::default(),
+            SECRET,
+        );
+
+        let result = sut.validate(&token);
+
+        assert_eq!(
+            result,
+            Err(AuthenticationError::InvalidCredentials(
+                "InvalidIssuer".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_no_key_for_algorithm() {
+        let sut = JwtValidator::new(None, None).with_hs256_secret(SECRET);
+
+        let token = token(
+            json!({ "sub": "42", "exp": future_exp() }),
+            Header::new(jsonwebtoken::Algorithm::HS512),
+            SECRET,
+        );
+
+        let result = sut.validate(&token);
+
+        assert_eq!(
+            result,
+            Err(AuthenticationError::InvalidCredentials(
+                "No key configured for algorithm HS512".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_jwks_key_by_kid() -> Result<(), Box<dyn std::error::Error>> {
+        let jwks = json!({
+            "keys": [
+                { "kty": "oct", "kid": "old", "alg": "HS256", "k": "b2xkLXNlY3JldA" },
+                { "kty": "oct", "kid": "new", "alg": "HS256", "k": "c3VwZXItc2VjcmV0" },
+            ]
+        });
+
+        let sut = JwtValidator::new(None, None).with_jwks(&jwks.to_string())?;
+
+        let header = Header {
+            kid: Some("new".to_string()),
+            ..Default::default()
+        };
+
+        let token = token(json!({ "sub": "42", "exp": future_exp() }), header, SECRET);
+
+        let principal = sut.validate(&token)?;
+
+        assert_eq!(principal.subject, "42");
+        assert!(principal.roles.is_empty());
+        assert!(principal.scopes.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_jwks_key_without_alg_uses_the_allowed_algorithms_of_its_key_type()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let jwks = json!({
+            "keys": [{ "kty": "oct", "kid": "k1", "k": "c3VwZXItc2VjcmV0" }]
+        });
+
+        let sut = JwtValidator::new(None, None)
+            .with_allowed_algorithms(vec![Algorithm::HS512])
+            .with_jwks(&jwks.to_string())?;
+
+        let claims = json!({ "sub": "42", "exp": future_exp() });
+
+        assert_eq!(
+            sut.validate(&token(claims.clone(), Header::default(), SECRET)),
+            Err(AuthenticationError::InvalidCredentials(
+                "No key configured for algorithm HS256".to_string()
+            ))
+        );
+        assert_eq!(
+            sut.validate(&token(claims, Header::new(Algorithm::HS512), SECRET))?
+                .subject,
+            "42"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_jwks_rejects_a_key_without_an_allowed_algorithm_for_its_key_type() {
+        let jwks = json!({
+            "keys": [{ "kty": "oct", "kid": "k1", "k": "c3VwZXItc2VjcmV0" }]
+        });
+
+        let result = JwtValidator::new(None, None)
+            .with_allowed_algorithms(vec![Algorithm::RS256])
+            .with_jwks(&jwks.to_string());
+
+        assert_eq!(
+            result.err(),
+            Some(
+                "JWK \"k1\" has no alg and none of the allowed algorithms fit its key type"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn with_jwks_rejects_an_alg_that_does_not_fit_the_key_type() {
+        let jwks = json!({
+            "keys": [{ "kty": "oct", "kid": "k1", "alg": "RS256", "k": "c3VwZXItc2VjcmV0" }]
+        });
+
+        let result = JwtValidator::new(None, None).with_jwks(&jwks.to_string());
+
+        assert_eq!(
+            result.err(),
+            Some("JWK \"k1\" declares RS256, which does not fit its key type".to_string())
+        );
+    }
+
+    #[test]
+    fn with_jwks_rejects_an_alg_outside_the_allow_list() {
+        let jwks = json!({
+            "keys": [{ "kty": "oct", "kid": "k1", "alg": "HS256", "k": "c3VwZXItc2VjcmV0" }]
+        });
+
+        let result = JwtValidator::new(None, None)
+            .with_allowed_algorithms(vec![Algorithm::HS512])
+            .with_jwks(&jwks.to_string());
+
+        assert_eq!(
+            result.err(),
+            Some("JWK \"k1\" declares HS256, which is not an allowed algorithm".to_string())
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use crate::domain::services::pii_redactor::{self, RedactionConfig};
+
+use super::{
+    cache::in_memory_user_cache::InMemoryUserCache,
+    crypto::pii_cipher::{self, PiiCipher},
+    db::connection::DBPool,
+    repositories::{
+        audited_user_repository::AuditedUserRepository,
+        caching_user_repository::{CachedPostgresUserRepository, CachingUserRepository},
+        metered_user_repository::MeteredUserRepository,
+        postgres_user_repository::PostgresUserRepository,
+    },
+};
+
+#[cfg(not(tarpaulin_include))]
+pub fn env_or<T: FromStr>(name: &str, default: T) -> T {
+    match std::env::var(name) {
+        Ok(value) => value
+            .parse()
+            .unwrap_or_else(|_| panic!("{name} has an invalid value: {value:?}")),
+        Err(_) => default,
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn init_pii() {
+    let redaction_allow_list = std::env::var("PII_REDACTION_ALLOW_LIST").unwrap_or_default();
+    pii_redactor::init(
+        RedactionConfig::from_allow_list(&redaction_allow_list)
+            .expect("Failed to configure PII redaction"),
+    );
+
+    pii_cipher::init(PiiCipher::from_env().expect("Failed to configure PII encryption"));
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn user_repository(pool: DBPool) -> CachedPostgresUserRepository {
+    CachingUserRepository::new(
+        Arc::new(MeteredUserRepository::new(
+            Arc::new(AuditedUserRepository::new(Arc::new(
+                PostgresUserRepository::new(pool),
+            ))),
+            "users",
+        )),
+        InMemoryUserCache::new(env_or("USER_CACHE_CAPACITY", 10_000)),
+    )
+    .with_ttl(
+        Duration::from_secs(env_or("USER_CACHE_TTL_SECS", 60)),
+        Duration::from_secs(env_or("USER_CACHE_NEGATIVE_TTL_SECS", 5)),
+    )
+}
@@ -0,0 +1,195 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+
+use crate::infrastructure::cache::user_cache::{CachedUser, UserCache, UserCacheKey};
+
+struct Entry {
+    value: CachedUser,
+    expires_at: Instant,
+    tick: u64,
+}
+
+#[derive(Default)]
+struct Lru {
+    entries: HashMap<UserCacheKey, Entry>,
+    recency: BTreeMap<u64, UserCacheKey>,
+    tick: u64,
+}
+
+impl Lru {
+    fn touch(&mut self, key: &UserCacheKey) -> u64 {
+        self.tick += 1;
+
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.recency.remove(&entry.tick);
+            entry.tick = self.tick;
+        }
+
+        self.recency.insert(self.tick, key.clone());
+        self.tick
+    }
+
+    fn remove(&mut self, key: &UserCacheKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.tick);
+        }
+    }
+}
+
+pub struct InMemoryUserCache {
+    capacity: usize,
+    lru: Mutex<Lru>,
+}
+
+impl InMemoryUserCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            lru: Mutex::new(Lru::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lru.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl UserCache for InMemoryUserCache {
+    async fn get(&self, key: &UserCacheKey) -> Option<CachedUser> {
+        let mut lru = self.lru.lock().unwrap();
+
+        let expired = lru.entries.get(key)?.expires_at <= Instant::now();
+
+        if expired {
+            lru.remove(key);
+            return None;
+        }
+
+        lru.touch(key);
+        lru.entries.get(key).map(|entry| entry.value.clone())
+    }
+
+    async fn put(&self, key: UserCacheKey, value: CachedUser, ttl: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut lru = self.lru.lock().unwrap();
+
+        lru.remove(&key);
+
+        let tick = lru.touch(&key);
+
+        lru.entries.insert(
+            key,
+            Entry {
+                value,
+                expires_at: Instant::now() + ttl,
+                tick,
+            },
+        );
+
+        while lru.entries.len() > self.capacity {
+            let Some((_, oldest)) = lru.recency.pop_first() else {
+                break;
+            };
+
+            lru.entries.remove(&oldest);
+        }
+    }
+
+    async fn invalidate(&self, key: &UserCacheKey) {
+        self.lru.lock().unwrap().remove(key);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        domain::entities::user::User,
+        infrastructure::cache::{
+            in_memory_user_cache::InMemoryUserCache,
+            user_cache::{CachedUser, UserCache, UserCacheKey},
+        },
+    };
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    fn found(id: i32) -> CachedUser {
+        CachedUser::Found(
+            User::restore(
+                id,
+                "Andrew".to_string(),
+                "andrew@email.com".to_string(),
+                "+001133334444".to_string(),
+                "Dawn St.".to_string(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn get_put_invalidate() {
+        let sut = InMemoryUserCache::new(10);
+
+        assert_eq!(sut.get(&UserCacheKey::Id(1)).await, None);
+
+        sut.put(UserCacheKey::Id(1), found(1), TTL).await;
+        sut.put(
+            UserCacheKey::email("Bea@Email.com"),
+            CachedUser::Missing,
+            TTL,
+        )
+        .await;
+
+        assert_eq!(sut.get(&UserCacheKey::Id(1)).await, Some(found(1)));
+        assert_eq!(
+            sut.get(&UserCacheKey::email("bea@email.com")).await,
+            Some(CachedUser::Missing)
+        );
+
+        sut.invalidate(&UserCacheKey::Id(1)).await;
+
+        assert_eq!(sut.get(&UserCacheKey::Id(1)).await, None);
+        assert_eq!(sut.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn expired_entries_are_dropped() {
+        let sut = InMemoryUserCache::new(10);
+
+        sut.put(UserCacheKey::Id(1), found(1), Duration::ZERO).await;
+
+        assert_eq!(sut.get(&UserCacheKey::Id(1)).await, None);
+        assert!(sut.is_empty());
+    }
+
+    #[tokio::test]
+    async fn evicts_the_least_recently_used_entry() {
+        let sut = InMemoryUserCache::new(2);
+
+        sut.put(UserCacheKey::Id(1), found(1), TTL).await;
+        sut.put(UserCacheKey::Id(2), found(2), TTL).await;
+
+        sut.get(&UserCacheKey::Id(1)).await;
+
+        sut.put(UserCacheKey::Id(3), found(3), TTL).await;
+
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut.get(&UserCacheKey::Id(2)).await, None);
+        assert_eq!(sut.get(&UserCacheKey::Id(1)).await, Some(found(1)));
+        assert_eq!(sut.get(&UserCacheKey::Id(3)).await, Some(found(3)));
+    }
+}
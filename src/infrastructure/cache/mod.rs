@@ -0,0 +1,2 @@
+pub mod in_memory_user_cache;
+pub mod user_cache;
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{entities::user::User, services::email_normalizer::normalize_email};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum UserCacheKey {
+    Id(i32),
+    Email(String),
+}
+
+impl UserCacheKey {
+    pub fn email(email: &str) -> Self {
+        Self::Email(normalize_email(email))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CachedUser {
+    Found(User),
+    Missing,
+}
+
+impl From<Option<User>> for CachedUser {
+    fn from(value: Option<User>) -> Self {
+        match value {
+            Some(user) => CachedUser::Found(user),
+            None => CachedUser::Missing,
+        }
+    }
+}
+
+impl From<CachedUser> for Option<User> {
+    fn from(value: CachedUser) -> Self {
+        match value {
+            CachedUser::Found(user) => Some(user),
+            CachedUser::Missing => None,
+        }
+    }
+}
+
+#[automock]
+#[async_trait]
+pub trait UserCache: Send + Sync {
+    async fn get(&self, key: &UserCacheKey) -> Option<CachedUser>;
+    async fn put(&self, key: UserCacheKey, value: CachedUser, ttl: Duration);
+    async fn invalidate(&self, key: &UserCacheKey);
+}
@@ -0,0 +1,319 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use aes_gcm::{
+    Aes256Gcm, Key, KeyInit, Nonce,
+    aead::{Aead, AeadCore, OsRng},
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+const ENVELOPE_PREFIX: &str = "enc:v1:";
+const NONCE_LEN: usize = 12;
+
+static PII_CIPHER: OnceLock<PiiCipher> = OnceLock::new();
+
+pub struct PiiCipher {
+    active_key_id: String,
+    keys: HashMap<String, Aes256Gcm>,
+    blind_index_key: Vec<u8>,
+    encrypt_email: bool,
+}
+
+impl PiiCipher {
+    pub fn new(
+        active_key_id: String,
+        keys: HashMap<String, [u8; 32]>,
+        blind_index_key: Vec<u8>,
+        encrypt_email: bool,
+    ) -> Result<Self, String> {
+        if !keys.contains_key(&active_key_id) {
+            return Err(format!(
+                "The active PII key {active_key_id} is not configured"
+            ));
+        }
+
+        let keys = keys
+            .into_iter()
+            .map(|(key_id, key)| (key_id, Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key))))
+            .collect();
+
+        Ok(Self {
+            active_key_id,
+            keys,
+            blind_index_key,
+            encrypt_email,
+        })
+    }
+
+    pub fn from_config(
+        keys: &str,
+        active_key_id: &str,
+        blind_index_key: &str,
+        encrypt_email: bool,
+    ) -> Result<Self, String> {
+        let keys = keys
+            .split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(|entry| {
+                let (key_id, key) = entry
+                    .trim()
+                    .split_once(':')
+                    .ok_or_else(|| format!("Expected <key id>:<base64 key>, got {entry}"))?;
+
+                let key: [u8; 32] = STANDARD
+                    .decode(key)
+                    .map_err(|err| format!("Invalid PII key {key_id}: {err}"))?
+                    .try_into()
+                    .map_err(|_| format!("The PII key {key_id} must be 32 bytes long"))?;
+
+                Ok((key_id.to_string(), key))
+            })
+            .collect::<Result<HashMap<String, [u8; 32]>, String>>()?;
+
+        let blind_index_key = STANDARD
+            .decode(blind_index_key)
+            .map_err(|err| format!("Invalid blind index key: {err}"))?;
+
+        Self::new(
+            active_key_id.to_string(),
+            keys,
+            blind_index_key,
+            encrypt_email,
+        )
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    pub fn from_env() -> Result<Self, String> {
+        let var = |name: &str| std::env::var(name).map_err(|_| format!("{name} is missing"));
+
+        Self::from_config(
+            &var("PII_ENCRYPTION_KEYS")?,
+            &var("PII_ACTIVE_KEY_ID")?,
+            &var("PII_BLIND_INDEX_KEY")?,
+            std::env::var("PII_ENCRYPT_EMAIL").is_ok_and(|value| value == "true"),
+        )
+    }
+
+    pub fn encrypts_email(&self) -> bool {
+        self.encrypt_email
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> Result<String, String> {
+        let cipher = &self.keys[&self.active_key_id];
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|err| err.to_string())?;
+
+        let mut payload = nonce.to_vec();
+        payload.extend(ciphertext);
+
+        Ok(format!(
+            "{ENVELOPE_PREFIX}{}:{}",
+            self.active_key_id,
+            STANDARD.encode(payload)
+        ))
+    }
+
+    pub fn decrypt(&self, value: &str) -> Result<String, String> {
+        let Some(envelope) = value.strip_prefix(ENVELOPE_PREFIX) else {
+            return Ok(value.to_string());
+        };
+
+        let (key_id, payload) = envelope
+            .split_once(':')
+            .ok_or_else(|| "Malformed encrypted value".to_string())?;
+
+        let cipher = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| format!("Unknown PII key: {key_id}"))?;
+
+        let payload = STANDARD.decode(payload).map_err(|err| err.to_string())?;
+
+        if payload.len() < NONCE_LEN {
+            return Err("Malformed encrypted value".to_string());
+        }
+
+        let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| format!("Failed to decrypt a value with the PII key {key_id}"))?;
+
+        String::from_utf8(plaintext).map_err(|err| err.to_string())
+    }
+
+    pub fn key_id_of(value: &str) -> Option<&str> {
+        value
+            .strip_prefix(ENVELOPE_PREFIX)
+            .and_then(|envelope| envelope.split_once(':'))
+            .map(|(key_id, _)| key_id)
+    }
+
+    pub fn is_current(&self, value: &str) -> bool {
+        Self::key_id_of(value) == Some(self.active_key_id.as_str())
+    }
+
+    pub fn blind_index(&self, value: &str) -> String {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&self.blind_index_key)
+            .expect("HMAC accepts keys of any length");
+        mac.update(value.as_bytes());
+
+        hex::encode(mac.finalize().into_bytes())
+    }
+}
+
+pub fn init(cipher: PiiCipher) {
+    let _ = PII_CIPHER.set(cipher);
+}
+
+pub fn cipher() -> Result<&'static PiiCipher, String> {
+    PII_CIPHER
+        .get()
+        .ok_or_else(|| "The PII cipher is not configured".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use base64::{Engine, engine::general_purpose::STANDARD};
+
+    use super::PiiCipher;
+
+    fn cipher(active_key_id: &str) -> PiiCipher {
+        PiiCipher::new(
+            active_key_id.to_string(),
+            HashMap::from([("k1".to_string(), [1u8; 32]), ("k2".to_string(), [2u8; 32])]),
+            b"blind-index-key".to_vec(),
+            false,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new_unknown_active_key() {
+        let result = PiiCipher::new("k3".to_string(), HashMap::new(), vec![], false);
+
+        assert_eq!(
+            result.err(),
+            Some("The active PII key k3 is not configured".to_string())
+        );
+    }
+
+    #[test]
+    fn from_config() -> Result<(), String> {
+        let keys = format!(
+            "k1:{}, k2:{}",
+            STANDARD.encode([1u8; 32]),
+            STANDARD.encode([2u8; 32])
+        );
+
+        let sut = PiiCipher::from_config(&keys, "k2", &STANDARD.encode("bidx"), true)?;
+
+        assert!(sut.encrypts_email());
+        assert_eq!(PiiCipher::key_id_of(&sut.encrypt("Dawn St.")?), Some("k2"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_config_short_key() {
+        let keys = format!("k1:{}", STANDARD.encode([1u8; 16]));
+
+        let result = PiiCipher::from_config(&keys, "k1", "", false);
+
+        assert_eq!(
+            result.err(),
+            Some("The PII key k1 must be 32 bytes long".to_string())
+        );
+    }
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() -> Result<(), String> {
+        let sut = cipher("k1");
+
+        let encrypted = sut.encrypt("+001133334444")?;
+
+        assert!(encrypted.starts_with("enc:v1:k1:"));
+        assert!(!encrypted.contains("+001133334444"));
+        assert_ne!(encrypted, sut.encrypt("+001133334444")?);
+        assert_eq!(sut.decrypt(&encrypted)?, "+001133334444");
+
+        Ok(())
+    }
+
+    #[test]
+    fn decrypt_after_key_rotation() -> Result<(), String> {
+        let encrypted = cipher("k1").encrypt("Dawn St.")?;
+
+        let rotated = cipher("k2");
+
+        assert_eq!(rotated.decrypt(&encrypted)?, "Dawn St.");
+        assert_eq!(
+            PiiCipher::key_id_of(&rotated.encrypt("Dawn St.")?),
+            Some("k2")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_current_only_for_the_active_key() -> Result<(), String> {
+        let encrypted = cipher("k1").encrypt("Dawn St.")?;
+
+        assert!(cipher("k1").is_current(&encrypted));
+        assert!(!cipher("k2").is_current(&encrypted));
+        assert!(!cipher("k1").is_current("Dawn St."));
+
+        Ok(())
+    }
+
+    #[test]
+    fn decrypt_plaintext_passthrough() -> Result<(), String> {
+        assert_eq!(cipher("k1").decrypt("Dawn St.")?, "Dawn St.");
+        assert_eq!(PiiCipher::key_id_of("Dawn St."), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn decrypt_unknown_key() {
+        let result = cipher("k1").decrypt("enc:v1:k9:AAAA");
+
+        assert_eq!(result, Err("Unknown PII key: k9".to_string()));
+    }
+
+    #[test]
+    fn decrypt_tampered_value() -> Result<(), String> {
+        let sut = cipher("k1");
+
+        let mut encrypted = sut.encrypt("Dawn St.")?;
+        encrypted.replace_range(encrypted.len() - 4.., "AAAA");
+
+        assert_eq!(
+            sut.decrypt(&encrypted),
+            Err("Failed to decrypt a value with the PII key k1".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn blind_index_is_deterministic() {
+        let sut = cipher("k1");
+
+        assert_eq!(
+            sut.blind_index("andrew@email.com"),
+            cipher("k2").blind_index("andrew@email.com")
+        );
+        assert_ne!(
+            sut.blind_index("andrew@email.com"),
+            sut.blind_index("other@email.com")
+        );
+        assert_eq!(sut.blind_index("andrew@email.com").len(), 64);
+    }
+}
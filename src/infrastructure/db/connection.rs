@@ -1,5 +1,7 @@
 use diesel::{PgConnection, r2d2::ConnectionManager};
 
+use crate::infrastructure::metrics::pool_metrics::PoolMetrics;
+
 pub type DBPool = r2d2::Pool<ConnectionManager<PgConnection>>;
 
 #[cfg(not(tarpaulin_include))]
@@ -7,6 +9,7 @@ pub fn establish_connection(db_url: &str) -> DBPool {
     let manager = ConnectionManager::<PgConnection>::new(db_url);
 
     r2d2::Pool::builder()
+        .event_handler(Box::new(PoolMetrics))
         .build(manager)
         .expect("Failed to create DB Pool")
 }
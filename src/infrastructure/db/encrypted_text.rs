@@ -0,0 +1,98 @@
+use std::io::Write;
+
+use diesel::{
+    AsExpression, FromSqlRow,
+    deserialize::FromSql,
+    pg::Pg,
+    serialize::{IsNull, ToSql},
+    sql_types::Text,
+};
+
+use crate::infrastructure::crypto::pii_cipher::cipher;
+
+#[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Text)]
+pub struct EncryptedText(pub String);
+
+#[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Text)]
+pub struct EncryptedEmail(pub String);
+
+impl From<String> for EncryptedText {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<EncryptedText> for String {
+    fn from(value: EncryptedText) -> Self {
+        value.0
+    }
+}
+
+impl From<String> for EncryptedEmail {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<EncryptedEmail> for String {
+    fn from(value: EncryptedEmail) -> Self {
+        value.0
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+fn decrypt_sql(
+    bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+) -> diesel::deserialize::Result<String> {
+    let value = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+    Ok(cipher()?.decrypt(&value)?)
+}
+
+#[cfg(not(tarpaulin_include))]
+impl FromSql<Text, Pg> for EncryptedText {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> diesel::deserialize::Result<Self> {
+        decrypt_sql(bytes).map(Self)
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl ToSql<Text, Pg> for EncryptedText {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, Pg>,
+    ) -> diesel::serialize::Result {
+        out.write_all(cipher()?.encrypt(&self.0)?.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl FromSql<Text, Pg> for EncryptedEmail {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> diesel::deserialize::Result<Self> {
+        decrypt_sql(bytes).map(Self)
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl ToSql<Text, Pg> for EncryptedEmail {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, Pg>,
+    ) -> diesel::serialize::Result {
+        let cipher = cipher()?;
+
+        if cipher.encrypts_email() {
+            out.write_all(cipher.encrypt(&self.0)?.as_bytes())?;
+        } else {
+            out.write_all(self.0.as_bytes())?;
+        }
+
+        Ok(IsNull::No)
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use diesel::{
+    PgConnection, RunQueryDsl, migration::MigrationSource, pg::Pg, sql_query, sql_types::BigInt,
+};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+const MIGRATION_LOCK_KEY: i64 = 0x7573_6572_5f6d_6967;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationStatus {
+    pub name: String,
+    pub applied: bool,
+}
+
+pub fn with_migration_lock<T>(
+    conn: &mut PgConnection,
+    f: impl FnOnce(&mut PgConnection) -> Result<T, String>,
+) -> Result<T, String> {
+    sql_query("SELECT pg_advisory_lock($1)")
+        .bind::<BigInt, _>(MIGRATION_LOCK_KEY)
+        .execute(conn)
+        .map_err(|err| err.to_string())?;
+
+    let result = f(conn);
+
+    sql_query("SELECT pg_advisory_unlock($1)")
+        .bind::<BigInt, _>(MIGRATION_LOCK_KEY)
+        .execute(conn)
+        .map_err(|err| err.to_string())?;
+
+    result
+}
+
+pub fn pending_migrations(conn: &mut PgConnection) -> Result<Vec<String>, String> {
+    conn.pending_migrations(MIGRATIONS)
+        .map(|migrations| {
+            migrations
+                .iter()
+                .map(|migration| migration.name().to_string())
+                .collect()
+        })
+        .map_err(|err| err.to_string())
+}
+
+pub fn run_pending_migrations(conn: &mut PgConnection) -> Result<Vec<String>, String> {
+    conn.run_pending_migrations(MIGRATIONS)
+        .map(|versions| versions.iter().map(ToString::to_string).collect())
+        .map_err(|err| err.to_string())
+}
+
+pub fn revert_last_migration(conn: &mut PgConnection) -> Result<String, String> {
+    conn.revert_last_migration(MIGRATIONS)
+        .map(|version| version.to_string())
+        .map_err(|err| err.to_string())
+}
+
+pub fn redo_last_migration(conn: &mut PgConnection) -> Result<String, String> {
+    let reverted = revert_last_migration(conn)?;
+
+    let migrations =
+        MigrationSource::<Pg>::migrations(&MIGRATIONS).map_err(|err| err.to_string())?;
+    let migration = migrations
+        .iter()
+        .find(|migration| migration.name().version().to_string() == reverted)
+        .ok_or_else(|| format!("Migration {reverted} is not embedded in this binary"))?;
+
+    conn.run_migration(migration.as_ref())
+        .map(|version| version.to_string())
+        .map_err(|err| err.to_string())
+}
+
+pub fn migration_status(conn: &mut PgConnection) -> Result<Vec<MigrationStatus>, String> {
+    let applied: HashSet<String> = conn
+        .applied_migrations()
+        .map_err(|err| err.to_string())?
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    embedded_migrations().map(|names| {
+        names
+            .into_iter()
+            .map(|(version, name)| MigrationStatus {
+                applied: applied.contains(&version),
+                name,
+            })
+            .collect()
+    })
+}
+
+fn embedded_migrations() -> Result<Vec<(String, String)>, String> {
+    let mut migrations: Vec<(String, String)> = MigrationSource::<Pg>::migrations(&MIGRATIONS)
+        .map_err(|err| err.to_string())?
+        .iter()
+        .map(|migration| {
+            (
+                migration.name().version().to_string(),
+                migration.name().to_string(),
+            )
+        })
+        .collect();
+
+    migrations.sort();
+
+    Ok(migrations)
+}
+
+#[cfg(test)]
+mod test {
+    use super::embedded_migrations;
+
+    #[test]
+    fn embeds_every_migration_directory() {
+        let mut directories: Vec<String> = std::fs::read_dir("migrations")
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        directories.sort();
+
+        let embedded: Vec<String> = embedded_migrations()
+            .unwrap()
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect();
+
+        assert_eq!(embedded, directories);
+    }
+}
@@ -1 +1,6 @@
 pub mod connection;
+pub mod encrypted_text;
+pub mod migrations;
+pub mod pending_email_change_row;
+pub mod pii_backfill;
+pub mod user_row;
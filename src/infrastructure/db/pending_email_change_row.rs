@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use diesel::{Queryable, prelude::Insertable};
+
+use crate::{
+    domain::{entities::pending_email_change::PendingEmailChange, value_objects::id::ID},
+    infrastructure::db::encrypted_text::EncryptedText,
+    schema::pending_email_changes,
+};
+
+#[derive(Queryable)]
+#[diesel(table_name = pending_email_changes)]
+pub struct PendingEmailChangeRow {
+    pub id: i32,
+    pub user_id: i32,
+    pub new_email: EncryptedText,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = pending_email_changes)]
+pub struct NewPendingEmailChangeRow {
+    pub user_id: i32,
+    pub new_email: EncryptedText,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+}
+
+impl From<PendingEmailChangeRow> for PendingEmailChange {
+    fn from(value: PendingEmailChangeRow) -> Self {
+        Self {
+            id: ID::Existing(value.id),
+            user_id: value.user_id,
+            new_email: value.new_email.into(),
+            token_hash: value.token_hash,
+            expires_at: value.expires_at,
+            created_at: value.created_at,
+            confirmed_at: value.confirmed_at,
+        }
+    }
+}
+
+impl From<&PendingEmailChange> for NewPendingEmailChangeRow {
+    fn from(value: &PendingEmailChange) -> Self {
+        Self {
+            user_id: value.user_id,
+            new_email: EncryptedText(value.new_email.clone()),
+            token_hash: value.token_hash.clone(),
+            expires_at: value.expires_at,
+            created_at: value.created_at,
+            confirmed_at: value.confirmed_at,
+        }
+    }
+}
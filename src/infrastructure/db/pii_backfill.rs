@@ -0,0 +1,206 @@
+use diesel::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    domain::services::email_normalizer::normalize_email,
+    infrastructure::{
+        crypto::pii_cipher::{PiiCipher, cipher},
+        db::encrypted_text::{EncryptedEmail, EncryptedText},
+    },
+    schema::users::dsl::{address, email, email_bidx, id, phone, users},
+};
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct PiiBackfillReport {
+    pub scanned: usize,
+    pub rewritten: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PiiRewrite {
+    pub email: String,
+    pub phone: String,
+    pub address: String,
+    pub email_bidx: String,
+}
+
+pub fn pii_rewrite(
+    cipher: &PiiCipher,
+    stored_email: &str,
+    stored_phone: &str,
+    stored_address: &str,
+    stored_email_bidx: Option<&str>,
+) -> Result<Option<PiiRewrite>, String> {
+    let plain_email = cipher.decrypt(stored_email)?;
+    let expected_email_bidx = cipher.blind_index(&normalize_email(&plain_email));
+
+    let email_is_current = if cipher.encrypts_email() {
+        cipher.is_current(stored_email)
+    } else {
+        PiiCipher::key_id_of(stored_email).is_none()
+    };
+
+    if email_is_current
+        && cipher.is_current(stored_phone)
+        && cipher.is_current(stored_address)
+        && stored_email_bidx == Some(expected_email_bidx.as_str())
+    {
+        return Ok(None);
+    }
+
+    Ok(Some(PiiRewrite {
+        email: plain_email,
+        phone: cipher.decrypt(stored_phone)?,
+        address: cipher.decrypt(stored_address)?,
+        email_bidx: expected_email_bidx,
+    }))
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn backfill_user_pii(
+    conn: &mut PgConnection,
+    batch_size: i64,
+) -> Result<PiiBackfillReport, String> {
+    let cipher = cipher()?;
+    let mut report = PiiBackfillReport::default();
+    let mut last_id = 0;
+
+    loop {
+        let batch = conn
+            .transaction::<_, Box<dyn std::error::Error + Send + Sync>, _>(|conn| {
+                let rows = users
+                    .select((id, email, phone, address, email_bidx))
+                    .filter(id.gt(last_id))
+                    .order(id.asc())
+                    .limit(batch_size)
+                    .for_update()
+                    .load::<(i32, String, String, String, Option<String>)>(conn)?;
+
+                let mut rewritten = 0;
+
+                for (user_id, stored_email, stored_phone, stored_address, stored_bidx) in &rows {
+                    let rewrite = pii_rewrite(
+                        cipher,
+                        stored_email,
+                        stored_phone,
+                        stored_address,
+                        stored_bidx.as_deref(),
+                    )
+                    .map_err(|err| format!("User {user_id}: {err}"))?;
+
+                    if let Some(rewrite) = rewrite {
+                        diesel::update(users.find(user_id))
+                            .set((
+                                email.eq(EncryptedEmail(rewrite.email)),
+                                phone.eq(EncryptedText(rewrite.phone)),
+                                address.eq(EncryptedText(rewrite.address)),
+                                email_bidx.eq(rewrite.email_bidx),
+                            ))
+                            .execute(conn)?;
+
+                        rewritten += 1;
+                    }
+                }
+
+                Ok((rows.last().map(|row| row.0), rows.len(), rewritten))
+            })
+            .map_err(|err| err.to_string())?;
+
+        let (Some(batch_last_id), scanned, rewritten) = batch else {
+            return Ok(report);
+        };
+
+        last_id = batch_last_id;
+        report.scanned += scanned;
+        report.rewritten += rewritten;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::infrastructure::crypto::pii_cipher::PiiCipher;
+
+    use super::{PiiRewrite, pii_rewrite};
+
+    fn cipher(active_key_id: &str, encrypt_email: bool) -> PiiCipher {
+        PiiCipher::new(
+            active_key_id.to_string(),
+            HashMap::from([("k1".to_string(), [1u8; 32]), ("k2".to_string(), [2u8; 32])]),
+            b"blind-index-key".to_vec(),
+            encrypt_email,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn pii_rewrite_encrypts_plaintext_and_backfills_the_blind_index() -> Result<(), String> {
+        let sut = cipher("k1", false);
+
+        let rewrite = pii_rewrite(&sut, "Andrew@Email.com", "+001133334444", "Dawn St.", None)?;
+
+        assert_eq!(
+            rewrite,
+            Some(PiiRewrite {
+                email: "Andrew@Email.com".to_string(),
+                phone: "+001133334444".to_string(),
+                address: "Dawn St.".to_string(),
+                email_bidx: sut.blind_index("andrew@email.com"),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pii_rewrite_skips_rows_that_are_up_to_date() -> Result<(), String> {
+        let sut = cipher("k1", true);
+
+        let rewrite = pii_rewrite(
+            &sut,
+            &sut.encrypt("andrew@email.com")?,
+            &sut.encrypt("+001133334444")?,
+            &sut.encrypt("Dawn St.")?,
+            Some(&sut.blind_index("andrew@email.com")),
+        )?;
+
+        assert_eq!(rewrite, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pii_rewrite_rotates_values_to_the_active_key() -> Result<(), String> {
+        let old = cipher("k1", false);
+        let sut = cipher("k2", false);
+
+        let rewrite = pii_rewrite(
+            &sut,
+            "andrew@email.com",
+            &old.encrypt("+001133334444")?,
+            &sut.encrypt("Dawn St.")?,
+            Some(&sut.blind_index("andrew@email.com")),
+        )?;
+
+        assert_eq!(
+            rewrite.map(|rewrite| rewrite.phone),
+            Some("+001133334444".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pii_rewrite_unknown_key() {
+        let result = pii_rewrite(
+            &cipher("k1", false),
+            "andrew@email.com",
+            "enc:v1:k9:AAAA",
+            "Dawn St.",
+            None,
+        );
+
+        assert_eq!(result, Err("Unknown PII key: k9".to_string()));
+    }
+}
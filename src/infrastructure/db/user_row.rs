@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use diesel::{Queryable, QueryableByName, Selectable, prelude::Insertable};
+
+use crate::{
+    domain::{entities::user::User, value_objects::id::ID},
+    infrastructure::db::encrypted_text::{EncryptedEmail, EncryptedText},
+    schema::users,
+};
+
+#[derive(Queryable, QueryableByName, Selectable)]
+#[diesel(table_name = users)]
+pub struct UserRow {
+    pub id: i32,
+    pub name: String,
+    pub email: EncryptedEmail,
+    pub phone: EncryptedText,
+    pub address: EncryptedText,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub suspended_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = users)]
+pub struct NewUserRow {
+    pub name: String,
+    pub email: EncryptedEmail,
+    pub phone: EncryptedText,
+    pub address: EncryptedText,
+}
+
+impl From<UserRow> for User {
+    fn from(value: UserRow) -> Self {
+        Self {
+            id: ID::Existing(value.id),
+            name: value.name,
+            email: value.email.into(),
+            phone: value.phone.into(),
+            address: value.address.into(),
+            created_at: Some(value.created_at),
+            updated_at: Some(value.updated_at),
+            suspended_at: value.suspended_at,
+        }
+    }
+}
+
+impl From<&User> for NewUserRow {
+    fn from(value: &User) -> Self {
+        Self {
+            name: value.name.clone(),
+            email: EncryptedEmail(value.email.clone()),
+            phone: EncryptedText(value.phone.clone()),
+            address: EncryptedText(value.address.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        domain::{entities::user::User, value_objects::id::ID},
+        infrastructure::db::encrypted_text::{EncryptedEmail, EncryptedText},
+    };
+
+    use super::{NewUserRow, UserRow};
+
+    #[test]
+    fn user_from_row() {
+        let at = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+
+        let user = User::from(UserRow {
+            id: 42,
+            name: "Andrew".to_string(),
+            email: EncryptedEmail("andrew@email.com".to_string()),
+            phone: EncryptedText("+001133334444".to_string()),
+            address: EncryptedText("Dawn St.".to_string()),
+            created_at: at,
+            updated_at: at,
+            suspended_at: None,
+        });
+
+        assert_eq!(user.id, ID::Existing(42));
+        assert_eq!(user.email, "andrew@email.com");
+        assert_eq!(user.address, "Dawn St.");
+        assert_eq!(user.created_at, Some(at));
+        assert_eq!(user.updated_at, Some(at));
+    }
+
+    #[test]
+    fn new_row_from_user() {
+        let user = User::new(
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        );
+
+        let row = NewUserRow::from(&user);
+
+        assert_eq!(row.name, "Andrew");
+        assert_eq!(row.email, EncryptedEmail("andrew@email.com".to_string()));
+        assert_eq!(row.phone, EncryptedText("+001133334444".to_string()));
+    }
+}
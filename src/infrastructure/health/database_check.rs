@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use diesel::{RunQueryDsl, sql_query};
+
+use crate::infrastructure::{db::connection::DBPool, health::health_check::HealthCheck};
+
+pub struct DatabaseCheck {
+    pool: DBPool,
+    timeout: Duration,
+}
+
+impl DatabaseCheck {
+    pub fn new(pool: DBPool, timeout: Duration) -> Self {
+        Self { pool, timeout }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for DatabaseCheck {
+    fn name(&self) -> &str {
+        "database"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        let pool = self.pool.clone();
+        let timeout = self.timeout;
+
+        tokio::task::spawn_blocking(move || {
+            let mut conn = pool.get_timeout(timeout).map_err(|err| err.to_string())?;
+
+            sql_query("SELECT 1")
+                .execute(&mut conn)
+                .map(|_| ())
+                .map_err(|err| err.to_string())
+        })
+        .await
+        .map_err(|err| err.to_string())?
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    net::{TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+
+use crate::infrastructure::health::health_check::HealthCheck;
+
+pub struct DownstreamCheck {
+    name: String,
+    address: String,
+    timeout: Duration,
+}
+
+impl DownstreamCheck {
+    pub fn new(name: String, address: String, timeout: Duration) -> Self {
+        Self {
+            name,
+            address,
+            timeout,
+        }
+    }
+
+    pub fn parse_all(config: &str, timeout: Duration) -> Result<Vec<Self>, String> {
+        config
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.split_once('=') {
+                Some((name, address)) if !name.trim().is_empty() && !address.trim().is_empty() => {
+                    Ok(Self::new(
+                        name.trim().to_string(),
+                        address.trim().to_string(),
+                        timeout,
+                    ))
+                }
+                _ => Err(format!(
+                    "Expected a name=host:port downstream, got {entry:?}"
+                )),
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl HealthCheck for DownstreamCheck {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        let address = self.address.clone();
+        let timeout = self.timeout;
+
+        tokio::task::spawn_blocking(move || {
+            let addr = address
+                .to_socket_addrs()
+                .map_err(|err| err.to_string())?
+                .next()
+                .ok_or_else(|| format!("{address} did not resolve to an address"))?;
+
+            TcpStream::connect_timeout(&addr, timeout)
+                .map(|_| ())
+                .map_err(|err| err.to_string())
+        })
+        .await
+        .map_err(|err| err.to_string())?
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{net::TcpListener, time::Duration};
+
+    use crate::infrastructure::health::health_check::HealthCheck;
+
+    use super::DownstreamCheck;
+
+    #[test]
+    fn parse_all_downstreams() {
+        let result = DownstreamCheck::parse_all(
+            "collector=127.0.0.1:4318, mailer = smtp.local:25,",
+            Duration::from_secs(1),
+        )
+        .unwrap();
+
+        let parsed: Vec<(&str, &str)> = result
+            .iter()
+            .map(|check| (check.name(), check.address.as_str()))
+            .collect();
+
+        assert_eq!(
+            parsed,
+            vec![("collector", "127.0.0.1:4318"), ("mailer", "smtp.local:25")]
+        );
+        assert!(DownstreamCheck::parse_all("collector", Duration::from_secs(1)).is_err());
+    }
+
+    #[tokio::test]
+    async fn check_connects_to_the_downstream() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+
+        let up = DownstreamCheck::new("up".to_string(), address, Duration::from_secs(1));
+
+        assert_eq!(up.check().await, Ok(()));
+
+        drop(listener);
+
+        let down = DownstreamCheck::new(
+            "down".to_string(),
+            "127.0.0.1:1".to_string(),
+            Duration::from_secs(1),
+        );
+
+        assert!(down.check().await.is_err());
+    }
+}
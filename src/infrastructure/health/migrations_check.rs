@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::infrastructure::{
+    db::{connection::DBPool, migrations::pending_migrations},
+    health::health_check::HealthCheck,
+};
+
+pub struct MigrationsCheck {
+    pool: DBPool,
+    timeout: Duration,
+}
+
+impl MigrationsCheck {
+    pub fn new(pool: DBPool, timeout: Duration) -> Self {
+        Self { pool, timeout }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for MigrationsCheck {
+    fn name(&self) -> &str {
+        "migrations"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        let pool = self.pool.clone();
+        let timeout = self.timeout;
+
+        let pending = tokio::task::spawn_blocking(move || {
+            let mut conn = pool.get_timeout(timeout).map_err(|err| err.to_string())?;
+
+            pending_migrations(&mut conn)
+        })
+        .await
+        .map_err(|err| err.to_string())??;
+
+        if pending.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Pending migrations: {}", pending.join(", ")))
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod database_check;
+pub mod downstream_check;
+pub mod health_check;
+pub mod migrations_check;
+pub mod readiness;
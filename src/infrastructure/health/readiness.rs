@@ -0,0 +1,175 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use futures_util::future::join_all;
+use serde::Serialize;
+
+use crate::infrastructure::health::health_check::HealthCheck;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckReport {
+    pub name: String,
+    pub status: HealthStatus,
+    pub latency_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checks: Vec<CheckReport>,
+}
+
+impl HealthReport {
+    fn new(checks: Vec<CheckReport>) -> Self {
+        let status = if checks.iter().all(|check| check.status == HealthStatus::Up) {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+
+        Self { status, checks }
+    }
+}
+
+pub struct Readiness {
+    checks: Vec<Arc<dyn HealthCheck>>,
+    timeout: Duration,
+    shutting_down: AtomicBool,
+}
+
+impl Readiness {
+    pub fn new(checks: Vec<Arc<dyn HealthCheck>>, timeout: Duration) -> Self {
+        Self {
+            checks,
+            timeout,
+            shutting_down: AtomicBool::new(false),
+        }
+    }
+
+    pub fn mark_shutting_down(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    pub async fn report(&self) -> HealthReport {
+        if self.is_shutting_down() {
+            return HealthReport::new(vec![CheckReport {
+                name: "shutdown".to_string(),
+                status: HealthStatus::Down,
+                latency_ms: 0.0,
+                error: Some("The server is shutting down".to_string()),
+            }]);
+        }
+
+        HealthReport::new(join_all(self.checks.iter().map(|check| self.run(check.as_ref()))).await)
+    }
+
+    async fn run(&self, check: &dyn HealthCheck) -> CheckReport {
+        let started = Instant::now();
+
+        let result = match tokio::time::timeout(self.timeout, check.check()).await {
+            Ok(result) => result,
+            Err(_) => Err(format!("Timed out after {}ms", self.timeout.as_millis())),
+        };
+
+        CheckReport {
+            name: check.name().to_string(),
+            status: if result.is_ok() {
+                HealthStatus::Up
+            } else {
+                HealthStatus::Down
+            },
+            latency_ms: started.elapsed().as_secs_f64() * 1000.0,
+            error: result.err(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, time::Duration};
+
+    use crate::infrastructure::health::health_check::{HealthCheck, MockHealthCheck};
+
+    use super::{HealthStatus, Readiness};
+
+    fn check(name: &'static str, result: Result<(), String>) -> Arc<dyn HealthCheck> {
+        let mut mock = MockHealthCheck::new();
+
+        mock.expect_name().return_const(name.to_string());
+        mock.expect_check().return_const(result);
+
+        Arc::new(mock)
+    }
+
+    #[tokio::test]
+    async fn report_is_up_when_every_check_passes() {
+        let sut = Readiness::new(
+            vec![check("database", Ok(())), check("migrations", Ok(()))],
+            Duration::from_secs(1),
+        );
+
+        let result = sut.report().await;
+
+        assert_eq!(result.status, HealthStatus::Up);
+        assert_eq!(
+            result
+                .checks
+                .iter()
+                .map(|check| (check.name.as_str(), check.status))
+                .collect::<Vec<_>>(),
+            vec![
+                ("database", HealthStatus::Up),
+                ("migrations", HealthStatus::Up)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_is_down_when_a_check_fails() {
+        let sut = Readiness::new(
+            vec![
+                check("database", Ok(())),
+                check("migrations", Err("Pending migrations: x".to_string())),
+            ],
+            Duration::from_secs(1),
+        );
+
+        let result = sut.report().await;
+
+        assert_eq!(result.status, HealthStatus::Down);
+        assert_eq!(
+            result.checks[1].error.as_deref(),
+            Some("Pending migrations: x")
+        );
+    }
+
+    #[tokio::test]
+    async fn report_is_down_while_shutting_down() {
+        let sut = Readiness::new(vec![check("database", Ok(()))], Duration::from_secs(1));
+
+        sut.mark_shutting_down();
+
+        let result = sut.report().await;
+
+        assert_eq!(result.status, HealthStatus::Down);
+        assert_eq!(result.checks[0].name, "shutdown");
+    }
+}
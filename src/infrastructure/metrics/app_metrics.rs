@@ -0,0 +1,320 @@
+use std::{
+    sync::{LazyLock, Mutex},
+    time::Duration,
+};
+
+use prometheus::{
+    Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use tracing::{Instrument, field::Empty, info_span};
+
+use crate::{
+    application::errors::user_application_error::UserApplicationError,
+    infrastructure::repositories::caching_user_repository::CacheStats,
+};
+
+static METRICS: LazyLock<AppMetrics> = LazyLock::new(AppMetrics::new);
+
+const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+pub fn metrics() -> &'static AppMetrics {
+    &METRICS
+}
+
+pub async fn observed<T>(
+    use_case: &'static str,
+    execution: impl Future<Output = Result<T, UserApplicationError>>,
+) -> Result<T, UserApplicationError> {
+    let span = info_span!("use_case", use_case, outcome = Empty);
+
+    let result = execution.instrument(span.clone()).await;
+
+    span.record("outcome", outcome(&result));
+    metrics().observe_use_case(use_case, &result);
+
+    result
+}
+
+fn outcome<T>(result: &Result<T, UserApplicationError>) -> &'static str {
+    match result {
+        Ok(_) => "ok",
+        Err(err) => err.kind(),
+    }
+}
+
+pub struct AppMetrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    db_pool_connections: IntGauge,
+    db_pool_idle_connections: IntGauge,
+    db_pool_wait: Histogram,
+    db_pool_timeouts: IntCounter,
+    use_case_executions: IntCounterVec,
+    repository_query_duration: HistogramVec,
+    user_cache_lookups: IntCounterVec,
+    user_cache_sync: Mutex<()>,
+}
+
+impl AppMetrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests handled"),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency in seconds",
+            )
+            .buckets(LATENCY_BUCKETS.to_vec()),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+
+        let db_pool_connections = IntGauge::new(
+            "db_pool_connections",
+            "Connections currently held by the database pool",
+        )
+        .unwrap();
+
+        let db_pool_idle_connections = IntGauge::new(
+            "db_pool_idle_connections",
+            "Idle connections in the database pool",
+        )
+        .unwrap();
+
+        let db_pool_wait = Histogram::with_opts(
+            HistogramOpts::new(
+                "db_pool_wait_seconds",
+                "Time spent waiting to check out a database connection",
+            )
+            .buckets(LATENCY_BUCKETS.to_vec()),
+        )
+        .unwrap();
+
+        let db_pool_timeouts = IntCounter::new(
+            "db_pool_checkout_timeouts_total",
+            "Database connection checkouts that timed out",
+        )
+        .unwrap();
+
+        let use_case_executions = IntCounterVec::new(
+            Opts::new(
+                "use_case_executions_total",
+                "Use case executions by outcome",
+            ),
+            &["use_case", "outcome"],
+        )
+        .unwrap();
+
+        let repository_query_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "repository_query_duration_seconds",
+                "Repository operation latency in seconds",
+            )
+            .buckets(LATENCY_BUCKETS.to_vec()),
+            &["repository", "operation", "outcome"],
+        )
+        .unwrap();
+
+        let user_cache_lookups = IntCounterVec::new(
+            Opts::new("user_cache_lookups_total", "User cache lookups by result"),
+            &["result"],
+        )
+        .unwrap();
+
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(db_pool_connections.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(db_pool_idle_connections.clone()))
+            .unwrap();
+        registry.register(Box::new(db_pool_wait.clone())).unwrap();
+        registry
+            .register(Box::new(db_pool_timeouts.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(use_case_executions.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(repository_query_duration.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(user_cache_lookups.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            db_pool_connections,
+            db_pool_idle_connections,
+            db_pool_wait,
+            db_pool_timeouts,
+            use_case_executions,
+            repository_query_duration,
+            user_cache_lookups,
+            user_cache_sync: Mutex::new(()),
+        }
+    }
+
+    pub fn observe_http(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_use_case<T>(&self, use_case: &str, result: &Result<T, UserApplicationError>) {
+        self.use_case_executions
+            .with_label_values(&[use_case, outcome(result)])
+            .inc();
+    }
+
+    pub fn observe_query<T, E>(
+        &self,
+        repository: &str,
+        operation: &str,
+        result: &Result<T, E>,
+        elapsed: Duration,
+    ) {
+        let outcome = if result.is_ok() { "ok" } else { "error" };
+
+        self.repository_query_duration
+            .with_label_values(&[repository, operation, outcome])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_pool_checkout(&self, waited: Duration) {
+        self.db_pool_wait.observe(waited.as_secs_f64());
+    }
+
+    pub fn observe_pool_timeout(&self, waited: Duration) {
+        self.db_pool_timeouts.inc();
+        self.db_pool_wait.observe(waited.as_secs_f64());
+    }
+
+    pub fn observe_pool_state(&self, state: r2d2::State) {
+        self.db_pool_connections.set(i64::from(state.connections));
+        self.db_pool_idle_connections
+            .set(i64::from(state.idle_connections));
+    }
+
+    pub fn observe_user_cache(&self, stats: CacheStats) {
+        let _guard = self.user_cache_sync.lock().unwrap();
+
+        for (result, total) in [
+            ("hit", stats.hits),
+            ("negative_hit", stats.negative_hits),
+            ("miss", stats.misses),
+        ] {
+            let counter = self.user_cache_lookups.with_label_values(&[result]);
+            counter.inc_by(total.saturating_sub(counter.get()));
+        }
+    }
+
+    pub fn render(&self) -> Result<String, String> {
+        TextEncoder::new()
+            .encode_to_string(&self.registry.gather())
+            .map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        application::errors::user_application_error::UserApplicationError,
+        infrastructure::{
+            metrics::app_metrics::{metrics, observed},
+            repositories::caching_user_repository::CacheStats,
+        },
+    };
+
+    fn sample(rendered: &str, series: &str) -> f64 {
+        rendered
+            .lines()
+            .find_map(|line| line.strip_prefix(series))
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn observed_counts_outcomes_by_error_kind() {
+        let ok = r#"use_case_executions_total{outcome="ok",use_case="test_observed"}"#;
+        let not_found =
+            r#"use_case_executions_total{outcome="not_found",use_case="test_observed"}"#;
+
+        let _ = observed("test_observed", async { Ok::<_, UserApplicationError>(()) }).await;
+        let _ = observed("test_observed", async {
+            Err::<(), _>(UserApplicationError::NotFound("42".to_string()))
+        })
+        .await;
+        let _ = observed("test_observed", async {
+            Err::<(), _>(UserApplicationError::NotFound("43".to_string()))
+        })
+        .await;
+
+        let rendered = metrics().render().unwrap();
+
+        assert_eq!(sample(&rendered, ok), 1.0);
+        assert_eq!(sample(&rendered, not_found), 2.0);
+    }
+
+    #[test]
+    fn observe_http_records_counts_and_latency() {
+        metrics().observe_http("GET", "/test/{id}", 200, Duration::from_millis(3));
+
+        let rendered = metrics().render().unwrap();
+
+        assert_eq!(
+            sample(
+                &rendered,
+                r#"http_requests_total{method="GET",route="/test/{id}",status="200"}"#
+            ),
+            1.0
+        );
+        assert_eq!(
+            sample(
+                &rendered,
+                r#"http_request_duration_seconds_bucket{method="GET",route="/test/{id}",status="200",le="0.005"}"#
+            ),
+            1.0
+        );
+    }
+
+    #[test]
+    fn observe_user_cache_tracks_cumulative_stats() {
+        let stats = |hits| CacheStats {
+            hits,
+            negative_hits: 0,
+            misses: 0,
+        };
+
+        metrics().observe_user_cache(stats(3));
+        metrics().observe_user_cache(stats(5));
+
+        let rendered = metrics().render().unwrap();
+
+        assert_eq!(
+            sample(&rendered, r#"user_cache_lookups_total{result="hit"}"#),
+            5.0
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod app_metrics;
+pub mod pool_metrics;
@@ -0,0 +1,19 @@
+use r2d2::{
+    HandleEvent,
+    event::{CheckoutEvent, TimeoutEvent},
+};
+
+use crate::infrastructure::metrics::app_metrics::metrics;
+
+#[derive(Debug)]
+pub struct PoolMetrics;
+
+impl HandleEvent for PoolMetrics {
+    fn handle_checkout(&self, event: CheckoutEvent) {
+        metrics().observe_pool_checkout(event.duration());
+    }
+
+    fn handle_timeout(&self, event: TimeoutEvent) {
+        metrics().observe_pool_timeout(event.timeout());
+    }
+}
@@ -1,3 +1,12 @@
+pub mod auth;
+pub mod bootstrap;
+pub mod cache;
+pub mod crypto;
 pub mod db;
+pub mod health;
+pub mod metrics;
+pub mod notifications;
 pub mod repositories;
+pub mod shutdown;
+pub mod telemetry;
 pub mod web;
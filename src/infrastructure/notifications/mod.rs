@@ -0,0 +1 @@
+pub mod smtp_email_notifier;
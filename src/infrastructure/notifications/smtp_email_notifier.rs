@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, header::ContentType},
+};
+use tracing::info;
+
+use crate::domain::services::{
+    email_notifier::EmailNotifier,
+    pii_redactor::{PiiField, redact, redact_text},
+};
+
+pub struct SmtpEmailNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpEmailNotifier {
+    pub fn new(transport: AsyncSmtpTransport<Tokio1Executor>, from: Mailbox) -> Self {
+        Self { transport, from }
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    pub fn from_env() -> Result<Self, String> {
+        let var = |name: &str| std::env::var(name).map_err(|_| format!("{name} is missing"));
+
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::from_url(&var("SMTP_URL")?)
+            .map_err(|err| format!("SMTP_URL is invalid: {err}"))?
+            .build();
+        let from = var("EMAIL_FROM")?
+            .parse()
+            .map_err(|err| format!("EMAIL_FROM is invalid: {err}"))?;
+
+        Ok(Self::new(transport, from))
+    }
+
+    fn message(&self, to: &str, subject: &str, body: String) -> Result<Message, String> {
+        let to: Mailbox = to.parse().map_err(|_| {
+            format!(
+                "Cannot send an email to {}: the address is invalid",
+                redact(PiiField::Email, to)
+            )
+        })?;
+
+        Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body)
+            .map_err(|err| err.to_string())
+    }
+
+    async fn send(&self, to: &str, subject: &str, body: String) -> Result<(), String> {
+        let message = self.message(to, subject, body)?;
+
+        self.transport.send(message).await.map_err(|err| {
+            format!(
+                "Failed to send an email to {}: {}",
+                redact(PiiField::Email, to),
+                redact_text(&err.to_string())
+            )
+        })?;
+
+        info!("Sent \"{subject}\" to {}", redact(PiiField::Email, to));
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EmailNotifier for Arc<SmtpEmailNotifier> {
+    async fn send_email_change_confirmation(&self, to: &str, token: &str) -> Result<(), String> {
+        self.send(
+            to,
+            "Confirm your new email address",
+            format!(
+                "Someone asked to use this address for their account.\n\n\
+                 Confirm the change with this token within 24 hours:\n\n{token}\n\n\
+                 If it was not you, ignore this email and nothing will change.\n"
+            ),
+        )
+        .await
+    }
+
+    async fn send_email_change_notice(&self, to: &str, new_email: &str) -> Result<(), String> {
+        self.send(
+            to,
+            "Your email address is being changed",
+            format!(
+                "A change of your account email to {new_email} was requested.\n\n\
+                 It takes effect once confirmed from the new address. If it was not \
+                 you, contact support.\n"
+            ),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use lettre::{AsyncSmtpTransport, Tokio1Executor};
+
+    use crate::domain::services::email_notifier::EmailNotifier;
+
+    use super::SmtpEmailNotifier;
+
+    fn notifier(port: u16) -> Arc<SmtpEmailNotifier> {
+        Arc::new(SmtpEmailNotifier::new(
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous("127.0.0.1")
+                .port(port)
+                .build(),
+            "Users <no-reply@users.test>".parse().unwrap(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn message_is_addressed_to_the_recipient() {
+        let message = notifier(25)
+            .message("new@email.com", "Subject", "The token".to_string())
+            .unwrap();
+
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+
+        assert!(formatted.contains("From: Users <no-reply@users.test>"));
+        assert!(formatted.contains("To: new@email.com"));
+        assert!(formatted.contains("Subject: Subject"));
+        assert!(formatted.contains("The token"));
+    }
+
+    #[tokio::test]
+    async fn invalid_recipient_is_redacted() {
+        let result = notifier(25)
+            .send_email_change_confirmation("andrew.at.email.com", "token")
+            .await;
+
+        let err = result.unwrap_err();
+        assert!(err.starts_with("Cannot send an email to "));
+        assert!(err.ends_with(": the address is invalid"));
+        assert!(!err.contains("andrew.at.email.com"));
+    }
+
+    #[tokio::test]
+    async fn delivery_failure_is_reported_without_the_recipient() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let result = notifier(port)
+            .send_email_change_confirmation("new@email.com", "token")
+            .await;
+
+        let err = result.unwrap_err();
+        assert!(err.starts_with("Failed to send an email to n***@email.com: "));
+        assert!(!err.contains("new@email.com"));
+        assert!(!err.contains("token"));
+    }
+}
@@ -0,0 +1,305 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    application::auth::request_context::RequestContext,
+    domain::{
+        entities::{
+            user::User, user_audit_entry::UserAuditEntry, user_erasure::UserErasure,
+            user_merge::UserMerge,
+        },
+        errors::user_repository_error::UserRepositoryError,
+        repositories::{
+            auditable_user_repository::{AuditableUserRepository, UserAuditor},
+            user_repository::{UserBatchStream, UserRepository},
+        },
+        value_objects::{
+            creation_range::CreationRange, user_cursor::UserCursor, user_search_hit::UserSearchHit,
+        },
+    },
+};
+
+/// Records who changed a user, and what changed, on every write of the inner
+/// repository. The backend stores the entries in the transaction of the write.
+pub struct AuditedUserRepository<R: AuditableUserRepository> {
+    inner: R,
+    auditor: Arc<dyn UserAuditor>,
+}
+
+impl<R: AuditableUserRepository> AuditedUserRepository<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            auditor: Arc::new(RequestContextAuditor),
+        }
+    }
+}
+
+struct RequestContextAuditor;
+
+impl UserAuditor for RequestContextAuditor {
+    fn entry(
+        &self,
+        user_id: i32,
+        action: &str,
+        before: Option<&User>,
+        after: &User,
+        recorded_at: DateTime<Utc>,
+    ) -> UserAuditEntry {
+        let context = RequestContext::current();
+
+        UserAuditEntry::new(
+            user_id,
+            context.actor,
+            action,
+            context.request_id,
+            UserAuditEntry::diff(before, Some(after)),
+            recorded_at,
+        )
+    }
+}
+
+#[async_trait]
+impl<R> UserRepository for Arc<AuditedUserRepository<R>>
+where
+    R: AuditableUserRepository + Send + Sync,
+{
+    async fn save(&self, user: &User) -> Result<i32, UserRepositoryError> {
+        self.inner.save_audited(user, self.auditor.clone()).await
+    }
+
+    async fn save_batch(&self, users: &[User]) -> Result<Vec<i32>, UserRepositoryError> {
+        self.inner
+            .save_batch_audited(users, self.auditor.clone())
+            .await
+    }
+
+    async fn exists_by_email(&self, email: &str) -> Result<bool, UserRepositoryError> {
+        self.inner.exists_by_email(email).await
+    }
+
+    async fn find_existing_emails(
+        &self,
+        emails: &[String],
+    ) -> Result<Vec<String>, UserRepositoryError> {
+        self.inner.find_existing_emails(emails).await
+    }
+
+    async fn find_by_email(&self, email: String) -> Result<Option<User>, UserRepositoryError> {
+        self.inner.find_by_email(email).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<User>, UserRepositoryError> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn list(
+        &self,
+        range: &CreationRange,
+        after: Option<UserCursor>,
+        limit: i64,
+    ) -> Result<Vec<User>, UserRepositoryError> {
+        self.inner.list(range, after, limit).await
+    }
+
+    async fn stream(
+        &self,
+        range: &CreationRange,
+        batch_size: usize,
+    ) -> Result<UserBatchStream, UserRepositoryError> {
+        self.inner.stream(range, batch_size).await
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<UserSearchHit>, UserRepositoryError> {
+        self.inner.search(query, limit).await
+    }
+
+    async fn update_email(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.inner
+            .update_email_audited(user, self.auditor.clone())
+            .await
+    }
+
+    async fn update_profile(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.inner
+            .update_profile_audited(user, self.auditor.clone())
+            .await
+    }
+
+    async fn update_suspension(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.inner
+            .update_suspension_audited(user, self.auditor.clone())
+            .await
+    }
+
+    async fn erase(&self, user: &User, erasure: &UserErasure) -> Result<(), UserRepositoryError> {
+        self.inner
+            .erase_audited(user, erasure, self.auditor.clone())
+            .await
+    }
+
+    async fn find_erasures(&self, user_id: i32) -> Result<Vec<UserErasure>, UserRepositoryError> {
+        self.inner.find_erasures(user_id).await
+    }
+
+    async fn merge(
+        &self,
+        survivor: &User,
+        merged: &User,
+        merge: &UserMerge,
+    ) -> Result<(), UserRepositoryError> {
+        self.inner
+            .merge_audited(survivor, merged, merge, self.auditor.clone())
+            .await
+    }
+
+    async fn find_merges(&self, user_id: i32) -> Result<Vec<UserMerge>, UserRepositoryError> {
+        self.inner.find_merges(user_id).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use mockall::mock;
+    use serde_json::json;
+
+    use crate::{
+        application::auth::request_context::RequestContext,
+        domain::{
+            entities::{user::User, user_erasure::UserErasure, user_merge::UserMerge},
+            errors::user_repository_error::UserRepositoryError,
+            repositories::{
+                auditable_user_repository::{AuditableUserRepository, UserAuditor},
+                user_repository::{UserBatchStream, UserRepository},
+            },
+            value_objects::{creation_range::CreationRange, user_cursor::UserCursor},
+        },
+    };
+
+    use super::{AuditedUserRepository, RequestContextAuditor};
+
+    mock! {
+        Backend {}
+
+        #[async_trait]
+        impl UserRepository for Backend {
+            async fn save(&self, user: &User) -> Result<i32, UserRepositoryError>;
+            async fn save_batch(&self, users: &[User]) -> Result<Vec<i32>, UserRepositoryError>;
+            async fn exists_by_email(&self, email: &str) -> Result<bool, UserRepositoryError>;
+            async fn find_by_email(&self, email: String) -> Result<Option<User>, UserRepositoryError>;
+            async fn find_existing_emails(&self, emails: &[String]) -> Result<Vec<String>, UserRepositoryError>;
+            async fn find_by_id(&self, id: i32) -> Result<Option<User>, UserRepositoryError>;
+            async fn list(&self, range: &CreationRange, after: Option<UserCursor>, limit: i64) -> Result<Vec<User>, UserRepositoryError>;
+            async fn stream(&self, range: &CreationRange, batch_size: usize) -> Result<UserBatchStream, UserRepositoryError>;
+            async fn update_email(&self, user: &User) -> Result<(), UserRepositoryError>;
+            async fn update_profile(&self, user: &User) -> Result<(), UserRepositoryError>;
+            async fn update_suspension(&self, user: &User) -> Result<(), UserRepositoryError>;
+            async fn erase(&self, user: &User, erasure: &UserErasure) -> Result<(), UserRepositoryError>;
+            async fn find_erasures(&self, user_id: i32) -> Result<Vec<UserErasure>, UserRepositoryError>;
+            async fn merge(&self, survivor: &User, merged: &User, merge: &UserMerge) -> Result<(), UserRepositoryError>;
+            async fn find_merges(&self, user_id: i32) -> Result<Vec<UserMerge>, UserRepositoryError>;
+        }
+
+        #[async_trait]
+        impl AuditableUserRepository for Backend {
+            async fn save_audited(&self, user: &User, auditor: Arc<dyn UserAuditor>) -> Result<i32, UserRepositoryError>;
+            async fn save_batch_audited(&self, users: &[User], auditor: Arc<dyn UserAuditor>) -> Result<Vec<i32>, UserRepositoryError>;
+            async fn update_email_audited(&self, user: &User, auditor: Arc<dyn UserAuditor>) -> Result<(), UserRepositoryError>;
+            async fn update_profile_audited(&self, user: &User, auditor: Arc<dyn UserAuditor>) -> Result<(), UserRepositoryError>;
+            async fn update_suspension_audited(&self, user: &User, auditor: Arc<dyn UserAuditor>) -> Result<(), UserRepositoryError>;
+            async fn erase_audited(&self, user: &User, erasure: &UserErasure, auditor: Arc<dyn UserAuditor>) -> Result<(), UserRepositoryError>;
+            async fn merge_audited(&self, survivor: &User, merged: &User, merge: &UserMerge, auditor: Arc<dyn UserAuditor>) -> Result<(), UserRepositoryError>;
+        }
+    }
+
+    fn user() -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn writes_are_handed_to_the_backend_with_the_auditor() {
+        let mut backend = MockBackend::new();
+
+        backend
+            .expect_update_profile_audited()
+            .times(1)
+            .returning(|user, auditor| {
+                let entry = auditor.entry(42, "update", None, user, Utc::now());
+
+                assert_eq!(entry.actor, "7");
+                assert_eq!(entry.action, "update");
+                Ok(())
+            });
+        backend.expect_update_profile().never();
+
+        let sut = Arc::new(AuditedUserRepository::new(backend));
+
+        RequestContext::new("7".to_string(), None)
+            .scope(sut.update_profile(&user()))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn entry_records_the_request_context() {
+        let entry = RequestContext::new("7".to_string(), Some("req-1".to_string()))
+            .scope(async { RequestContextAuditor.entry(42, "create", None, &user(), Utc::now()) })
+            .await;
+
+        assert_eq!(entry.user_id, 42);
+        assert_eq!(entry.actor, "7");
+        assert_eq!(entry.action, "create");
+        assert_eq!(entry.request_id.as_deref(), Some("req-1"));
+        assert_eq!(
+            entry.changes["name"],
+            json!({ "before": null, "after": "[redacted]" })
+        );
+    }
+
+    #[test]
+    fn entry_outside_of_a_request_is_recorded_as_system() {
+        let mut erased = user();
+        erased.anonymize();
+
+        let entry = RequestContextAuditor.entry(42, "erase", Some(&user()), &erased, Utc::now());
+
+        assert_eq!(entry.actor, "system");
+        assert!(entry.request_id.is_none());
+        assert!(!entry.changes.to_string().contains("Andrew"));
+        assert!(!entry.changes.to_string().contains("Dawn St."));
+        assert_eq!(
+            entry.changes["name"],
+            json!({ "before": "[redacted]", "after": "[redacted]" })
+        );
+    }
+
+    #[test]
+    fn entry_only_records_changed_fields() {
+        let mut survivor = user();
+        survivor.name = "Andrew Smith".to_string();
+
+        let entry = RequestContextAuditor.entry(42, "merge", Some(&user()), &survivor, Utc::now());
+
+        assert_eq!(
+            entry.changes,
+            json!({ "name": { "before": "[redacted]", "after": "[redacted]" } })
+        );
+    }
+}
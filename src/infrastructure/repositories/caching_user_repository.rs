@@ -0,0 +1,498 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entities::{user::User, user_erasure::UserErasure, user_merge::UserMerge},
+        errors::user_repository_error::UserRepositoryError,
+        repositories::user_repository::{UserBatchStream, UserRepository},
+        value_objects::{
+            creation_range::CreationRange, id::ID, user_cursor::UserCursor,
+            user_search_hit::UserSearchHit,
+        },
+    },
+    infrastructure::{
+        cache::{
+            in_memory_user_cache::InMemoryUserCache,
+            user_cache::{CachedUser, UserCache, UserCacheKey},
+        },
+        repositories::{
+            audited_user_repository::AuditedUserRepository,
+            metered_user_repository::MeteredUserRepository,
+            postgres_user_repository::PostgresUserRepository,
+        },
+    },
+};
+
+pub type CachedPostgresUserRepository = CachingUserRepository<
+    Arc<MeteredUserRepository<Arc<AuditedUserRepository<Arc<PostgresUserRepository>>>>>,
+    InMemoryUserCache,
+>;
+
+const DEFAULT_TTL: Duration = Duration::from_secs(60);
+const DEFAULT_NEGATIVE_TTL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub negative_hits: u64,
+    pub misses: u64,
+}
+
+/// Caches lookups in process, so only the replica that performed a write
+/// invalidates its entries; other replicas serve theirs until the TTL expires.
+pub struct CachingUserRepository<R: UserRepository, C: UserCache> {
+    inner: R,
+    cache: C,
+    // Bumped on every invalidation. A load that started before a bump may have
+    // read the row the writer just replaced, so its result is not cached.
+    generation: RwLock<u64>,
+    ttl: Duration,
+    negative_ttl: Duration,
+    hits: AtomicU64,
+    negative_hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<R: UserRepository, C: UserCache> CachingUserRepository<R, C> {
+    pub fn new(inner: R, cache: C) -> Self {
+        Self {
+            inner,
+            cache,
+            generation: RwLock::new(0),
+            ttl: DEFAULT_TTL,
+            negative_ttl: DEFAULT_NEGATIVE_TTL,
+            hits: AtomicU64::new(0),
+            negative_hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration, negative_ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self.negative_ttl = negative_ttl;
+        self
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            negative_hits: self.negative_hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    async fn cached(
+        &self,
+        key: UserCacheKey,
+        load: impl Future<Output = Result<Option<User>, UserRepositoryError>>,
+    ) -> Result<Option<User>, UserRepositoryError> {
+        match self.cache.get(&key).await {
+            Some(CachedUser::Found(user)) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(Some(user));
+            }
+            Some(CachedUser::Missing) => {
+                self.negative_hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(None);
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let generation = *self.generation.read().await;
+        let user = load.await?;
+
+        let ttl = match user {
+            Some(_) => self.ttl,
+            None => self.negative_ttl,
+        };
+
+        let current = self.generation.read().await;
+        if *current == generation {
+            self.cache.put(key, user.clone().into(), ttl).await;
+        }
+
+        Ok(user)
+    }
+
+    async fn invalidate(&self, user: &User) {
+        if let ID::Existing(user_id) = user.id {
+            self.evict(&UserCacheKey::Id(user_id)).await;
+        }
+
+        self.evict(&UserCacheKey::email(&user.email)).await;
+    }
+
+    async fn evict(&self, key: &UserCacheKey) {
+        let mut generation = self.generation.write().await;
+        *generation += 1;
+
+        self.cache.invalidate(key).await;
+    }
+
+    async fn stored(&self, user: &User) -> Result<Option<User>, UserRepositoryError> {
+        match user.id {
+            ID::Existing(user_id) => self.inner.find_by_id(user_id).await,
+            ID::New => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl<R, C> UserRepository for Arc<CachingUserRepository<R, C>>
+where
+    R: UserRepository + Send + Sync,
+    C: UserCache,
+{
+    async fn save(&self, user: &User) -> Result<i32, UserRepositoryError> {
+        let user_id = self.inner.save(user).await?;
+
+        self.invalidate(user).await;
+        self.evict(&UserCacheKey::Id(user_id)).await;
+
+        Ok(user_id)
+    }
+
+    async fn save_batch(&self, users: &[User]) -> Result<Vec<i32>, UserRepositoryError> {
+        let user_ids = self.inner.save_batch(users).await?;
+
+        for (user_id, user) in user_ids.iter().zip(users) {
+            self.invalidate(user).await;
+            self.evict(&UserCacheKey::Id(*user_id)).await;
+        }
+
+        Ok(user_ids)
+    }
+
+    async fn exists_by_email(&self, email: &str) -> Result<bool, UserRepositoryError> {
+        match self.cache.get(&UserCacheKey::email(email)).await {
+            Some(CachedUser::Found(_)) => Ok(true),
+            Some(CachedUser::Missing) => Ok(false),
+            None => self.inner.exists_by_email(email).await,
+        }
+    }
+
+    async fn find_existing_emails(
+        &self,
+        emails: &[String],
+    ) -> Result<Vec<String>, UserRepositoryError> {
+        self.inner.find_existing_emails(emails).await
+    }
+
+    async fn find_by_email(&self, email: String) -> Result<Option<User>, UserRepositoryError> {
+        self.cached(
+            UserCacheKey::email(&email),
+            self.inner.find_by_email(email.clone()),
+        )
+        .await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<User>, UserRepositoryError> {
+        self.cached(UserCacheKey::Id(id), self.inner.find_by_id(id))
+            .await
+    }
+
+    async fn list(
+        &self,
+        range: &CreationRange,
+        after: Option<UserCursor>,
+        limit: i64,
+    ) -> Result<Vec<User>, UserRepositoryError> {
+        self.inner.list(range, after, limit).await
+    }
+
+    async fn stream(
+        &self,
+        range: &CreationRange,
+        batch_size: usize,
+    ) -> Result<UserBatchStream, UserRepositoryError> {
+        self.inner.stream(range, batch_size).await
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<UserSearchHit>, UserRepositoryError> {
+        self.inner.search(query, limit).await
+    }
+
+    async fn update_email(&self, user: &User) -> Result<(), UserRepositoryError> {
+        let before = self.stored(user).await?;
+
+        self.inner.update_email(user).await?;
+
+        if let Some(before) = before {
+            self.invalidate(&before).await;
+        }
+
+        self.invalidate(user).await;
+
+        Ok(())
+    }
+
+    async fn update_profile(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.inner.update_profile(user).await?;
+
+        self.invalidate(user).await;
+
+        Ok(())
+    }
+
+    async fn update_suspension(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.inner.update_suspension(user).await?;
+
+        self.invalidate(user).await;
+
+        Ok(())
+    }
+
+    async fn erase(&self, user: &User, erasure: &UserErasure) -> Result<(), UserRepositoryError> {
+        let before = self.stored(user).await?;
+
+        self.inner.erase(user, erasure).await?;
+
+        if let Some(before) = before {
+            self.invalidate(&before).await;
+        }
+
+        self.invalidate(user).await;
+
+        Ok(())
+    }
+
+    async fn find_erasures(&self, user_id: i32) -> Result<Vec<UserErasure>, UserRepositoryError> {
+        self.inner.find_erasures(user_id).await
+    }
+
+    async fn merge(
+        &self,
+        survivor: &User,
+        merged: &User,
+        merge: &UserMerge,
+    ) -> Result<(), UserRepositoryError> {
+        let survivor_before = self.stored(survivor).await?;
+        let merged_before = self.stored(merged).await?;
+
+        self.inner.merge(survivor, merged, merge).await?;
+
+        for user in [survivor_before, merged_before].iter().flatten() {
+            self.invalidate(user).await;
+        }
+
+        self.invalidate(survivor).await;
+        self.invalidate(merged).await;
+
+        Ok(())
+    }
+
+    async fn find_merges(&self, user_id: i32) -> Result<Vec<UserMerge>, UserRepositoryError> {
+        self.inner.find_merges(user_id).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            entities::{user::User, user_erasure::UserErasure},
+            repositories::user_repository::{MockUserRepository, UserRepository},
+        },
+        infrastructure::cache::{
+            in_memory_user_cache::InMemoryUserCache, user_cache::UserCacheKey,
+        },
+    };
+
+    use super::{CacheStats, CachingUserRepository};
+
+    fn user(email: &str) -> User {
+        User::restore(
+            42,
+            "Andrew".to_string(),
+            email.to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn find_by_email_is_served_from_the_cache() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_email()
+            .with(eq("andrew@email.com".to_string()))
+            .times(1)
+            .return_const(Ok(Some(user("andrew@email.com"))));
+
+        let sut = Arc::new(CachingUserRepository::new(
+            mock_user_repo,
+            InMemoryUserCache::new(10),
+        ));
+
+        sut.find_by_email("andrew@email.com".to_string()).await?;
+        let cached = sut.find_by_email("Andrew@Email.com ".to_string()).await?;
+
+        assert_eq!(cached, Some(user("andrew@email.com")));
+        assert!(sut.exists_by_email("andrew@email.com").await?);
+        assert_eq!(
+            sut.stats(),
+            CacheStats {
+                hits: 1,
+                negative_hits: 0,
+                misses: 1,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn misses_are_cached_until_a_save() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(2)
+            .return_const(Ok(None));
+        mock_user_repo.expect_save().times(1).return_const(Ok(42));
+
+        let sut = Arc::new(CachingUserRepository::new(
+            mock_user_repo,
+            InMemoryUserCache::new(10),
+        ));
+
+        assert_eq!(sut.find_by_id(42).await?, None);
+        assert_eq!(sut.find_by_id(42).await?, None);
+
+        sut.save(&User::new(
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        ))
+        .await?;
+
+        assert_eq!(sut.find_by_id(42).await?, None);
+        assert_eq!(
+            sut.stats(),
+            CacheStats {
+                hits: 0,
+                negative_hits: 1,
+                misses: 2,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_email_invalidates_the_previous_email() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_email()
+            .with(eq("andrew@email.com".to_string()))
+            .times(2)
+            .return_const(Ok(Some(user("andrew@email.com"))));
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(user("andrew@email.com"))));
+        mock_user_repo
+            .expect_update_email()
+            .times(1)
+            .return_const(Ok(()));
+
+        let sut = Arc::new(CachingUserRepository::new(
+            mock_user_repo,
+            InMemoryUserCache::new(10),
+        ));
+
+        sut.find_by_email("andrew@email.com".to_string()).await?;
+        sut.update_email(&user("drew@email.com")).await?;
+        sut.find_by_email("andrew@email.com".to_string()).await?;
+
+        assert_eq!(sut.stats().misses, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn erase_invalidates_the_user() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        let mut erased = user("andrew@email.com");
+        erased.anonymize();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(3)
+            .return_const(Ok(Some(user("andrew@email.com"))));
+        mock_user_repo.expect_erase().times(1).return_const(Ok(()));
+
+        let sut = Arc::new(CachingUserRepository::new(
+            mock_user_repo,
+            InMemoryUserCache::new(10),
+        ));
+
+        sut.find_by_id(42).await?;
+        sut.erase(&erased, &UserErasure::new(42, "1".to_string(), Utc::now()))
+            .await?;
+        sut.find_by_id(42).await?;
+
+        assert_eq!(sut.stats().misses, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn load_racing_an_invalidation_is_not_cached() -> Result<(), Box<dyn std::error::Error>> {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .with(eq(42))
+            .times(1)
+            .return_const(Ok(Some(user("drew@email.com"))));
+
+        let sut = Arc::new(CachingUserRepository::new(
+            mock_user_repo,
+            InMemoryUserCache::new(10),
+        ));
+
+        let stale = sut
+            .cached(UserCacheKey::Id(42), async {
+                sut.invalidate(&user("drew@email.com")).await;
+                Ok(Some(user("andrew@email.com")))
+            })
+            .await?;
+
+        assert_eq!(stale, Some(user("andrew@email.com")));
+        assert_eq!(sut.find_by_id(42).await?, Some(user("drew@email.com")));
+        assert_eq!(sut.find_by_id(42).await?, Some(user("drew@email.com")));
+        assert_eq!(sut.stats().hits, 1);
+
+        Ok(())
+    }
+}
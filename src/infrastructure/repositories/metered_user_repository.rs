@@ -0,0 +1,207 @@
+use std::{sync::Arc, time::Instant};
+
+use async_trait::async_trait;
+use tracing::{Instrument, field::Empty, info_span};
+
+use crate::{
+    domain::{
+        entities::{user::User, user_erasure::UserErasure, user_merge::UserMerge},
+        errors::user_repository_error::UserRepositoryError,
+        repositories::user_repository::{UserBatchStream, UserRepository},
+        value_objects::{
+            creation_range::CreationRange, user_cursor::UserCursor, user_search_hit::UserSearchHit,
+        },
+    },
+    infrastructure::metrics::app_metrics::metrics,
+};
+
+pub struct MeteredUserRepository<R: UserRepository> {
+    inner: R,
+    name: &'static str,
+}
+
+impl<R: UserRepository> MeteredUserRepository<R> {
+    pub fn new(inner: R, name: &'static str) -> Self {
+        Self { inner, name }
+    }
+
+    async fn timed<T>(
+        &self,
+        operation: &str,
+        query: impl Future<Output = Result<T, UserRepositoryError>>,
+    ) -> Result<T, UserRepositoryError> {
+        let span = info_span!(
+            "repository",
+            otel.name = format!("{}.{operation}", self.name),
+            repository = self.name,
+            operation,
+            outcome = Empty,
+        );
+        let started = Instant::now();
+
+        let result = query.instrument(span.clone()).await;
+
+        span.record("outcome", if result.is_ok() { "ok" } else { "error" });
+        metrics().observe_query(self.name, operation, &result, started.elapsed());
+
+        result
+    }
+}
+
+#[async_trait]
+impl<R> UserRepository for Arc<MeteredUserRepository<R>>
+where
+    R: UserRepository + Send + Sync,
+{
+    async fn save(&self, user: &User) -> Result<i32, UserRepositoryError> {
+        self.timed("save", self.inner.save(user)).await
+    }
+
+    async fn save_batch(&self, users: &[User]) -> Result<Vec<i32>, UserRepositoryError> {
+        self.timed("save_batch", self.inner.save_batch(users)).await
+    }
+
+    async fn exists_by_email(&self, email: &str) -> Result<bool, UserRepositoryError> {
+        self.timed("exists_by_email", self.inner.exists_by_email(email))
+            .await
+    }
+
+    async fn find_existing_emails(
+        &self,
+        emails: &[String],
+    ) -> Result<Vec<String>, UserRepositoryError> {
+        self.timed(
+            "find_existing_emails",
+            self.inner.find_existing_emails(emails),
+        )
+        .await
+    }
+
+    async fn find_by_email(&self, email: String) -> Result<Option<User>, UserRepositoryError> {
+        self.timed("find_by_email", self.inner.find_by_email(email))
+            .await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<User>, UserRepositoryError> {
+        self.timed("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn list(
+        &self,
+        range: &CreationRange,
+        after: Option<UserCursor>,
+        limit: i64,
+    ) -> Result<Vec<User>, UserRepositoryError> {
+        self.timed("list", self.inner.list(range, after, limit))
+            .await
+    }
+
+    async fn stream(
+        &self,
+        range: &CreationRange,
+        batch_size: usize,
+    ) -> Result<UserBatchStream, UserRepositoryError> {
+        self.timed("stream", self.inner.stream(range, batch_size))
+            .await
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<UserSearchHit>, UserRepositoryError> {
+        self.timed("search", self.inner.search(query, limit)).await
+    }
+
+    async fn update_email(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.timed("update_email", self.inner.update_email(user))
+            .await
+    }
+
+    async fn update_profile(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.timed("update_profile", self.inner.update_profile(user))
+            .await
+    }
+
+    async fn update_suspension(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.timed("update_suspension", self.inner.update_suspension(user))
+            .await
+    }
+
+    async fn erase(&self, user: &User, erasure: &UserErasure) -> Result<(), UserRepositoryError> {
+        self.timed("erase", self.inner.erase(user, erasure)).await
+    }
+
+    async fn find_erasures(&self, user_id: i32) -> Result<Vec<UserErasure>, UserRepositoryError> {
+        self.timed("find_erasures", self.inner.find_erasures(user_id))
+            .await
+    }
+
+    async fn merge(
+        &self,
+        survivor: &User,
+        merged: &User,
+        merge: &UserMerge,
+    ) -> Result<(), UserRepositoryError> {
+        self.timed("merge", self.inner.merge(survivor, merged, merge))
+            .await
+    }
+
+    async fn find_merges(&self, user_id: i32) -> Result<Vec<UserMerge>, UserRepositoryError> {
+        self.timed("find_merges", self.inner.find_merges(user_id))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::{
+        domain::{
+            errors::user_repository_error::UserRepositoryError,
+            repositories::user_repository::{MockUserRepository, UserRepository},
+        },
+        infrastructure::metrics::app_metrics::metrics,
+    };
+
+    use super::MeteredUserRepository;
+
+    fn observations(rendered: &str, outcome: &str) -> f64 {
+        let series = format!(
+            r#"repository_query_duration_seconds_count{{operation="find_by_id",outcome="{outcome}",repository="test_metered"}}"#
+        );
+
+        rendered
+            .lines()
+            .find_map(|line| line.strip_prefix(series.as_str()))
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn records_latency_by_outcome() {
+        let mut mock_user_repo = MockUserRepository::new();
+
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(None));
+        mock_user_repo
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(UserRepositoryError::DatabaseError(
+                "Fake Error".to_string(),
+            )));
+
+        let sut = Arc::new(MeteredUserRepository::new(mock_user_repo, "test_metered"));
+
+        assert!(sut.find_by_id(1).await.is_ok());
+        assert!(sut.find_by_id(2).await.is_err());
+
+        let rendered = metrics().render().unwrap();
+
+        assert_eq!(observations(&rendered, "ok"), 1.0);
+        assert_eq!(observations(&rendered, "error"), 1.0);
+    }
+}
@@ -1 +1,7 @@
+pub mod audited_user_repository;
+pub mod caching_user_repository;
+pub mod metered_user_repository;
+pub mod postgres_api_key_repository;
+pub mod postgres_email_change_repository;
+pub mod postgres_user_audit_repository;
 pub mod postgres_user_repository;
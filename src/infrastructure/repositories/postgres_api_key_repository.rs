@@ -0,0 +1,89 @@
+use crate::application::auth::request_context::RequestContext;
+use crate::domain::errors::api_key_repository_error::ApiKeyRepositoryError;
+use crate::schema::api_keys::dsl::{api_keys, created_at, id, key_hash, last_used_at, revoked_at};
+use crate::{
+    domain::{entities::api_key::ApiKey, repositories::api_key_repository::ApiKeyRepository},
+    infrastructure::db::connection::DBPool,
+    schema,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PostgresApiKeyRepository {
+    pool: DBPool,
+}
+
+impl PostgresApiKeyRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl From<diesel::result::Error> for ApiKeyRepositoryError {
+    fn from(value: diesel::result::Error) -> Self {
+        ApiKeyRepositoryError::DatabaseError(RequestContext::annotate(value.to_string()))
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for Arc<PostgresApiKeyRepository> {
+    async fn save(&self, api_key: &ApiKey) -> Result<i32, ApiKeyRepositoryError> {
+        let inserted_api_key_id = diesel::insert_into(schema::api_keys::table)
+            .values(api_key.clone())
+            .returning(id)
+            .get_result(&mut self.pool.get().unwrap())?;
+
+        Ok(inserted_api_key_id)
+    }
+
+    async fn list(&self) -> Result<Vec<ApiKey>, ApiKeyRepositoryError> {
+        let keys = api_keys
+            .order(created_at.desc())
+            .load::<ApiKey>(&mut self.pool.get().unwrap())?;
+
+        Ok(keys)
+    }
+
+    async fn find_by_hash(
+        &self,
+        input_hash: &str,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        let api_key = api_keys
+            .filter(key_hash.eq(input_hash))
+            .first::<ApiKey>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(api_key)
+    }
+
+    async fn revoke(
+        &self,
+        input_id: i32,
+        input_revoked_at: DateTime<Utc>,
+    ) -> Result<bool, ApiKeyRepositoryError> {
+        let updated = diesel::update(
+            api_keys
+                .filter(id.eq(input_id))
+                .filter(revoked_at.is_null()),
+        )
+        .set(revoked_at.eq(input_revoked_at))
+        .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(updated > 0)
+    }
+
+    async fn touch_last_used(
+        &self,
+        input_id: i32,
+        input_last_used_at: DateTime<Utc>,
+    ) -> Result<(), ApiKeyRepositoryError> {
+        diesel::update(api_keys.filter(id.eq(input_id)))
+            .set(last_used_at.eq(input_last_used_at))
+            .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(())
+    }
+}
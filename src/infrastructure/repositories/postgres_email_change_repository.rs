@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::pending_email_change::PendingEmailChange,
+        errors::user_repository_error::UserRepositoryError,
+        repositories::email_change_repository::EmailChangeRepository,
+    },
+    infrastructure::db::{
+        connection::DBPool,
+        pending_email_change_row::{NewPendingEmailChangeRow, PendingEmailChangeRow},
+    },
+    schema::pending_email_changes::dsl::{
+        confirmed_at, id, pending_email_changes, token_hash, user_id,
+    },
+};
+
+#[derive(Clone)]
+pub struct PostgresEmailChangeRepository {
+    pool: DBPool,
+}
+
+impl PostgresEmailChangeRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EmailChangeRepository for Arc<PostgresEmailChangeRepository> {
+    async fn replace_pending(
+        &self,
+        change: &PendingEmailChange,
+    ) -> Result<(), UserRepositoryError> {
+        self.pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                diesel::delete(
+                    pending_email_changes
+                        .filter(user_id.eq(change.user_id))
+                        .filter(confirmed_at.is_null()),
+                )
+                .execute(conn)?;
+
+                diesel::insert_into(pending_email_changes)
+                    .values(NewPendingEmailChangeRow::from(change))
+                    .execute(conn)?;
+
+                Ok(())
+            })?;
+
+        Ok(())
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        input_token_hash: &str,
+    ) -> Result<Option<PendingEmailChange>, UserRepositoryError> {
+        let change = pending_email_changes
+            .filter(token_hash.eq(input_token_hash))
+            .first::<PendingEmailChangeRow>(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(change.map(PendingEmailChange::from))
+    }
+
+    async fn mark_confirmed(
+        &self,
+        input_id: i32,
+        input_confirmed_at: DateTime<Utc>,
+    ) -> Result<bool, UserRepositoryError> {
+        let updated = diesel::update(
+            pending_email_changes
+                .filter(id.eq(input_id))
+                .filter(confirmed_at.is_null()),
+        )
+        .set(confirmed_at.eq(input_confirmed_at))
+        .execute(&mut self.pool.get().unwrap())?;
+
+        Ok(updated == 1)
+    }
+}
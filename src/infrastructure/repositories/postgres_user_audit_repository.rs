@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::user_audit_entry::UserAuditEntry,
+        errors::user_repository_error::UserRepositoryError,
+        repositories::user_audit_repository::UserAuditRepository,
+    },
+    infrastructure::db::connection::DBPool,
+    schema::user_audit_log,
+};
+
+#[derive(Clone)]
+pub struct PostgresUserAuditRepository {
+    pool: DBPool,
+}
+
+impl PostgresUserAuditRepository {
+    pub fn new(pool: DBPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserAuditRepository for Arc<PostgresUserAuditRepository> {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<UserAuditEntry>, UserRepositoryError> {
+        let entries = user_audit_log::table
+            .filter(user_audit_log::user_id.eq(user_id))
+            .order((user_audit_log::recorded_at.asc(), user_audit_log::id.asc()))
+            .load::<UserAuditEntry>(&mut self.pool.get().unwrap())?;
+
+        Ok(entries)
+    }
+}
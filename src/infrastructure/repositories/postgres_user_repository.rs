@@ -1,49 +1,427 @@
+use crate::application::auth::request_context::RequestContext;
+use crate::domain::entities::user_erasure::UserErasure;
+use crate::domain::entities::user_merge::UserMerge;
 use crate::domain::errors::user_repository_error::UserRepositoryError;
-use crate::schema::users::dsl::{email, id, users};
+use crate::domain::services::clock::{Clock, SystemClock};
+use crate::domain::services::email_normalizer::normalize_email;
+use crate::domain::services::pii_redactor::redact_text;
+use crate::domain::services::user_search::{MIN_SIMILARITY, search_terms};
+use crate::domain::value_objects::creation_range::CreationRange;
+use crate::domain::value_objects::user_cursor::UserCursor;
+use crate::domain::value_objects::user_search_hit::UserSearchHit;
+use crate::infrastructure::crypto::pii_cipher::{PiiCipher, cipher};
+use crate::infrastructure::db::encrypted_text::{EncryptedEmail, EncryptedText};
+use crate::infrastructure::db::user_row::{NewUserRow, UserRow};
+use crate::schema::users::dsl::{
+    address, created_at, email, email_bidx, id, name, phone, suspended_at, updated_at, users,
+};
+use crate::schema::{user_audit_log, user_erasures, user_merges};
 use crate::{
-    domain::{entities::user::User, repositories::user_repository::UserRepository},
-    infrastructure::db::connection::{DBPool, establish_connection},
+    domain::{
+        entities::user::User,
+        repositories::{
+            auditable_user_repository::{AuditableUserRepository, UserAuditor},
+            user_repository::{UserBatchStream, UserRepository},
+        },
+        value_objects::id::ID,
+    },
+    infrastructure::db::connection::DBPool,
     schema,
 };
 use async_trait::async_trait;
-use diesel::dsl::exists;
-use diesel::{prelude::*, select};
+use chrono::{DateTime, Utc};
+use diesel::dsl::{exists, not};
+use diesel::result::DatabaseErrorKind;
+use diesel::sql_types::{BigInt, Double, Nullable, Text, Timestamptz};
+use diesel::{prelude::*, select, sql_query};
+use futures_util::stream;
 use std::sync::Arc;
+use tokio::sync::mpsc;
+
+define_sql_function! {
+    fn lower(value: Text) -> Text;
+}
+
+const DECLARE_EXPORT_CURSOR: &str = "DECLARE user_export NO SCROLL CURSOR FOR \
+    SELECT id, name, email, phone, address, created_at, updated_at, suspended_at FROM users \
+    WHERE ($1::timestamptz IS NULL OR created_at >= $1) \
+    AND ($2::timestamptz IS NULL OR created_at < $2) \
+    AND NOT EXISTS (SELECT 1 FROM user_merges WHERE merged_id = users.id) \
+    ORDER BY created_at, id";
+
+const SEARCH_USERS: &str = "SELECT id, name, email, phone, address, created_at, updated_at, \
+    suspended_at, \
+    (CASE WHEN $2 <> '' THEN ts_rank(to_tsvector('simple', name), to_tsquery('simple', $2)) ELSE 0 END \
+    + word_similarity($1, name) \
+    + CASE WHEN email_bidx = $3 THEN 1 ELSE 0 END \
+    + CASE WHEN email NOT LIKE 'enc:v1:%' THEN similarity(split_part($1, '@', 1), split_part(lower(email), '@', 1)) ELSE 0 END \
+    )::float8 AS score \
+    FROM users \
+    WHERE (($2 <> '' AND to_tsvector('simple', name) @@ to_tsquery('simple', $2)) \
+    OR $1 <% name \
+    OR email_bidx = $3 \
+    OR (email NOT LIKE 'enc:v1:%' AND split_part($1, '@', 1) % split_part(lower(email), '@', 1))) \
+    AND NOT EXISTS (SELECT 1 FROM user_merges WHERE merged_id = users.id) \
+    ORDER BY score DESC, id \
+    LIMIT $4";
+
+#[derive(QueryableByName)]
+struct ScoredUser {
+    #[diesel(embed)]
+    user: UserRow,
+    #[diesel(sql_type = Double)]
+    score: f64,
+}
 
 #[derive(Clone)]
 pub struct PostgresUserRepository {
     pool: DBPool,
+    clock: Arc<dyn Clock>,
 }
 
 impl PostgresUserRepository {
-    pub fn new() -> Self {
-        let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL is missing");
+    pub fn new(pool: DBPool) -> Self {
         Self {
-            pool: establish_connection(&database_url),
+            pool,
+            clock: Arc::new(SystemClock),
         }
     }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    async fn save_with(
+        &self,
+        user: &User,
+        auditor: Option<&dyn UserAuditor>,
+    ) -> Result<i32, UserRepositoryError> {
+        let input_email_bidx = cipher()
+            .map_err(UserRepositoryError::DatabaseError)?
+            .blind_index(&normalize_email(&user.email));
+
+        let now = self.clock.now();
+
+        let inserted_user_id = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let inserted_user_id = diesel::insert_into(schema::users::table)
+                    .values((
+                        NewUserRow::from(user),
+                        email_bidx.eq(input_email_bidx),
+                        created_at.eq(now),
+                        updated_at.eq(now),
+                    ))
+                    .returning(id)
+                    .get_result(conn)?;
+
+                record(conn, auditor, inserted_user_id, "create", None, user, now)?;
+
+                Ok(inserted_user_id)
+            })?;
+
+        Ok(inserted_user_id)
+    }
+
+    async fn save_batch_with(
+        &self,
+        new_users: &[User],
+        auditor: Option<&dyn UserAuditor>,
+    ) -> Result<Vec<i32>, UserRepositoryError> {
+        let cipher = cipher().map_err(UserRepositoryError::DatabaseError)?;
+        let now = self.clock.now();
+
+        let rows: Vec<_> = new_users
+            .iter()
+            .map(|user| {
+                (
+                    NewUserRow::from(user),
+                    email_bidx.eq(cipher.blind_index(&normalize_email(&user.email))),
+                    created_at.eq(now),
+                    updated_at.eq(now),
+                )
+            })
+            .collect();
+
+        let inserted_user_ids = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let inserted_user_ids: Vec<i32> = diesel::insert_into(schema::users::table)
+                    .values(rows)
+                    .returning(id)
+                    .get_results(conn)?;
+
+                for (inserted_user_id, user) in inserted_user_ids.iter().zip(new_users) {
+                    record(conn, auditor, *inserted_user_id, "create", None, user, now)?;
+                }
+
+                Ok(inserted_user_ids)
+            })?;
+
+        Ok(inserted_user_ids)
+    }
+
+    async fn update_email_with(
+        &self,
+        user: &User,
+        auditor: Option<&dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        let ID::Existing(user_id) = user.id else {
+            return Err(UserRepositoryError::DatabaseError(
+                "Cannot update a user that was never saved".to_string(),
+            ));
+        };
+
+        let input_email_bidx = cipher()
+            .map_err(UserRepositoryError::DatabaseError)?
+            .blind_index(&normalize_email(&user.email));
+
+        let now = self.clock.now();
+
+        self.pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let Some(before) = find_for_update(conn, user_id)? else {
+                    return Ok(());
+                };
+
+                diesel::update(users.find(user_id))
+                    .set((
+                        email.eq(EncryptedEmail(user.email.clone())),
+                        email_bidx.eq(input_email_bidx),
+                        updated_at.eq(now),
+                    ))
+                    .execute(conn)?;
+
+                record(
+                    conn,
+                    auditor,
+                    user_id,
+                    "change_email",
+                    Some(&before),
+                    user,
+                    now,
+                )
+            })?;
+
+        Ok(())
+    }
+
+    async fn update_profile_with(
+        &self,
+        user: &User,
+        auditor: Option<&dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        let ID::Existing(user_id) = user.id else {
+            return Err(UserRepositoryError::DatabaseError(
+                "Cannot update a user that was never saved".to_string(),
+            ));
+        };
+
+        let now = self.clock.now();
+
+        self.pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let Some(before) = find_for_update(conn, user_id)? else {
+                    return Ok(());
+                };
+
+                diesel::update(users.find(user_id))
+                    .set((
+                        name.eq(&user.name),
+                        phone.eq(EncryptedText(user.phone.clone())),
+                        address.eq(EncryptedText(user.address.clone())),
+                        updated_at.eq(now),
+                    ))
+                    .execute(conn)?;
+
+                record(conn, auditor, user_id, "update", Some(&before), user, now)
+            })?;
+
+        Ok(())
+    }
+
+    async fn update_suspension_with(
+        &self,
+        user: &User,
+        auditor: Option<&dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        let ID::Existing(user_id) = user.id else {
+            return Err(UserRepositoryError::DatabaseError(
+                "Cannot update a user that was never saved".to_string(),
+            ));
+        };
+
+        let action = if user.is_suspended() {
+            "suspend"
+        } else {
+            "reinstate"
+        };
+        let now = self.clock.now();
+
+        self.pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let Some(before) = find_for_update(conn, user_id)? else {
+                    return Ok(());
+                };
+
+                diesel::update(users.find(user_id))
+                    .set((suspended_at.eq(user.suspended_at), updated_at.eq(now)))
+                    .execute(conn)?;
+
+                record(conn, auditor, user_id, action, Some(&before), user, now)
+            })?;
+
+        Ok(())
+    }
+
+    async fn erase_with(
+        &self,
+        user: &User,
+        erasure: &UserErasure,
+        auditor: Option<&dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        let ID::Existing(user_id) = user.id else {
+            return Err(UserRepositoryError::DatabaseError(
+                "Cannot erase a user that was never saved".to_string(),
+            ));
+        };
+
+        let cipher = cipher().map_err(UserRepositoryError::DatabaseError)?;
+        let now = self.clock.now();
+
+        self.pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let before = find_for_update(conn, user_id)?;
+
+                overwrite(conn, user_id, user, cipher, now)?;
+
+                diesel::insert_into(user_erasures::table)
+                    .values(erasure.clone())
+                    .execute(conn)?;
+
+                record(conn, auditor, user_id, "erase", before.as_ref(), user, now)
+            })?;
+
+        Ok(())
+    }
+
+    async fn merge_with(
+        &self,
+        survivor: &User,
+        merged: &User,
+        merge: &UserMerge,
+        auditor: Option<&dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        let (ID::Existing(survivor_id), ID::Existing(merged_id)) = (&survivor.id, &merged.id)
+        else {
+            return Err(UserRepositoryError::DatabaseError(
+                "Cannot merge users that were never saved".to_string(),
+            ));
+        };
+
+        let cipher = cipher().map_err(UserRepositoryError::DatabaseError)?;
+        let now = self.clock.now();
+
+        self.pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                let locked: Vec<User> = users
+                    .filter(id.eq_any([*survivor_id, *merged_id]))
+                    .order(id)
+                    .select(UserRow::as_select())
+                    .for_update()
+                    .load::<UserRow>(conn)?
+                    .into_iter()
+                    .map(User::from)
+                    .collect();
+                let before =
+                    |user_id: i32| locked.iter().find(|user| user.id == ID::Existing(user_id));
+
+                overwrite(conn, *merged_id, merged, cipher, now)?;
+                overwrite(conn, *survivor_id, survivor, cipher, now)?;
+
+                diesel::insert_into(user_merges::table)
+                    .values(merge.clone())
+                    .execute(conn)?;
+
+                record(
+                    conn,
+                    auditor,
+                    *survivor_id,
+                    "merge",
+                    before(*survivor_id),
+                    survivor,
+                    now,
+                )?;
+                record(
+                    conn,
+                    auditor,
+                    *merged_id,
+                    "merged",
+                    before(*merged_id),
+                    merged,
+                    now,
+                )
+            })?;
+
+        Ok(())
+    }
 }
 
 impl From<diesel::result::Error> for UserRepositoryError {
     fn from(value: diesel::result::Error) -> Self {
-        UserRepositoryError::DatabaseError(value.to_string())
+        match value {
+            diesel::result::Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                UserRepositoryError::Conflict(RequestContext::annotate(redact_text(
+                    &value.to_string(),
+                )))
+            }
+            _ => UserRepositoryError::DatabaseError(RequestContext::annotate(redact_text(
+                &value.to_string(),
+            ))),
+        }
     }
 }
 
 #[async_trait]
 impl UserRepository for Arc<PostgresUserRepository> {
     async fn save(&self, user: &User) -> Result<i32, UserRepositoryError> {
-        let inserted_user_id = diesel::insert_into(schema::users::table)
-            .values(user.clone())
-            .returning(id)
-            .get_result(&mut self.pool.get().unwrap())?;
+        self.save_with(user, None).await
+    }
 
-        Ok(inserted_user_id)
+    async fn save_batch(&self, new_users: &[User]) -> Result<Vec<i32>, UserRepositoryError> {
+        self.save_batch_with(new_users, None).await
     }
 
     async fn exists_by_email(&self, input_email: &str) -> Result<bool, UserRepositoryError> {
-        let exists_by_email = select(exists(users.filter(email.eq(input_email))))
-            .get_result(&mut self.pool.get().unwrap())?;
+        let input_email = normalize_email(input_email);
+
+        let input_email_bidx = cipher()
+            .map_err(UserRepositoryError::DatabaseError)?
+            .blind_index(&input_email);
+
+        let exists_by_email = select(exists(
+            users.filter(
+                email_bidx
+                    .eq(input_email_bidx)
+                    .or(email_bidx.is_null().and(lower(email).eq(input_email))),
+            ),
+        ))
+        .get_result(&mut self.pool.get().unwrap())?;
 
         Ok(exists_by_email)
     }
@@ -52,11 +430,536 @@ impl UserRepository for Arc<PostgresUserRepository> {
         &self,
         input_email: String,
     ) -> Result<Option<User>, UserRepositoryError> {
+        let input_email = normalize_email(&input_email);
+
+        let input_email_bidx = cipher()
+            .map_err(UserRepositoryError::DatabaseError)?
+            .blind_index(&input_email);
+
         let user = users
-            .filter(email.eq(input_email))
-            .first::<User>(&mut self.pool.get().unwrap())
+            .filter(
+                email_bidx
+                    .eq(input_email_bidx)
+                    .or(email_bidx.is_null().and(lower(email).eq(input_email))),
+            )
+            .select(UserRow::as_select())
+            .first(&mut self.pool.get().unwrap())
             .optional()?;
 
-        Ok(user)
+        Ok(user.map(User::from))
+    }
+
+    async fn find_existing_emails(
+        &self,
+        input_emails: &[String],
+    ) -> Result<Vec<String>, UserRepositoryError> {
+        let cipher = cipher().map_err(UserRepositoryError::DatabaseError)?;
+
+        let input_emails: Vec<String> = input_emails
+            .iter()
+            .map(|input_email| normalize_email(input_email))
+            .collect();
+
+        let input_email_bidxs: Vec<String> = input_emails
+            .iter()
+            .map(|input_email| cipher.blind_index(input_email))
+            .collect();
+
+        let existing_emails = users
+            .filter(
+                email_bidx
+                    .eq_any(input_email_bidxs)
+                    .or(email_bidx.is_null().and(lower(email).eq_any(&input_emails))),
+            )
+            .select(email)
+            .load::<EncryptedEmail>(&mut self.pool.get().unwrap())?;
+
+        Ok(existing_emails
+            .into_iter()
+            .map(|existing_email| normalize_email(&existing_email.0))
+            .collect())
+    }
+
+    async fn find_by_id(&self, input_id: i32) -> Result<Option<User>, UserRepositoryError> {
+        let user = users
+            .find(input_id)
+            .select(UserRow::as_select())
+            .first(&mut self.pool.get().unwrap())
+            .optional()?;
+
+        Ok(user.map(User::from))
+    }
+
+    async fn list(
+        &self,
+        range: &CreationRange,
+        after: Option<UserCursor>,
+        limit: i64,
+    ) -> Result<Vec<User>, UserRepositoryError> {
+        let mut query = users
+            .select(UserRow::as_select())
+            .filter(not(exists(
+                user_merges::table.filter(user_merges::merged_id.eq(id)),
+            )))
+            .into_boxed();
+
+        if let Some(from) = range.from {
+            query = query.filter(created_at.ge(from));
+        }
+
+        if let Some(to) = range.to {
+            query = query.filter(created_at.lt(to));
+        }
+
+        if let Some(after) = after {
+            query = query.filter(
+                created_at
+                    .gt(after.created_at)
+                    .or(created_at.eq(after.created_at).and(id.gt(after.id))),
+            );
+        }
+
+        let loaded_users = query
+            .order((created_at.asc(), id.asc()))
+            .limit(limit)
+            .load::<UserRow>(&mut self.pool.get().unwrap())?;
+
+        Ok(loaded_users.into_iter().map(User::from).collect())
+    }
+
+    async fn stream(
+        &self,
+        range: &CreationRange,
+        batch_size: usize,
+    ) -> Result<UserBatchStream, UserRepositoryError> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|err| UserRepositoryError::DatabaseError(err.to_string()))?;
+
+        let range = range.clone();
+        let (sender, receiver) = mpsc::channel(1);
+
+        tokio::task::spawn_blocking(move || {
+            let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+                sql_query(DECLARE_EXPORT_CURSOR)
+                    .bind::<Nullable<Timestamptz>, _>(range.from)
+                    .bind::<Nullable<Timestamptz>, _>(range.to)
+                    .execute(conn)?;
+
+                let fetch = format!("FETCH FORWARD {batch_size} FROM user_export");
+
+                loop {
+                    let batch = sql_query(&fetch).load::<UserRow>(conn)?;
+
+                    if batch.is_empty()
+                        || sender
+                            .blocking_send(Ok(batch.into_iter().map(User::from).collect()))
+                            .is_err()
+                    {
+                        return Ok(());
+                    }
+                }
+            });
+
+            if let Err(err) = result {
+                let _ = sender.blocking_send(Err(err.into()));
+            }
+        });
+
+        Ok(Box::pin(stream::unfold(receiver, |mut receiver| async {
+            receiver.recv().await.map(|batch| (batch, receiver))
+        })))
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<UserSearchHit>, UserRepositoryError> {
+        let query = normalize_email(query);
+
+        let prefix_query = search_terms(&query)
+            .iter()
+            .map(|term| format!("{term}:*"))
+            .collect::<Vec<_>>()
+            .join(" & ");
+
+        let query_email_bidx = cipher()
+            .map_err(UserRepositoryError::DatabaseError)?
+            .blind_index(&query);
+
+        let scored_users = self
+            .pool
+            .get()
+            .unwrap()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for setting in ["similarity_threshold", "word_similarity_threshold"] {
+                    sql_query(format!("SET LOCAL pg_trgm.{setting} = {MIN_SIMILARITY}"))
+                        .execute(conn)?;
+                }
+
+                sql_query(SEARCH_USERS)
+                    .bind::<Text, _>(&query)
+                    .bind::<Text, _>(&prefix_query)
+                    .bind::<Text, _>(&query_email_bidx)
+                    .bind::<BigInt, _>(limit)
+                    .load::<ScoredUser>(conn)
+            })?;
+
+        Ok(scored_users
+            .into_iter()
+            .map(|scored| UserSearchHit::new(scored.user.into(), scored.score))
+            .collect())
+    }
+
+    async fn update_email(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.update_email_with(user, None).await
+    }
+
+    async fn update_profile(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.update_profile_with(user, None).await
+    }
+
+    async fn update_suspension(&self, user: &User) -> Result<(), UserRepositoryError> {
+        self.update_suspension_with(user, None).await
+    }
+
+    async fn erase(&self, user: &User, erasure: &UserErasure) -> Result<(), UserRepositoryError> {
+        self.erase_with(user, erasure, None).await
+    }
+
+    async fn find_erasures(
+        &self,
+        input_user_id: i32,
+    ) -> Result<Vec<UserErasure>, UserRepositoryError> {
+        let erasures = user_erasures::table
+            .filter(user_erasures::user_id.eq(input_user_id))
+            .order(user_erasures::erased_at.asc())
+            .load::<UserErasure>(&mut self.pool.get().unwrap())?;
+
+        Ok(erasures)
+    }
+
+    async fn merge(
+        &self,
+        survivor: &User,
+        merged: &User,
+        merge: &UserMerge,
+    ) -> Result<(), UserRepositoryError> {
+        self.merge_with(survivor, merged, merge, None).await
+    }
+
+    async fn find_merges(&self, input_user_id: i32) -> Result<Vec<UserMerge>, UserRepositoryError> {
+        let merges = user_merges::table
+            .filter(
+                user_merges::survivor_id
+                    .eq(input_user_id)
+                    .or(user_merges::merged_id.eq(input_user_id)),
+            )
+            .order(user_merges::merged_at.asc())
+            .load::<UserMerge>(&mut self.pool.get().unwrap())?;
+
+        Ok(merges)
+    }
+}
+
+#[async_trait]
+impl AuditableUserRepository for Arc<PostgresUserRepository> {
+    async fn save_audited(
+        &self,
+        user: &User,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<i32, UserRepositoryError> {
+        self.save_with(user, Some(auditor.as_ref())).await
+    }
+
+    async fn save_batch_audited(
+        &self,
+        new_users: &[User],
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<Vec<i32>, UserRepositoryError> {
+        self.save_batch_with(new_users, Some(auditor.as_ref()))
+            .await
+    }
+
+    async fn update_email_audited(
+        &self,
+        user: &User,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        self.update_email_with(user, Some(auditor.as_ref())).await
+    }
+
+    async fn update_profile_audited(
+        &self,
+        user: &User,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        self.update_profile_with(user, Some(auditor.as_ref())).await
+    }
+
+    async fn update_suspension_audited(
+        &self,
+        user: &User,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        self.update_suspension_with(user, Some(auditor.as_ref()))
+            .await
+    }
+
+    async fn erase_audited(
+        &self,
+        user: &User,
+        erasure: &UserErasure,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        self.erase_with(user, erasure, Some(auditor.as_ref())).await
+    }
+
+    async fn merge_audited(
+        &self,
+        survivor: &User,
+        merged: &User,
+        merge: &UserMerge,
+        auditor: Arc<dyn UserAuditor>,
+    ) -> Result<(), UserRepositoryError> {
+        self.merge_with(survivor, merged, merge, Some(auditor.as_ref()))
+            .await
+    }
+}
+
+fn overwrite(
+    conn: &mut PgConnection,
+    user_id: i32,
+    user: &User,
+    cipher: &PiiCipher,
+    now: DateTime<Utc>,
+) -> Result<(), diesel::result::Error> {
+    diesel::update(users.find(user_id))
+        .set((
+            name.eq(&user.name),
+            email.eq(EncryptedEmail(user.email.clone())),
+            phone.eq(EncryptedText(user.phone.clone())),
+            address.eq(EncryptedText(user.address.clone())),
+            email_bidx.eq(cipher.blind_index(&normalize_email(&user.email))),
+            updated_at.eq(now),
+        ))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+fn find_for_update(
+    conn: &mut PgConnection,
+    user_id: i32,
+) -> Result<Option<User>, diesel::result::Error> {
+    let user = users
+        .find(user_id)
+        .select(UserRow::as_select())
+        .for_update()
+        .first(conn)
+        .optional()?;
+
+    Ok(user.map(User::from))
+}
+
+/// Appends the entry the auditor builds on the connection of the mutation it
+/// describes, so the change and its history commit or roll back together.
+fn record(
+    conn: &mut PgConnection,
+    auditor: Option<&dyn UserAuditor>,
+    user_id: i32,
+    action: &str,
+    before: Option<&User>,
+    after: &User,
+    now: DateTime<Utc>,
+) -> Result<(), diesel::result::Error> {
+    let Some(auditor) = auditor else {
+        return Ok(());
+    };
+
+    diesel::insert_into(user_audit_log::table)
+        .values(auditor.entry(user_id, action, before, after, now))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, time::Duration};
+
+    use chrono::{DateTime, TimeZone, Utc};
+    use diesel::prelude::*;
+    use futures_util::StreamExt;
+
+    use crate::{
+        domain::{
+            entities::user::User, repositories::user_repository::UserRepository,
+            services::clock::MockClock, value_objects::creation_range::CreationRange,
+        },
+        infrastructure::{
+            crypto::pii_cipher::{self, PiiCipher, cipher},
+            db::{
+                connection::{DBPool, establish_connection},
+                user_row::NewUserRow,
+            },
+            repositories::postgres_user_repository::PostgresUserRepository,
+        },
+        schema::users,
+    };
+
+    diesel::table! {
+        pg_stat_activity (pid) {
+            pid -> Int4,
+            state -> Nullable<Text>,
+            query -> Nullable<Text>,
+        }
+    }
+
+    const BATCHES: usize = 40;
+    const BATCH_SIZE: usize = 100;
+
+    fn pool() -> DBPool {
+        pii_cipher::init(PiiCipher::from_env().expect("The PII keys are missing"));
+
+        establish_connection(&std::env::var("DATABASE_URL").expect("DATABASE_URL is missing"))
+    }
+
+    /// Deletes the users created at a test's own instant, even when the test
+    /// panics half way.
+    struct Seeded {
+        pool: DBPool,
+        created_at: DateTime<Utc>,
+    }
+
+    impl Seeded {
+        fn at(pool: &DBPool) -> Self {
+            let run = rand::random::<u32>() % 10_000_000;
+
+            Self {
+                pool: pool.clone(),
+                created_at: Utc.with_ymd_and_hms(1990, 1, 1, 0, 0, 0).unwrap()
+                    + chrono::Duration::seconds(run.into()),
+            }
+        }
+
+        fn range(&self) -> CreationRange {
+            CreationRange::new(
+                Some(self.created_at),
+                Some(self.created_at + chrono::Duration::seconds(1)),
+            )
+            .unwrap()
+        }
+    }
+
+    impl Drop for Seeded {
+        fn drop(&mut self) {
+            if let Ok(mut conn) = self.pool.get() {
+                let _ = diesel::delete(users::table.filter(users::created_at.eq(self.created_at)))
+                    .execute(&mut conn);
+            }
+        }
+    }
+
+    fn clock(at: DateTime<Utc>) -> Arc<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(at);
+        Arc::new(clock)
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    #[tokio::test]
+    #[ignore = "needs DATABASE_URL and the PII keys"]
+    async fn save_and_update_stamp_the_time_of_the_clock() {
+        let pool = pool();
+        let seeded = Seeded::at(&pool);
+        let updated_at = seeded.created_at + chrono::Duration::days(1);
+
+        let repo = PostgresUserRepository::new(pool.clone());
+        let saving = Arc::new(repo.clone().with_clock(clock(seeded.created_at)));
+        let updating = Arc::new(repo.with_clock(clock(updated_at)));
+
+        let mut user = User::new(
+            "Andrew".to_string(),
+            format!("clock-{}@email.com", seeded.created_at.timestamp()),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        );
+        let user_id = saving.save(&user).await.unwrap();
+        user.id = user_id.into();
+
+        let saved = saving.find_by_id(user_id).await.unwrap().unwrap();
+
+        assert_eq!(saved.created_at, Some(seeded.created_at));
+        assert_eq!(saved.updated_at, Some(seeded.created_at));
+
+        user.address = "Dusk St.".to_string();
+        updating.update_profile(&user).await.unwrap();
+
+        let updated = updating.find_by_id(user_id).await.unwrap().unwrap();
+
+        assert_eq!(updated.created_at, Some(seeded.created_at));
+        assert_eq!(updated.updated_at, Some(updated_at));
+    }
+
+    #[cfg(not(tarpaulin_include))]
+    #[tokio::test]
+    #[ignore = "needs DATABASE_URL and the PII keys"]
+    async fn stream_holds_the_cursor_while_the_reader_is_paused() {
+        let pool = pool();
+        let seeded = Seeded::at(&pool);
+        let cipher = cipher().unwrap();
+
+        let rows: Vec<_> = (0..BATCHES * BATCH_SIZE)
+            .map(|index| {
+                let email = format!("export-{index}-{}@email.com", seeded.created_at.timestamp());
+
+                (
+                    NewUserRow::from(&User::new(
+                        format!("Export {index}"),
+                        email.clone(),
+                        "+001133334444".to_string(),
+                        "Dawn St.".to_string(),
+                    )),
+                    users::email_bidx.eq(cipher.blind_index(&email)),
+                    users::created_at.eq(seeded.created_at),
+                    users::updated_at.eq(seeded.created_at),
+                )
+            })
+            .collect();
+
+        diesel::insert_into(users::table)
+            .values(rows)
+            .execute(&mut pool.get().unwrap())
+            .unwrap();
+
+        let mut batches = Arc::new(PostgresUserRepository::new(pool.clone()))
+            .stream(&seeded.range(), BATCH_SIZE)
+            .await
+            .unwrap();
+
+        let first = batches.next().await.unwrap().unwrap();
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        // Without back-pressure the cursor would have fetched every batch and
+        // committed while the reader was paused.
+        let paused = diesel::select(diesel::dsl::exists(
+            pg_stat_activity::table
+                .filter(pg_stat_activity::state.eq("idle in transaction"))
+                .filter(pg_stat_activity::query.like("FETCH FORWARD % FROM user_export")),
+        ))
+        .get_result::<bool>(&mut pool.get().unwrap())
+        .unwrap();
+
+        let rest: Vec<_> = batches.map(Result::unwrap).collect().await;
+
+        assert!(paused, "the cursor ran ahead of the reader");
+        assert_eq!(rest.len(), BATCHES - 1);
+        assert!(rest.iter().all(|batch| batch.len() == BATCH_SIZE));
+        assert_eq!(first[0].name, "Export 0");
+        assert_eq!(first[0].created_at, Some(seeded.created_at));
     }
 }
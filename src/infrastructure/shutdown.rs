@@ -0,0 +1,161 @@
+use std::{sync::Arc, time::Duration};
+
+use actix_web::dev::ServerHandle;
+use tracing::{info, warn};
+
+use super::{db::connection::DBPool, health::readiness::Readiness};
+
+const POOL_DRAIN_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShutdownSignal {
+    Interrupt,
+    Terminate,
+}
+
+#[cfg(not(tarpaulin_include))]
+pub async fn shutdown_signal() -> ShutdownSignal {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let mut terminate = signal(SignalKind::terminate()).expect("Failed to listen for SIGTERM");
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => ShutdownSignal::Interrupt,
+            _ = terminate.recv() => ShutdownSignal::Terminate,
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+
+        ShutdownSignal::Interrupt
+    }
+}
+
+pub async fn shutdown_gracefully(
+    signal: impl Future<Output = ShutdownSignal>,
+    server: ServerHandle,
+    readiness: Arc<Readiness>,
+    readiness_delay: Duration,
+) {
+    let signal = signal.await;
+
+    info!(?signal, "Shutting down, no longer ready");
+    readiness.mark_shutting_down();
+
+    if signal == ShutdownSignal::Terminate && !readiness_delay.is_zero() {
+        info!(
+            delay_ms = readiness_delay.as_millis() as u64,
+            "Waiting for load balancers to observe the readiness change"
+        );
+        tokio::time::sleep(readiness_delay).await;
+    }
+
+    info!("Stopping the server and draining in-flight requests");
+    server.stop(true).await;
+}
+
+pub async fn close_pool(pool: DBPool, timeout: Duration) {
+    let drained = tokio::time::timeout(timeout, async {
+        loop {
+            let state = pool.state();
+            if state.connections == state.idle_connections {
+                return state;
+            }
+
+            tokio::time::sleep(POOL_DRAIN_INTERVAL).await;
+        }
+    })
+    .await;
+
+    match drained {
+        Ok(state) => info!(connections = state.connections, "Closing the database pool"),
+        Err(_) => warn!(
+            in_use = pool.state().connections - pool.state().idle_connections,
+            "Closing the database pool with connections still in use"
+        ),
+    }
+
+    drop(pool);
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+        sync::Arc,
+        thread,
+        time::Duration,
+    };
+
+    use actix_web::{App, HttpResponse, HttpServer, web};
+    use tokio::sync::oneshot;
+
+    use crate::infrastructure::health::readiness::Readiness;
+
+    use super::{ShutdownSignal, shutdown_gracefully};
+
+    async fn slow() -> HttpResponse {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        HttpResponse::Ok().body("finished")
+    }
+
+    fn get(addr: std::net::SocketAddr, path: &str) -> std::io::Result<String> {
+        let mut stream = TcpStream::connect(addr)?;
+
+        write!(
+            stream,
+            "GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n"
+        )?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+
+        Ok(response)
+    }
+
+    #[actix_web::test]
+    async fn in_flight_requests_complete_before_the_server_stops() {
+        let readiness = Arc::new(Readiness::new(vec![], Duration::from_secs(1)));
+
+        let server = HttpServer::new(|| App::new().route("/slow", web::get().to(slow)))
+            .workers(1)
+            .disable_signals()
+            .shutdown_timeout(5)
+            .bind("127.0.0.1:0")
+            .unwrap();
+        let addr = server.addrs()[0];
+        let server = server.run();
+
+        let (signal_tx, signal_rx) = oneshot::channel();
+        let shutdown = actix_web::rt::spawn(shutdown_gracefully(
+            async move { signal_rx.await.unwrap_or(ShutdownSignal::Interrupt) },
+            server.handle(),
+            readiness.clone(),
+            Duration::from_millis(200),
+        ));
+        let server = actix_web::rt::spawn(server);
+
+        let in_flight = thread::spawn(move || get(addr, "/slow"));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        signal_tx.send(ShutdownSignal::Terminate).unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert!(readiness.is_shutting_down());
+
+        shutdown.await.unwrap();
+        server.await.unwrap().unwrap();
+
+        let response = in_flight.join().unwrap().unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("finished"));
+        assert!(get(addr, "/slow").is_err());
+    }
+}
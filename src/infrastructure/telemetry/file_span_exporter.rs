@@ -0,0 +1,82 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use opentelemetry::trace::Status;
+use opentelemetry_sdk::{
+    error::{OTelSdkError, OTelSdkResult},
+    trace::{SpanData, SpanExporter},
+};
+use serde_json::{Map, Value, json};
+
+#[derive(Debug)]
+pub struct FileSpanExporter {
+    file: Mutex<File>,
+}
+
+impl FileSpanExporter {
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl SpanExporter for FileSpanExporter {
+    async fn export(&self, batch: Vec<SpanData>) -> OTelSdkResult {
+        let mut lines = vec![];
+
+        for span in &batch {
+            serde_json::to_writer(&mut lines, &to_json(span))
+                .map_err(|err| OTelSdkError::InternalFailure(err.to_string()))?;
+            lines.push(b'\n');
+        }
+
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|err| OTelSdkError::InternalFailure(err.to_string()))?;
+
+        file.write_all(&lines)
+            .and_then(|()| file.flush())
+            .map_err(|err| OTelSdkError::InternalFailure(err.to_string()))
+    }
+}
+
+fn to_json(span: &SpanData) -> Value {
+    let attributes: Map<String, Value> = span
+        .attributes
+        .iter()
+        .map(|kv| (kv.key.to_string(), Value::String(kv.value.to_string())))
+        .collect();
+
+    let status = match &span.status {
+        Status::Unset => "unset",
+        Status::Ok => "ok",
+        Status::Error { .. } => "error",
+    };
+
+    json!({
+        "trace_id": span.span_context.trace_id().to_string(),
+        "span_id": span.span_context.span_id().to_string(),
+        "parent_span_id": span.parent_span_id.to_string(),
+        "name": span.name,
+        "kind": format!("{:?}", span.span_kind),
+        "start_time_unix_nano": unix_nanos(span.start_time),
+        "end_time_unix_nano": unix_nanos(span.end_time),
+        "status": status,
+        "attributes": attributes,
+    })
+}
+
+fn unix_nanos(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default()
+}
@@ -0,0 +1,2 @@
+pub mod file_span_exporter;
+pub mod tracer;
@@ -0,0 +1,131 @@
+use opentelemetry::{global, trace::TracerProvider};
+use opentelemetry_sdk::{
+    Resource,
+    propagation::TraceContextPropagator,
+    trace::{SdkTracerProvider, TracerProviderBuilder},
+};
+use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+
+use super::file_span_exporter::FileSpanExporter;
+
+const SERVICE_NAME: &str = "user-service";
+
+pub struct TracerGuard {
+    provider: SdkTracerProvider,
+}
+
+impl Drop for TracerGuard {
+    fn drop(&mut self) {
+        if let Err(err) = self.provider.shutdown() {
+            eprintln!("Failed to flush traces: {err}");
+        }
+    }
+}
+
+fn with_exporters(mut builder: TracerProviderBuilder) -> TracerProviderBuilder {
+    if std::env::var_os("OTEL_EXPORTER_OTLP_ENDPOINT").is_some() {
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .build()
+            .expect("Failed to configure the OTLP exporter");
+
+        builder = builder.with_batch_exporter(exporter);
+    }
+
+    if let Ok(path) = std::env::var("OTEL_TRACES_FILE") {
+        let exporter =
+            FileSpanExporter::create(&path).expect("Failed to open the OTEL_TRACES_FILE");
+
+        builder = builder.with_simple_exporter(exporter);
+    }
+
+    builder
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn init() -> TracerGuard {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let provider = with_exporters(SdkTracerProvider::builder())
+        .with_resource(Resource::builder().with_service_name(SERVICE_NAME).build())
+        .build();
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with(fmt::layer().json().flatten_event(true))
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer(SERVICE_NAME)))
+        .init();
+
+    global::set_tracer_provider(provider.clone());
+
+    TracerGuard { provider }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use opentelemetry::{
+        propagation::TextMapPropagator,
+        trace::{TraceContextExt, TracerProvider},
+    };
+    use opentelemetry_sdk::{propagation::TraceContextPropagator, trace::SdkTracerProvider};
+    use serde_json::Value;
+    use tracing::{Instrument, info_span};
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use crate::infrastructure::telemetry::file_span_exporter::FileSpanExporter;
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+
+    #[tokio::test]
+    async fn spans_continue_the_incoming_trace() {
+        let path = std::env::temp_dir().join(format!("spans-{}.ndjson", std::process::id()));
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(FileSpanExporter::create(&path).unwrap())
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        let headers = HashMap::from([(
+            "traceparent".to_string(),
+            format!("00-{TRACE_ID}-00f067aa0ba902b7-01"),
+        )]);
+        let parent = TraceContextPropagator::new().extract(&headers);
+
+        let _default = tracing::subscriber::set_default(subscriber);
+
+        let request = info_span!("http_request");
+        request.set_parent(parent).unwrap();
+
+        let trace_id = request.context().span().span_context().trace_id();
+
+        async { info_span!("use_case").in_scope(|| info_span!("repository").in_scope(|| {})) }
+            .instrument(request)
+            .await;
+
+        provider.shutdown().unwrap();
+
+        let spans: HashMap<String, Value> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .map(|span| (span["name"].as_str().unwrap().to_string(), span))
+            .collect();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(trace_id.to_string(), TRACE_ID);
+        assert!(spans.values().all(|span| span["trace_id"] == TRACE_ID));
+        assert_eq!(spans["http_request"]["parent_span_id"], "00f067aa0ba902b7");
+        assert_eq!(
+            spans["use_case"]["parent_span_id"],
+            spans["http_request"]["span_id"]
+        );
+        assert_eq!(
+            spans["repository"]["parent_span_id"],
+            spans["use_case"]["span_id"]
+        );
+    }
+}
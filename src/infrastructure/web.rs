@@ -1,24 +1,181 @@
-use crate::presentation::routes;
+use crate::{
+    domain::services::pii_redactor,
+    presentation::{
+        graphql::schema::build_schema,
+        grpc::user_service::UserGrpcService,
+        middlewares::{
+            metrics_middleware::metrics_middleware, request_id_middleware::request_id_middleware,
+            tracing_middleware::tracing_middleware,
+        },
+        routes,
+    },
+};
 
-use super::repositories::postgres_user_repository::PostgresUserRepository;
-use actix_web::{App, HttpServer, middleware::Logger, web};
-use log::info;
+use super::{
+    auth::jwt_validator::JwtValidator,
+    bootstrap::{env_or, init_pii, user_repository},
+    db::{
+        connection::establish_connection,
+        migrations::{run_pending_migrations, with_migration_lock},
+    },
+    health::{
+        database_check::DatabaseCheck, downstream_check::DownstreamCheck,
+        health_check::HealthCheck, migrations_check::MigrationsCheck, readiness::Readiness,
+    },
+    notifications::smtp_email_notifier::SmtpEmailNotifier,
+    repositories::{
+        postgres_api_key_repository::PostgresApiKeyRepository,
+        postgres_email_change_repository::PostgresEmailChangeRepository,
+        postgres_user_audit_repository::PostgresUserAuditRepository,
+    },
+    shutdown::{close_pool, shutdown_gracefully, shutdown_signal},
+};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use actix_web::{
+    App, HttpServer,
+    dev::ServiceRequest,
+    middleware::{Logger, from_fn},
+    web,
+};
+use tokio::{net::TcpListener, sync::oneshot};
+use tokio_stream::wrappers::TcpListenerStream;
+use tracing::info;
+
+const LOG_FORMAT: &str =
+    r#"%a "%{request_line}xi" %s %b "%{Referer}i" "%{User-Agent}i" %T %{X-Request-Id}o"#;
+
+fn redacted_request_line(req: &ServiceRequest) -> String {
+    pii_redactor::redact_text(&format!(
+        "{} {} {:?}",
+        req.method(),
+        req.uri(),
+        req.version()
+    ))
+}
 
 #[cfg(not(tarpaulin_include))]
 pub async fn run() -> std::io::Result<()> {
-    let repo = PostgresUserRepository::new();
-    let app_data = web::Data::new(repo);
+    init_pii();
+
+    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL is missing");
+    let pool = establish_connection(&database_url);
+
+    if env_or("MIGRATE_ON_STARTUP", false) {
+        let mut conn = pool
+            .get()
+            .expect("Failed to get a connection for migrations");
+        let applied = with_migration_lock(&mut conn, run_pending_migrations)
+            .expect("Failed to run the pending migrations");
+
+        info!(?applied, "Applied pending migrations");
+    }
+
+    let user_audit_repo = web::Data::new(PostgresUserAuditRepository::new(pool.clone()));
+    let user_repo = web::Data::new(user_repository(pool.clone()));
+    let graphql_schema = web::Data::new(build_schema(user_repo.clone().into_inner()));
+    let email_change_repo = web::Data::new(PostgresEmailChangeRepository::new(pool.clone()));
+    let api_key_repo = web::Data::new(PostgresApiKeyRepository::new(pool.clone()));
+
+    let health_check_timeout = Duration::from_millis(env_or("HEALTH_CHECK_TIMEOUT_MS", 2_000));
+    let mut health_checks: Vec<Arc<dyn HealthCheck>> = vec![
+        Arc::new(DatabaseCheck::new(pool.clone(), health_check_timeout)),
+        Arc::new(MigrationsCheck::new(pool.clone(), health_check_timeout)),
+    ];
+    health_checks.extend(
+        DownstreamCheck::parse_all(
+            &std::env::var("HEALTH_CHECK_DOWNSTREAMS").unwrap_or_default(),
+            health_check_timeout,
+        )
+        .expect("Failed to configure the downstream health checks")
+        .into_iter()
+        .map(|check| Arc::new(check) as Arc<dyn HealthCheck>),
+    );
+    let readiness = web::Data::new(Readiness::new(health_checks, health_check_timeout));
+
+    let shutdown_timeout = Duration::from_secs(env_or("SHUTDOWN_TIMEOUT_SECS", 30));
+    let readiness_delay = Duration::from_secs(env_or("SHUTDOWN_READINESS_DELAY_SECS", 5));
+
+    let drained_pool = pool.clone();
+    let pool = web::Data::new(pool);
+    let email_notifier =
+        web::Data::new(SmtpEmailNotifier::from_env().expect("Failed to configure email delivery"));
+
+    let jwt_validator = JwtValidator::from_env().expect("Failed to configure JWT validation");
+    let jwt_validator = web::Data::new(jwt_validator);
+
+    let grpc_addr = SocketAddr::from(([0, 0, 0, 0], env_or("GRPC_PORT", 50051)));
+    let grpc_listener = TcpListener::bind(grpc_addr).await?;
+    let grpc_service = UserGrpcService::new(
+        user_repo.clone().into_inner(),
+        api_key_repo.clone().into_inner(),
+        jwt_validator.clone().into_inner(),
+    );
 
     info!("Starting...");
 
-    HttpServer::new(move || {
+    let shutdown_readiness = readiness.clone().into_inner();
+
+    let server = HttpServer::new(move || {
         App::new()
-            .app_data(app_data.clone())
-            .wrap(Logger::default())
+            .app_data(user_repo.clone())
+            .app_data(user_audit_repo.clone())
+            .app_data(graphql_schema.clone())
+            .app_data(email_change_repo.clone())
+            .app_data(email_notifier.clone())
+            .app_data(api_key_repo.clone())
+            .app_data(jwt_validator.clone())
+            .app_data(pool.clone())
+            .app_data(readiness.clone())
+            .wrap(from_fn(request_id_middleware))
+            .wrap(from_fn(metrics_middleware))
+            .wrap(from_fn(tracing_middleware))
+            .wrap(
+                Logger::new(LOG_FORMAT)
+                    .custom_request_replace("request_line", redacted_request_line),
+            )
             .configure(routes::user_routes::routes)
+            .configure(routes::api_key_routes::routes)
+            .configure(routes::graphql_routes::routes)
+            .configure(routes::metrics_routes::routes)
+            .configure(routes::health_routes::routes)
+            .configure(routes::openapi_routes::routes)
     })
     .bind("0.0.0.0:4000")
     .unwrap()
-    .run()
-    .await
+    .disable_signals()
+    .shutdown_timeout(shutdown_timeout.as_secs())
+    .run();
+
+    actix_web::rt::spawn(shutdown_gracefully(
+        shutdown_signal(),
+        server.handle(),
+        shutdown_readiness,
+        readiness_delay,
+    ));
+
+    let (stop_grpc, grpc_stopped) = oneshot::channel::<()>();
+    let grpc_server = actix_web::rt::spawn(
+        tonic::transport::Server::builder()
+            .add_service(grpc_service.into_server())
+            .serve_with_incoming_shutdown(TcpListenerStream::new(grpc_listener), async {
+                grpc_stopped.await.ok();
+            }),
+    );
+
+    info!(%grpc_addr, "Serving gRPC");
+
+    server.await?;
+
+    let _ = stop_grpc.send(());
+    grpc_server
+        .await
+        .map_err(std::io::Error::other)?
+        .map_err(std::io::Error::other)?;
+
+    close_pool(drained_pool, shutdown_timeout).await;
+
+    info!("Stopped");
+
+    Ok(())
 }
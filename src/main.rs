@@ -1,17 +1,85 @@
 use actix_web::main;
+use clap::{Parser, Subcommand};
+use diesel::{Connection, PgConnection};
 use dotenv::dotenv;
-use env_logger::Env;
-use infrastructure::web::run;
+use first_ddd_project_with_rust::infrastructure::{
+    db::migrations::{
+        migration_status, redo_last_migration, revert_last_migration, run_pending_migrations,
+        with_migration_lock,
+    },
+    telemetry::tracer,
+    web::run,
+};
 
-pub mod application;
-pub mod domain;
-pub mod infrastructure;
-pub mod presentation;
-pub mod schema;
+#[derive(Parser)]
+#[command(version, about = "User management service")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    #[command(about = "Run the HTTP server (the default)")]
+    Serve,
+    #[command(about = "Manage the embedded database migrations")]
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum MigrateAction {
+    #[command(about = "Apply every pending migration")]
+    Up,
+    #[command(about = "Revert the most recently applied migration")]
+    Down,
+    #[command(about = "List the migrations and whether they are applied")]
+    Status,
+    #[command(about = "Revert and re-apply the most recently applied migration")]
+    Redo,
+}
+
+#[cfg(not(tarpaulin_include))]
+fn migrate(action: MigrateAction) -> Result<(), String> {
+    let database_url = std::env::var("DATABASE_URL").map_err(|_| "DATABASE_URL is missing")?;
+    let mut conn = PgConnection::establish(&database_url).map_err(|err| err.to_string())?;
+
+    with_migration_lock(&mut conn, |conn| {
+        match action {
+            MigrateAction::Up => {
+                let applied = run_pending_migrations(conn)?;
+                if applied.is_empty() {
+                    println!("No pending migrations");
+                }
+                for version in applied {
+                    println!("Applied {version}");
+                }
+            }
+            MigrateAction::Down => println!("Reverted {}", revert_last_migration(conn)?),
+            MigrateAction::Redo => println!("Redid {}", redo_last_migration(conn)?),
+            MigrateAction::Status => {
+                for migration in migration_status(conn)? {
+                    let mark = if migration.applied { "X" } else { " " };
+                    println!("[{mark}] {}", migration.name);
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
 
 #[main]
 async fn main() -> std::io::Result<()> {
     dotenv().ok();
-    env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
-    run().await
+
+    match Cli::parse().command.unwrap_or(Command::Serve) {
+        Command::Serve => {
+            let _tracer = tracer::init();
+            run().await
+        }
+        Command::Migrate { action } => migrate(action).map_err(std::io::Error::other),
+    }
 }
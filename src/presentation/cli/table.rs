@@ -0,0 +1,166 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::presentation::dtos::{
+    user_dto::{LoadedUserDTO, UserAuditEntryDTO, UserSearchHitDTO},
+    user_import_dto::{ImportRowDTO, ImportRowStatusDTO},
+};
+
+pub trait Tabular {
+    fn headers() -> &'static [&'static str];
+    fn row(&self) -> Vec<String>;
+}
+
+fn timestamp(at: Option<DateTime<Utc>>) -> String {
+    at.map(|at| at.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .unwrap_or_default()
+}
+
+impl Tabular for LoadedUserDTO {
+    fn headers() -> &'static [&'static str] {
+        &[
+            "ID",
+            "NAME",
+            "EMAIL",
+            "PHONE",
+            "ADDRESS",
+            "CREATED AT",
+            "UPDATED AT",
+            "SUSPENDED AT",
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.name.clone(),
+            self.email.clone(),
+            self.phone.clone(),
+            self.address.clone(),
+            timestamp(self.created_at),
+            timestamp(self.updated_at),
+            timestamp(self.suspended_at),
+        ]
+    }
+}
+
+impl Tabular for UserSearchHitDTO {
+    fn headers() -> &'static [&'static str] {
+        &["ID", "NAME", "EMAIL", "SCORE"]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.user.id.to_string(),
+            self.user.name.clone(),
+            self.user.email.clone(),
+            format!("{:.3}", self.score),
+        ]
+    }
+}
+
+impl Tabular for UserAuditEntryDTO {
+    fn headers() -> &'static [&'static str] {
+        &["RECORDED AT", "ACTOR", "ACTION", "REQUEST ID", "CHANGES"]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            timestamp(Some(self.recorded_at)),
+            self.actor.clone(),
+            self.action.clone(),
+            self.request_id.clone().unwrap_or_default(),
+            self.changes.to_string(),
+        ]
+    }
+}
+
+impl Tabular for ImportRowDTO {
+    fn headers() -> &'static [&'static str] {
+        &["ROW", "STATUS", "DETAIL"]
+    }
+
+    fn row(&self) -> Vec<String> {
+        let (status, detail) = match &self.status {
+            ImportRowStatusDTO::Created { id } => ("created", format!("id {id}")),
+            ImportRowStatusDTO::Valid => ("valid", String::new()),
+            ImportRowStatusDTO::Duplicate { reason } => ("duplicate", reason.clone()),
+            ImportRowStatusDTO::Invalid { reasons } => ("invalid", reasons.join("; ")),
+        };
+
+        vec![self.row.to_string(), status.to_string(), detail]
+    }
+}
+
+pub fn render<T: Tabular>(items: &[T]) -> String {
+    let headers = T::headers();
+    let rows: Vec<Vec<String>> = items.iter().map(Tabular::row).collect();
+
+    let widths: Vec<usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(column, header)| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .chain([header.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let line = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![line(headers.to_vec())];
+    lines.extend(
+        rows.iter()
+            .map(|row| line(row.iter().map(String::as_str).collect())),
+    );
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::presentation::dtos::user_import_dto::{ImportRowDTO, ImportRowStatusDTO};
+
+    use super::render;
+
+    #[test]
+    fn render_aligns_columns() {
+        let rows = vec![
+            ImportRowDTO {
+                row: 1,
+                status: ImportRowStatusDTO::Created { id: 42 },
+            },
+            ImportRowDTO {
+                row: 10,
+                status: ImportRowStatusDTO::Invalid {
+                    reasons: vec![
+                        "name must not be empty".to_string(),
+                        "email is invalid".to_string(),
+                    ],
+                },
+            },
+        ];
+
+        assert_eq!(
+            render(&rows),
+            "ROW  STATUS   DETAIL\n\
+             1    created  id 42\n\
+             10   invalid  name must not be empty; email is invalid"
+        );
+    }
+
+    #[test]
+    fn render_headers_only_when_empty() {
+        assert_eq!(render::<ImportRowDTO>(&[]), "ROW  STATUS  DETAIL");
+    }
+}
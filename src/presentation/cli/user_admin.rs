@@ -0,0 +1,564 @@
+use std::{fs, io::Write, path::PathBuf, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use futures_util::{StreamExt, stream};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    application::{
+        auth::{
+            authenticated_principal::AuthenticatedPrincipal, authorization::SCOPES,
+            request_context::RequestContext,
+        },
+        use_cases::{
+            erase_user::EraseUserUseCase, export_users::ExportUsersUseCase,
+            find_user_by_email::FindUserByEmailUseCase, find_user_by_id::FindUserByIdUseCase,
+            get_user_history::GetUserHistoryUseCase, import_users::ImportUsersUseCase,
+            list_users::ListUsersUseCase, merge_users::MergeUsersUseCase,
+            register_user::RegisterUserUseCase, search_users::SearchUsersUseCase,
+            suspend_user::SuspendUserUseCase, update_user::UpdateUserUseCase,
+        },
+    },
+    domain::entities::user::User,
+    infrastructure::{
+        bootstrap::{init_pii, user_repository},
+        db::{
+            connection::{DBPool, establish_connection},
+            pii_backfill::backfill_user_pii,
+        },
+        repositories::{
+            caching_user_repository::CachedPostgresUserRepository,
+            postgres_user_audit_repository::PostgresUserAuditRepository,
+        },
+    },
+    presentation::{
+        cli::table::{Tabular, render},
+        dtos::{
+            user_dto::{
+                CreateUserDTO, LoadedUserDTO, UpdateUserDTO, UserAuditEntryDTO, UserSearchHitDTO,
+            },
+            user_export_dto::{ExportFormat, UserExportEncoder, encode_export},
+            user_import_dto::{ImportRows, UserImportReportDTO, parse_csv, parse_ndjson},
+        },
+    },
+};
+
+#[derive(Parser)]
+#[command(
+    name = "user-admin",
+    version,
+    about = "Administer users directly against the configured repository"
+)]
+pub struct UserAdminCli {
+    #[arg(long, short, value_enum, global = true, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+    #[arg(
+        long,
+        global = true,
+        env = "USER_ADMIN_ACTOR",
+        help = "Recorded as the actor in the audit history (defaults to cli:$USER)"
+    )]
+    pub actor: Option<String>,
+    #[command(subcommand)]
+    pub command: UserAdminCommand,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, ValueEnum)]
+pub enum ImportFormat {
+    Csv,
+    Ndjson,
+}
+
+#[derive(Subcommand)]
+pub enum UserAdminCommand {
+    #[command(about = "Register a new user")]
+    Register {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        email: String,
+        #[arg(long)]
+        phone: String,
+        #[arg(long)]
+        address: String,
+    },
+    #[command(about = "Look up a user by id or email")]
+    Get {
+        #[arg(required_unless_present = "email", conflicts_with = "email")]
+        id: Option<i32>,
+        #[arg(long)]
+        email: Option<String>,
+    },
+    #[command(about = "List users, optionally filtered by creation date")]
+    List {
+        #[arg(long)]
+        created_from: Option<DateTime<Utc>>,
+        #[arg(long)]
+        created_to: Option<DateTime<Utc>>,
+        #[arg(long)]
+        limit: Option<i64>,
+    },
+    #[command(about = "Search users by name or email")]
+    Search {
+        query: String,
+        #[arg(long)]
+        limit: Option<i64>,
+    },
+    #[command(about = "Update the name, phone or address of a user")]
+    Update {
+        id: i32,
+        #[arg(long, required_unless_present_any = ["phone", "address"])]
+        name: Option<String>,
+        #[arg(long)]
+        phone: Option<String>,
+        #[arg(long)]
+        address: Option<String>,
+    },
+    #[command(about = "Suspend a user, or lift their suspension")]
+    Suspend {
+        id: i32,
+        #[arg(long, help = "Reinstate the user instead")]
+        lift: bool,
+    },
+    #[command(about = "Show the audit history of a user")]
+    History { id: i32 },
+    #[command(about = "Merge a duplicate user into a surviving one")]
+    Merge { survivor_id: i32, duplicate_id: i32 },
+    #[command(alias = "erase", about = "Erase the personal data of a user")]
+    Delete {
+        id: i32,
+        #[arg(long, help = "Confirm the erasure, which cannot be undone")]
+        yes: bool,
+    },
+    #[command(about = "Import users from a CSV or NDJSON file")]
+    Import {
+        file: PathBuf,
+        #[arg(long, value_enum, help = "Defaults to the file extension")]
+        format: Option<ImportFormat>,
+        #[arg(long)]
+        dry_run: bool,
+    },
+    #[command(about = "Export users to a CSV, NDJSON or Parquet file")]
+    Export {
+        file: PathBuf,
+        #[arg(long, value_enum, help = "Defaults to the file extension")]
+        format: Option<ExportFormat>,
+        #[arg(long)]
+        created_from: Option<DateTime<Utc>>,
+        #[arg(long)]
+        created_to: Option<DateTime<Utc>>,
+    },
+    #[command(
+        about = "Encrypt plaintext PII with the active key and backfill the email blind index"
+    )]
+    Reencrypt {
+        #[arg(long, default_value_t = 500)]
+        batch_size: i64,
+    },
+}
+
+fn file_format<T: ValueEnum>(file: &std::path::Path, format: Option<T>) -> Result<T, String> {
+    if let Some(format) = format {
+        return Ok(format);
+    }
+
+    file.extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(|extension| T::from_str(extension, true).ok())
+        .ok_or_else(|| {
+            format!(
+                "Cannot infer the format of {}, pass --format",
+                file.display()
+            )
+        })
+}
+
+fn operator(actor: &str) -> AuthenticatedPrincipal {
+    AuthenticatedPrincipal::new(actor.to_string(), None, vec!["admin".to_string()])
+        .with_scopes(SCOPES.iter().map(ToString::to_string).collect())
+}
+
+fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<(), String> {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).map_err(|err| err.to_string())?
+    );
+
+    Ok(())
+}
+
+fn print_all<T: Serialize + Tabular>(output: OutputFormat, items: &[T]) -> Result<(), String> {
+    match output {
+        OutputFormat::Table => {
+            println!("{}", render(items));
+            Ok(())
+        }
+        OutputFormat::Json => print_json(items),
+    }
+}
+
+fn print_one<T: Serialize + Tabular>(output: OutputFormat, item: T) -> Result<(), String> {
+    match output {
+        OutputFormat::Table => print_all(output, &[item]),
+        OutputFormat::Json => print_json(&item),
+    }
+}
+
+fn print_status(output: OutputFormat, message: String, value: serde_json::Value) {
+    match output {
+        OutputFormat::Table => println!("{message}"),
+        OutputFormat::Json => println!("{value:#}"),
+    }
+}
+
+fn loaded(user: User) -> Result<LoadedUserDTO, String> {
+    Option::<LoadedUserDTO>::from(user).ok_or_else(|| "The user has no id".to_string())
+}
+
+struct Repositories {
+    pool: DBPool,
+    users: Arc<CachedPostgresUserRepository>,
+    audit: Arc<PostgresUserAuditRepository>,
+}
+
+async fn execute(
+    command: UserAdminCommand,
+    output: OutputFormat,
+    principal: &AuthenticatedPrincipal,
+    repos: Repositories,
+) -> Result<(), String> {
+    match command {
+        UserAdminCommand::Register {
+            name,
+            email,
+            phone,
+            address,
+        } => {
+            let input = CreateUserDTO {
+                name,
+                email,
+                phone,
+                address,
+            };
+            let id = RegisterUserUseCase::new(repos.users)
+                .execute(principal, input)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            print_status(output, format!("Registered user {id}"), json!({ "id": id }));
+        }
+        UserAdminCommand::Get { id, email } => {
+            let user = match (id, email) {
+                (Some(id), _) => FindUserByIdUseCase::new(repos.users)
+                    .execute(principal, id)
+                    .await
+                    .map_err(|err| err.to_string())?,
+                (None, Some(email)) => FindUserByEmailUseCase::new(repos.users)
+                    .execute(principal, email)
+                    .await
+                    .map_err(|err| err.to_string())?,
+                (None, None) => None,
+            };
+
+            print_one(output, loaded(user.ok_or("User not found")?)?)?;
+        }
+        UserAdminCommand::List {
+            created_from,
+            created_to,
+            limit,
+        } => {
+            let users = ListUsersUseCase::new(repos.users)
+                .execute(principal, created_from, created_to, None, limit)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            print_all(
+                output,
+                &users
+                    .into_iter()
+                    .filter_map(Option::<LoadedUserDTO>::from)
+                    .collect::<Vec<_>>(),
+            )?;
+        }
+        UserAdminCommand::Search { query, limit } => {
+            let hits = SearchUsersUseCase::new(repos.users)
+                .execute(principal, &query, limit)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            print_all(
+                output,
+                &hits
+                    .into_iter()
+                    .filter_map(Option::<UserSearchHitDTO>::from)
+                    .collect::<Vec<_>>(),
+            )?;
+        }
+        UserAdminCommand::Update {
+            id,
+            name,
+            phone,
+            address,
+        } => {
+            let changes = UpdateUserDTO {
+                name,
+                phone,
+                address,
+            };
+            let updated = UpdateUserUseCase::new(repos.users)
+                .execute(principal, id, changes)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            print_one(output, loaded(updated)?)?;
+        }
+        UserAdminCommand::Suspend { id, lift } => {
+            let user = SuspendUserUseCase::new(repos.users)
+                .execute(principal, id, !lift)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            print_one(output, loaded(user)?)?;
+        }
+        UserAdminCommand::History { id } => {
+            let history = GetUserHistoryUseCase::new(repos.audit)
+                .execute(principal, id)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            print_all(
+                output,
+                &history
+                    .into_iter()
+                    .map(UserAuditEntryDTO::from)
+                    .collect::<Vec<_>>(),
+            )?;
+        }
+        UserAdminCommand::Merge {
+            survivor_id,
+            duplicate_id,
+        } => {
+            let merged = MergeUsersUseCase::new(repos.users)
+                .execute(principal, survivor_id, duplicate_id)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            print_one(output, loaded(merged)?)?;
+        }
+        UserAdminCommand::Delete { id, yes } => {
+            if !yes {
+                return Err(format!(
+                    "Erasing user {id} cannot be undone, pass --yes to confirm"
+                ));
+            }
+
+            EraseUserUseCase::new(repos.users)
+                .execute(principal, id)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            print_status(output, format!("Erased user {id}"), json!({ "erased": id }));
+        }
+        UserAdminCommand::Import {
+            file,
+            format,
+            dry_run,
+        } => {
+            let parse: fn(fs::File) -> ImportRows = match file_format(&file, format)? {
+                ImportFormat::Csv => parse_csv,
+                ImportFormat::Ndjson => parse_ndjson,
+            };
+            let input =
+                fs::File::open(&file).map_err(|err| format!("{}: {err}", file.display()))?;
+
+            let results = ImportUsersUseCase::new(repos.users)
+                .execute(principal, stream::iter(parse(input)), dry_run)
+                .await
+                .map_err(|err| err.to_string())?;
+            let report = UserImportReportDTO::new(dry_run, results);
+
+            match output {
+                OutputFormat::Table => {
+                    print_all(output, &report.rows)?;
+                    println!(
+                        "\ncreated: {}, valid: {}, duplicate: {}, invalid: {}{}",
+                        report.created,
+                        report.valid,
+                        report.duplicate,
+                        report.invalid,
+                        if dry_run { " (dry run)" } else { "" }
+                    );
+                }
+                OutputFormat::Json => print_json(&report)?,
+            }
+        }
+        UserAdminCommand::Export {
+            file,
+            format,
+            created_from,
+            created_to,
+        } => {
+            let encoder = UserExportEncoder::new(file_format(&file, format)?)
+                .map_err(|err| err.to_string())?;
+
+            let batches = ExportUsersUseCase::new(repos.users)
+                .execute(principal, created_from, created_to)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            let mut writer =
+                fs::File::create(&file).map_err(|err| format!("{}: {err}", file.display()))?;
+            let mut written = 0;
+            let mut chunks = Box::pin(encode_export(batches, encoder));
+
+            while let Some(chunk) = chunks.next().await {
+                let chunk = chunk.map_err(|err| err.to_string())?;
+                writer
+                    .write_all(&chunk)
+                    .map_err(|err| format!("{}: {err}", file.display()))?;
+                written += chunk.len();
+            }
+
+            print_status(
+                output,
+                format!("Exported {written} bytes to {}", file.display()),
+                json!({ "file": file, "bytes": written }),
+            );
+        }
+        UserAdminCommand::Reencrypt { batch_size } => {
+            let mut conn = repos.pool.get().map_err(|err| err.to_string())?;
+            let report = backfill_user_pii(&mut conn, batch_size)?;
+
+            print_status(
+                output,
+                format!(
+                    "Scanned {} users, rewrote {}",
+                    report.scanned, report.rewritten
+                ),
+                json!(report),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(tarpaulin_include))]
+pub async fn run(cli: UserAdminCli) -> Result<(), String> {
+    init_pii();
+
+    let database_url = std::env::var("DATABASE_URL").map_err(|_| "DATABASE_URL is missing")?;
+    let pool = establish_connection(&database_url);
+
+    let audit = Arc::new(PostgresUserAuditRepository::new(pool.clone()));
+    let users = Arc::new(user_repository(pool.clone()));
+
+    let actor = cli.actor.unwrap_or_else(|| {
+        format!(
+            "cli:{}",
+            std::env::var("USER").unwrap_or_else(|_| "unknown".to_string())
+        )
+    });
+    let request_id = format!("cli-{}", hex::encode(rand::random::<[u8; 8]>()));
+
+    RequestContext::new(actor.clone(), Some(request_id))
+        .scope(execute(
+            cli.command,
+            cli.output,
+            &operator(&actor),
+            Repositories { pool, users, audit },
+        ))
+        .await
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use clap::Parser;
+
+    use crate::presentation::dtos::user_export_dto::ExportFormat;
+
+    use super::{
+        ImportFormat, OutputFormat, UserAdminCli, UserAdminCommand, file_format, operator,
+    };
+
+    #[test]
+    fn file_format_from_the_extension_or_the_flag() {
+        assert_eq!(
+            file_format::<ImportFormat>(Path::new("users.NDJSON"), None),
+            Ok(ImportFormat::Ndjson)
+        );
+        assert_eq!(
+            file_format(Path::new("users.txt"), Some(ExportFormat::Parquet)),
+            Ok(ExportFormat::Parquet)
+        );
+        assert!(file_format::<ExportFormat>(Path::new("users"), None).is_err());
+    }
+
+    #[test]
+    fn operator_is_an_admin_with_every_scope() {
+        let principal = operator("cli:andrew");
+
+        assert_eq!(principal.subject, "cli:andrew");
+        assert_eq!(principal.roles, vec!["admin".to_string()]);
+        assert!(principal.has_scope("users:read"));
+        assert!(principal.has_scope("users:write"));
+    }
+
+    #[test]
+    fn parse_get_by_id_or_email() {
+        let cli = UserAdminCli::try_parse_from(["user-admin", "get", "42", "-o", "json"]).unwrap();
+
+        assert_eq!(cli.output, OutputFormat::Json);
+        assert!(matches!(
+            cli.command,
+            UserAdminCommand::Get {
+                id: Some(42),
+                email: None
+            }
+        ));
+
+        assert!(UserAdminCli::try_parse_from(["user-admin", "get"]).is_err());
+        assert!(
+            UserAdminCli::try_parse_from(["user-admin", "get", "42", "--email", "a@b.com"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_update_requires_a_change() {
+        let cli = UserAdminCli::try_parse_from(["user-admin", "update", "42", "--phone", "+1555"])
+            .unwrap();
+
+        assert!(matches!(
+            cli.command,
+            UserAdminCommand::Update {
+                id: 42,
+                name: None,
+                phone: Some(_),
+                address: None
+            }
+        ));
+
+        assert!(UserAdminCli::try_parse_from(["user-admin", "update", "42"]).is_err());
+    }
+
+    #[test]
+    fn parse_suspend_or_lift() {
+        let cli = UserAdminCli::try_parse_from(["user-admin", "suspend", "42", "--lift"]).unwrap();
+
+        assert!(matches!(
+            cli.command,
+            UserAdminCommand::Suspend { id: 42, lift: true }
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{entities::api_key::ApiKey, value_objects::id::ID};
+
+#[derive(Deserialize, Clone)]
+pub struct CreateApiKeyDTO {
+    pub name: String,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct LoadedApiKeyDTO {
+    pub id: i32,
+    pub name: String,
+    pub key_prefix: String,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct CreatedApiKeyDTO {
+    pub key: String,
+    #[serde(flatten)]
+    pub api_key: LoadedApiKeyDTO,
+}
+
+impl From<ApiKey> for Option<LoadedApiKeyDTO> {
+    fn from(value: ApiKey) -> Self {
+        match value.id {
+            ID::Existing(id) => Self::Some(LoadedApiKeyDTO {
+                id,
+                name: value.name,
+                key_prefix: value.key_prefix,
+                scopes: value.scopes,
+                created_at: value.created_at,
+                last_used_at: value.last_used_at,
+                revoked_at: value.revoked_at,
+            }),
+            ID::New => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use crate::domain::{entities::api_key::ApiKey, value_objects::id::ID};
+    use crate::presentation::dtos::api_key_dto::LoadedApiKeyDTO;
+
+    #[test]
+    fn from_api_key_into_optional_loaded_api_key_dto() {
+        let created_at = Utc::now();
+
+        let new_api_key = ApiKey::new(
+            "batch-jobs".to_string(),
+            "ak_0123abcd".to_string(),
+            "hash".to_string(),
+            vec!["users:read".to_string()],
+            created_at,
+        );
+
+        let loaded_api_key_dto: Option<LoadedApiKeyDTO> = new_api_key.clone().into();
+
+        assert!(loaded_api_key_dto.is_none());
+
+        let mut existing_api_key = new_api_key;
+        existing_api_key.id = ID::Existing(7);
+
+        let loaded_api_key_dto: Option<LoadedApiKeyDTO> = existing_api_key.into();
+
+        assert_eq!(
+            loaded_api_key_dto,
+            Some(LoadedApiKeyDTO {
+                id: 7,
+                name: "batch-jobs".to_string(),
+                key_prefix: "ak_0123abcd".to_string(),
+                scopes: vec!["users:read".to_string()],
+                created_at,
+                last_used_at: None,
+                revoked_at: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorDTO {
+    #[schema(example = "The user could not be found: 42")]
+    pub error: String,
+    #[schema(example = "3f2b8c0e9a1d4e7f8a6b5c4d3e2f1a0b")]
+    pub request_id: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProblemDTO {
+    #[serde(rename = "type")]
+    #[schema(example = "about:blank")]
+    pub problem_type: String,
+    #[schema(example = "Unauthorized")]
+    pub title: String,
+    #[schema(example = 401)]
+    pub status: u16,
+    pub detail: String,
+    pub request_id: String,
+}
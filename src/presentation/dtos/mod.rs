@@ -1 +1,5 @@
+pub mod api_key_dto;
+pub mod error_dto;
 pub mod user_dto;
+pub mod user_export_dto;
+pub mod user_import_dto;
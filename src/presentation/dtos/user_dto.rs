@@ -1,8 +1,22 @@
+use std::{collections::BTreeMap, fmt};
+
+use async_graphql::{InputObject, SimpleObject};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
 
-use crate::domain::{entities::user::User, value_objects::id::ID};
+use crate::{
+    application::use_cases::export_user_data::UserDataExport,
+    domain::{
+        entities::{user::User, user_audit_entry::UserAuditEntry, user_erasure::UserErasure},
+        services::pii_redactor::{PiiField, redact},
+        value_objects::{id::ID, user_search_hit::UserSearchHit},
+    },
+};
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, ToSchema, InputObject)]
+#[graphql(name = "RegisterUserInput")]
 pub struct CreateUserDTO {
     pub name: String,
     pub email: String,
@@ -10,13 +24,150 @@ pub struct CreateUserDTO {
     pub address: String,
 }
 
-#[derive(Serialize, PartialEq)]
+#[derive(Serialize, PartialEq, ToSchema, SimpleObject)]
+#[graphql(name = "User")]
 pub struct LoadedUserDTO {
     pub id: i32,
     pub name: String,
     pub email: String,
     pub phone: String,
     pub address: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub suspended_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Clone, Default, ToSchema, InputObject)]
+#[graphql(name = "UpdateUserInput")]
+pub struct UpdateUserDTO {
+    pub name: Option<String>,
+    pub phone: Option<String>,
+    pub address: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ChangeEmailDTO {
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MergeUsersDTO {
+    pub duplicate_id: i32,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ConfirmEmailChangeDTO {
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListUsersQuery {
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchUsersQuery {
+    pub q: String,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, PartialEq, ToSchema)]
+pub struct UserSearchHitDTO {
+    pub user: LoadedUserDTO,
+    pub score: f64,
+    pub highlights: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize, PartialEq, ToSchema)]
+pub struct UserErasureDTO {
+    pub requested_by: String,
+    pub erased_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, PartialEq, ToSchema)]
+pub struct UserAuditEntryDTO {
+    pub actor: String,
+    pub action: String,
+    pub request_id: Option<String>,
+    #[schema(value_type = Object)]
+    pub changes: Value,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, PartialEq, ToSchema)]
+pub struct UserDataExportDTO {
+    pub user: Option<LoadedUserDTO>,
+    pub erasures: Vec<UserErasureDTO>,
+    pub history: Vec<UserAuditEntryDTO>,
+    pub exported_at: DateTime<Utc>,
+}
+
+impl fmt::Debug for CreateUserDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateUserDTO")
+            .field("name", &self.name)
+            .field("email", &redact(PiiField::Email, &self.email))
+            .field("phone", &redact(PiiField::Phone, &self.phone))
+            .field("address", &redact(PiiField::Address, &self.address))
+            .finish()
+    }
+}
+
+impl fmt::Debug for UpdateUserDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UpdateUserDTO")
+            .field("name", &self.name)
+            .field(
+                "phone",
+                &self
+                    .phone
+                    .as_deref()
+                    .map(|phone| redact(PiiField::Phone, phone)),
+            )
+            .field(
+                "address",
+                &self
+                    .address
+                    .as_deref()
+                    .map(|address| redact(PiiField::Address, address)),
+            )
+            .finish()
+    }
+}
+
+impl fmt::Debug for ChangeEmailDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChangeEmailDTO")
+            .field("email", &redact(PiiField::Email, &self.email))
+            .finish()
+    }
+}
+
+impl fmt::Debug for ConfirmEmailChangeDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfirmEmailChangeDTO")
+            .field("token", &"[redacted]")
+            .finish()
+    }
+}
+
+impl fmt::Debug for LoadedUserDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoadedUserDTO")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("email", &redact(PiiField::Email, &self.email))
+            .field("phone", &redact(PiiField::Phone, &self.phone))
+            .field("address", &redact(PiiField::Address, &self.address))
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .field("suspended_at", &self.suspended_at)
+            .finish()
+    }
 }
 
 impl From<User> for Option<LoadedUserDTO> {
@@ -28,17 +179,71 @@ impl From<User> for Option<LoadedUserDTO> {
                 email: value.email,
                 phone: value.phone,
                 address: value.address,
+                created_at: value.created_at,
+                updated_at: value.updated_at,
+                suspended_at: value.suspended_at,
             }),
             ID::New => None,
         }
     }
 }
 
+impl From<UserErasure> for UserErasureDTO {
+    fn from(value: UserErasure) -> Self {
+        Self {
+            requested_by: value.requested_by,
+            erased_at: value.erased_at,
+        }
+    }
+}
+
+impl From<UserAuditEntry> for UserAuditEntryDTO {
+    fn from(value: UserAuditEntry) -> Self {
+        Self {
+            actor: value.actor,
+            action: value.action,
+            request_id: value.request_id,
+            changes: value.changes,
+            recorded_at: value.recorded_at,
+        }
+    }
+}
+
+impl From<UserSearchHit> for Option<UserSearchHitDTO> {
+    fn from(value: UserSearchHit) -> Self {
+        Option::<LoadedUserDTO>::from(value.user).map(|user| UserSearchHitDTO {
+            user,
+            score: value.score,
+            highlights: value.highlights,
+        })
+    }
+}
+
+impl From<UserDataExport> for UserDataExportDTO {
+    fn from(value: UserDataExport) -> Self {
+        Self {
+            user: value.user.into(),
+            erasures: value.erasures.into_iter().map(Into::into).collect(),
+            history: value.history.into_iter().map(Into::into).collect(),
+            exported_at: value.exported_at,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use chrono::Utc;
+    use serde_json::json;
+
+    use crate::application::use_cases::export_user_data::UserDataExport;
     use crate::domain::entities::user::User;
+    use crate::domain::entities::user_audit_entry::UserAuditEntry;
+    use crate::domain::entities::user_erasure::UserErasure;
     use crate::domain::value_objects::id::ID;
-    use crate::presentation::dtos::user_dto::LoadedUserDTO;
+    use crate::presentation::dtos::user_dto::{
+        ChangeEmailDTO, ConfirmEmailChangeDTO, CreateUserDTO, LoadedUserDTO, UserAuditEntryDTO,
+        UserDataExportDTO, UserErasureDTO,
+    };
 
     #[test]
     fn from_user_into_optional_loaded_user_dto() {
@@ -61,8 +266,10 @@ mod test {
 
         let mut existing_user = new_user;
         existing_user.id = ID::Existing(id);
+        existing_user.created_at = Some(Utc::now());
+        existing_user.updated_at = existing_user.created_at;
 
-        let loaded_user_dto: Option<LoadedUserDTO> = existing_user.into();
+        let loaded_user_dto: Option<LoadedUserDTO> = existing_user.clone().into();
         let loaded_user_dto = loaded_user_dto.unwrap();
 
         assert_eq!(loaded_user_dto.id, id);
@@ -70,5 +277,106 @@ mod test {
         assert_eq!(loaded_user_dto.email, email);
         assert_eq!(loaded_user_dto.phone, phone);
         assert_eq!(loaded_user_dto.address, address);
+        assert_eq!(loaded_user_dto.created_at, existing_user.created_at);
+        assert_eq!(loaded_user_dto.updated_at, existing_user.updated_at);
+    }
+
+    #[test]
+    fn debug_redacts_pii() {
+        let create_user_dto = CreateUserDTO {
+            name: "Andrew".to_string(),
+            email: "andrew@email.com".to_string(),
+            phone: "+550011111-2222".to_string(),
+            address: "Dawn St.".to_string(),
+        };
+
+        let loaded_user_dto = LoadedUserDTO {
+            id: 42,
+            name: create_user_dto.name.clone(),
+            email: create_user_dto.email.clone(),
+            phone: create_user_dto.phone.clone(),
+            address: create_user_dto.address.clone(),
+            created_at: None,
+            updated_at: None,
+            suspended_at: None,
+        };
+
+        assert_eq!(
+            format!("{create_user_dto:?}"),
+            "CreateUserDTO { name: \"Andrew\", email: \"a***@email.com\", phone: \"***22\", address: \"[redacted]\" }"
+        );
+        assert_eq!(
+            format!("{loaded_user_dto:?}"),
+            "LoadedUserDTO { id: 42, name: \"Andrew\", email: \"a***@email.com\", phone: \"***22\", address: \"[redacted]\", created_at: None, updated_at: None, suspended_at: None }"
+        );
+    }
+
+    #[test]
+    fn from_user_data_export_into_dto() {
+        let exported_at = Utc::now();
+
+        let user = User::restore(
+            42,
+            "Andrew".to_string(),
+            "andrew@email.com".to_string(),
+            "+550011111-2222".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap();
+
+        let export = UserDataExport {
+            user: user.clone(),
+            erasures: vec![UserErasure::new(42, "1".to_string(), exported_at)],
+            history: vec![UserAuditEntry::new(
+                42,
+                "1".to_string(),
+                "erase",
+                Some("req-1".to_string()),
+                json!({ "name": { "before": "[redacted]", "after": "[redacted]" } }),
+                exported_at,
+            )],
+            exported_at,
+        };
+
+        let dto: UserDataExportDTO = export.into();
+
+        assert_eq!(dto.user, user.into());
+        assert_eq!(
+            dto.erasures,
+            vec![UserErasureDTO {
+                requested_by: "1".to_string(),
+                erased_at: exported_at,
+            }]
+        );
+        assert_eq!(
+            dto.history,
+            vec![UserAuditEntryDTO {
+                actor: "1".to_string(),
+                action: "erase".to_string(),
+                request_id: Some("req-1".to_string()),
+                changes: json!({ "name": { "before": "[redacted]", "after": "[redacted]" } }),
+                recorded_at: exported_at,
+            }]
+        );
+        assert_eq!(dto.exported_at, exported_at);
+    }
+
+    #[test]
+    fn email_change_dtos_debug_redacts_secrets() {
+        let change_email_dto = ChangeEmailDTO {
+            email: "new@email.com".to_string(),
+        };
+        let confirm_dto = ConfirmEmailChangeDTO {
+            token: "secret-token".to_string(),
+        };
+
+        assert_eq!(
+            format!("{change_email_dto:?}"),
+            "ChangeEmailDTO { email: \"n***@email.com\" }"
+        );
+        assert_eq!(
+            format!("{confirm_dto:?}"),
+            "ConfirmEmailChangeDTO { token: \"[redacted]\" }"
+        );
     }
 }
@@ -0,0 +1,383 @@
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+use actix_web::web::Bytes;
+use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray, TimestampMicrosecondArray};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use futures_util::{Stream, StreamExt, stream};
+use parquet::arrow::ArrowWriter;
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    application::errors::user_application_error::UserApplicationError,
+    domain::{entities::user::User, repositories::user_repository::UserBatchStream},
+    presentation::{dtos::user_dto::LoadedUserDTO, errors::user_http_error::UserHttpError},
+};
+
+const COLUMNS: [&str; 8] = [
+    "id",
+    "name",
+    "email",
+    "phone",
+    "address",
+    "created_at",
+    "updated_at",
+    "suspended_at",
+];
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, ValueEnum, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Ndjson,
+    Parquet,
+}
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Ndjson => "application/x-ndjson",
+            ExportFormat::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ndjson => "ndjson",
+            ExportFormat::Parquet => "parquet",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportUsersQuery {
+    pub format: ExportFormat,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+pub struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn take(&self) -> Bytes {
+        Bytes::from(std::mem::take(&mut *self.0.lock().unwrap()))
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub enum UserExportEncoder {
+    Csv {
+        header_written: bool,
+    },
+    Ndjson,
+    Parquet {
+        writer: Box<ArrowWriter<SharedBuffer>>,
+        buffer: SharedBuffer,
+        schema: SchemaRef,
+    },
+}
+
+impl UserExportEncoder {
+    pub fn new(format: ExportFormat) -> Result<Self, UserHttpError> {
+        match format {
+            ExportFormat::Csv => Ok(Self::Csv {
+                header_written: false,
+            }),
+            ExportFormat::Ndjson => Ok(Self::Ndjson),
+            ExportFormat::Parquet => {
+                let schema = Arc::new(parquet_schema());
+                let buffer = SharedBuffer::default();
+                let writer = ArrowWriter::try_new(buffer.clone(), schema.clone(), None)
+                    .map_err(encoding_error)?;
+
+                Ok(Self::Parquet {
+                    writer: Box::new(writer),
+                    buffer,
+                    schema,
+                })
+            }
+        }
+    }
+
+    pub fn encode(&mut self, users: Vec<User>) -> Result<Bytes, UserHttpError> {
+        let rows: Vec<LoadedUserDTO> = users
+            .into_iter()
+            .filter_map(Option::<LoadedUserDTO>::from)
+            .collect();
+
+        match self {
+            Self::Csv { header_written } => {
+                let mut writer = csv::WriterBuilder::new()
+                    .has_headers(!*header_written)
+                    .from_writer(vec![]);
+
+                for row in &rows {
+                    writer.serialize(row).map_err(encoding_error)?;
+                }
+
+                *header_written |= !rows.is_empty();
+
+                writer.into_inner().map(Bytes::from).map_err(encoding_error)
+            }
+            Self::Ndjson => {
+                let mut chunk = vec![];
+
+                for row in &rows {
+                    serde_json::to_writer(&mut chunk, row).map_err(encoding_error)?;
+                    chunk.push(b'\n');
+                }
+
+                Ok(Bytes::from(chunk))
+            }
+            Self::Parquet {
+                writer,
+                buffer,
+                schema,
+            } => {
+                writer
+                    .write(&record_batch(schema.clone(), &rows)?)
+                    .map_err(encoding_error)?;
+                writer.flush().map_err(encoding_error)?;
+
+                Ok(buffer.take())
+            }
+        }
+    }
+
+    pub fn finish(self) -> Result<Bytes, UserHttpError> {
+        match self {
+            Self::Csv {
+                header_written: false,
+            } => {
+                let mut writer = csv::Writer::from_writer(vec![]);
+
+                writer.write_record(COLUMNS).map_err(encoding_error)?;
+
+                writer.into_inner().map(Bytes::from).map_err(encoding_error)
+            }
+            Self::Csv { .. } | Self::Ndjson => Ok(Bytes::new()),
+            Self::Parquet { writer, buffer, .. } => {
+                writer.close().map_err(encoding_error)?;
+
+                Ok(buffer.take())
+            }
+        }
+    }
+}
+
+pub fn encode_export(
+    batches: UserBatchStream,
+    encoder: UserExportEncoder,
+) -> impl Stream<Item = Result<Bytes, UserHttpError>> {
+    stream::unfold(Some((batches, encoder)), |state| async move {
+        let (mut batches, mut encoder) = state?;
+
+        match batches.next().await {
+            Some(Ok(users)) => {
+                let chunk = encoder.encode(users);
+                let state = chunk.is_ok().then_some((batches, encoder));
+
+                Some((chunk, state))
+            }
+            Some(Err(err)) => Some((
+                Err(UserHttpError::from(UserApplicationError::from(err))),
+                None,
+            )),
+            None => Some((encoder.finish(), None)),
+        }
+    })
+}
+
+fn parquet_schema() -> Schema {
+    let timestamp = DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()));
+
+    Schema::new(vec![
+        Field::new(COLUMNS[0], DataType::Int32, false),
+        Field::new(COLUMNS[1], DataType::Utf8, false),
+        Field::new(COLUMNS[2], DataType::Utf8, false),
+        Field::new(COLUMNS[3], DataType::Utf8, false),
+        Field::new(COLUMNS[4], DataType::Utf8, false),
+        Field::new(COLUMNS[5], timestamp.clone(), true),
+        Field::new(COLUMNS[6], timestamp.clone(), true),
+        Field::new(COLUMNS[7], timestamp, true),
+    ])
+}
+
+fn record_batch(schema: SchemaRef, rows: &[LoadedUserDTO]) -> Result<RecordBatch, UserHttpError> {
+    let strings = |field: fn(&LoadedUserDTO) -> &str| -> ArrayRef {
+        Arc::new(StringArray::from_iter_values(rows.iter().map(field)))
+    };
+
+    let timestamps = |field: fn(&LoadedUserDTO) -> Option<DateTime<Utc>>| -> ArrayRef {
+        Arc::new(
+            TimestampMicrosecondArray::from_iter(
+                rows.iter()
+                    .map(|row| field(row).map(|at| at.timestamp_micros())),
+            )
+            .with_timezone("UTC"),
+        )
+    };
+
+    RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int32Array::from_iter_values(rows.iter().map(|row| row.id))),
+            strings(|row| &row.name),
+            strings(|row| &row.email),
+            strings(|row| &row.phone),
+            strings(|row| &row.address),
+            timestamps(|row| row.created_at),
+            timestamps(|row| row.updated_at),
+            timestamps(|row| row.suspended_at),
+        ],
+    )
+    .map_err(encoding_error)
+}
+
+fn encoding_error(err: impl std::fmt::Display) -> UserHttpError {
+    UserHttpError::Internal(format!("Failed to encode the export: {err}"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use actix_web::web::Bytes;
+    use futures_util::{StreamExt, stream};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use crate::{
+        domain::{entities::user::User, repositories::user_repository::UserBatchStream},
+        presentation::dtos::user_export_dto::{ExportFormat, UserExportEncoder, encode_export},
+    };
+
+    const BATCHES: usize = 200;
+    const BATCH_SIZE: usize = 1000;
+
+    fn user(id: i32) -> User {
+        User::restore(
+            id,
+            format!("User {id}"),
+            format!("user{id}@email.com"),
+            "+001133334444".to_string(),
+            "Dawn St.".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn seeded(batches: usize, produced: Arc<AtomicUsize>) -> UserBatchStream {
+        stream::iter(0..batches)
+            .map(move |batch| {
+                produced.fetch_add(1, Ordering::SeqCst);
+
+                let first = (batch * BATCH_SIZE) as i32 + 1;
+
+                Ok((first..first + BATCH_SIZE as i32).map(user).collect())
+            })
+            .boxed()
+    }
+
+    async fn export(format: ExportFormat, batches: usize) -> (Vec<Bytes>, usize) {
+        let produced = Arc::new(AtomicUsize::new(0));
+        let encoder = UserExportEncoder::new(format).unwrap();
+        let mut body = Box::pin(encode_export(seeded(batches, produced.clone()), encoder));
+
+        let mut chunks = vec![];
+        let mut max_in_flight = 0;
+
+        while let Some(chunk) = body.next().await {
+            chunks.push(chunk.unwrap());
+            max_in_flight = max_in_flight.max(produced.load(Ordering::SeqCst) + 1 - chunks.len());
+        }
+
+        (chunks, max_in_flight)
+    }
+
+    #[tokio::test]
+    async fn csv_writes_the_header_once() {
+        let (chunks, _) = export(ExportFormat::Csv, 2).await;
+
+        let body: Vec<u8> = chunks.concat();
+        let lines: Vec<&str> = std::str::from_utf8(&body).unwrap().lines().collect();
+
+        assert_eq!(lines.len(), 1 + 2 * BATCH_SIZE);
+        assert_eq!(
+            lines[0],
+            "id,name,email,phone,address,created_at,updated_at,suspended_at"
+        );
+        assert_eq!(
+            lines[1],
+            "1,User 1,user1@email.com,+001133334444,Dawn St.,,,"
+        );
+    }
+
+    #[tokio::test]
+    async fn csv_empty_export_has_a_header() {
+        let (chunks, _) = export(ExportFormat::Csv, 0).await;
+
+        assert_eq!(
+            chunks.concat(),
+            b"id,name,email,phone,address,created_at,updated_at,suspended_at\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn ndjson_streams_in_constant_memory() {
+        let (chunks, max_in_flight) = export(ExportFormat::Ndjson, BATCHES).await;
+
+        let largest_chunk = chunks.iter().map(Bytes::len).max().unwrap();
+        let total: usize = chunks.iter().map(Bytes::len).sum();
+
+        assert_eq!(max_in_flight, 1);
+        assert!(largest_chunk * BATCHES / 2 < total);
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| chunk.iter().filter(|b| **b == b'\n').count())
+                .sum::<usize>(),
+            BATCHES * BATCH_SIZE
+        );
+    }
+
+    #[tokio::test]
+    async fn parquet_streams_one_row_group_per_batch() {
+        let (chunks, max_in_flight) = export(ExportFormat::Parquet, BATCHES).await;
+
+        let (_footer, row_groups) = chunks.split_last().unwrap();
+        let largest_chunk = row_groups.iter().map(Bytes::len).max().unwrap();
+        let body = Bytes::from(chunks.concat());
+
+        assert_eq!(max_in_flight, 1);
+        assert!(largest_chunk * BATCHES / 2 < body.len());
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(body).unwrap();
+
+        assert_eq!(reader.metadata().num_row_groups(), BATCHES);
+        assert_eq!(
+            reader.metadata().file_metadata().num_rows() as usize,
+            BATCHES * BATCH_SIZE
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    io::{self, BufRead, BufReader, Read},
+    iter,
+};
+
+use actix_web::web::Bytes;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use utoipa::ToSchema;
+
+use crate::{
+    application::use_cases::import_users::{ImportRowError, ImportRowOutcome, ImportRowResult},
+    presentation::dtos::user_dto::CreateUserDTO,
+};
+
+#[derive(Debug, Serialize, PartialEq, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ImportRowStatusDTO {
+    Created { id: i32 },
+    Valid,
+    Duplicate { reason: String },
+    Invalid { reasons: Vec<String> },
+}
+
+#[derive(Debug, Serialize, PartialEq, ToSchema)]
+pub struct ImportRowDTO {
+    pub row: usize,
+    #[serde(flatten)]
+    pub status: ImportRowStatusDTO,
+}
+
+#[derive(Debug, Default, Serialize, PartialEq, ToSchema)]
+pub struct UserImportReportDTO {
+    pub dry_run: bool,
+    pub created: usize,
+    pub valid: usize,
+    pub duplicate: usize,
+    pub invalid: usize,
+    pub rows: Vec<ImportRowDTO>,
+}
+
+impl From<ImportRowResult> for ImportRowDTO {
+    fn from(value: ImportRowResult) -> Self {
+        Self {
+            row: value.row,
+            status: match value.outcome {
+                ImportRowOutcome::Created(id) => ImportRowStatusDTO::Created { id },
+                ImportRowOutcome::Valid => ImportRowStatusDTO::Valid,
+                ImportRowOutcome::Duplicate(reason) => ImportRowStatusDTO::Duplicate { reason },
+                ImportRowOutcome::Invalid(reasons) => ImportRowStatusDTO::Invalid { reasons },
+            },
+        }
+    }
+}
+
+impl UserImportReportDTO {
+    pub fn new(dry_run: bool, results: Vec<ImportRowResult>) -> Self {
+        let mut report = Self {
+            dry_run,
+            ..Self::default()
+        };
+
+        for result in results {
+            match result.outcome {
+                ImportRowOutcome::Created(_) => report.created += 1,
+                ImportRowOutcome::Valid => report.valid += 1,
+                ImportRowOutcome::Duplicate(_) => report.duplicate += 1,
+                ImportRowOutcome::Invalid(_) => report.invalid += 1,
+            }
+
+            report.rows.push(result.into());
+        }
+
+        report
+    }
+}
+
+pub type ImportRows = Box<dyn Iterator<Item = Result<CreateUserDTO, ImportRowError>> + Send>;
+
+pub fn parse_csv<R: Read + Send + 'static>(input: R) -> ImportRows {
+    let mut records = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(input)
+        .into_deserialize::<CreateUserDTO>();
+    let mut failed = false;
+
+    Box::new(iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+
+        Some(records.next()?.map_err(|err| match err.kind() {
+            csv::ErrorKind::Io(err) => {
+                failed = true;
+                ImportRowError::Interrupted(err.to_string())
+            }
+            _ => ImportRowError::Invalid(format!("Malformed CSV record: {err}")),
+        }))
+    }))
+}
+
+pub fn parse_ndjson<R: Read + Send + 'static>(input: R) -> ImportRows {
+    let mut lines = BufReader::new(input).split(b'\n');
+    let mut failed = false;
+
+    Box::new(iter::from_fn(move || {
+        while !failed {
+            let line = match lines.next()? {
+                Ok(line) => line,
+                Err(err) => {
+                    failed = true;
+                    return Some(Err(ImportRowError::Interrupted(err.to_string())));
+                }
+            };
+
+            let Ok(line) = str::from_utf8(&line) else {
+                return Some(Err(ImportRowError::Invalid(
+                    "The line is not valid UTF-8".to_string(),
+                )));
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            return Some(
+                serde_json::from_str::<CreateUserDTO>(line)
+                    .map_err(|err| ImportRowError::Invalid(format!("Malformed JSON line: {err}"))),
+            );
+        }
+
+        None
+    }))
+}
+
+/// Blocking reader over request body chunks sent from the async side, so the
+/// synchronous parsers can consume an import while it is still uploading.
+pub struct BodyReader {
+    chunks: mpsc::Receiver<io::Result<Bytes>>,
+    chunk: Bytes,
+}
+
+impl BodyReader {
+    pub fn new(chunks: mpsc::Receiver<io::Result<Bytes>>) -> Self {
+        Self {
+            chunks,
+            chunk: Bytes::new(),
+        }
+    }
+}
+
+impl Read for BodyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.chunk.is_empty() {
+            match self.chunks.blocking_recv() {
+                Some(chunk) => self.chunk = chunk?,
+                None => return Ok(0),
+            }
+        }
+
+        let len = buf.len().min(self.chunk.len());
+        buf[..len].copy_from_slice(&self.chunk.split_to(len));
+
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{self, Read};
+
+    use actix_web::web::Bytes;
+    use serde_json::json;
+    use tokio::sync::mpsc;
+
+    use crate::application::use_cases::import_users::{
+        ImportRowError, ImportRowOutcome, ImportRowResult,
+    };
+
+    use super::{BodyReader, UserImportReportDTO, parse_csv, parse_ndjson};
+
+    #[test]
+    fn parse_csv_rows() {
+        let input = b"name,email,phone,address\n\
+            Andrew, andrew@email.com ,+001133334444,Dawn St.\n\
+            Bea,bea@email.com\n";
+
+        let rows: Vec<_> = parse_csv(&input[..]).collect();
+
+        assert_eq!(rows.len(), 2);
+
+        let first = rows[0].as_ref().unwrap();
+        assert_eq!(first.name, "Andrew");
+        assert_eq!(first.email, "andrew@email.com");
+
+        assert!(
+            rows[1]
+                .as_ref()
+                .is_err_and(|err| matches!(err, ImportRowError::Invalid(reason) if reason.starts_with("Malformed CSV record")))
+        );
+    }
+
+    #[test]
+    fn parse_ndjson_rows() {
+        let input = br#"{"name":"Andrew","email":"andrew@email.com","phone":"+001133334444","address":"Dawn St."}
+
+{"name":"Bea"}
+"#;
+
+        let rows: Vec<_> = parse_ndjson(&input[..]).collect();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].as_ref().unwrap().name, "Andrew");
+        assert!(
+            rows[1]
+                .as_ref()
+                .is_err_and(|err| matches!(err, ImportRowError::Invalid(reason) if reason.starts_with("Malformed JSON line")))
+        );
+    }
+
+    #[test]
+    fn parse_ndjson_rejects_lines_that_are_not_utf8() {
+        let input = b"{\"name\":\"Andr\xe9\"}\n{\"name\":\"Bea\",\"email\":\"bea@email.com\",\"phone\":\"+001133334444\",\"address\":\"Dusk Av.\"}\n";
+
+        let rows: Vec<_> = parse_ndjson(&input[..]).collect();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0].as_ref().unwrap_err(),
+            &ImportRowError::Invalid("The line is not valid UTF-8".to_string())
+        );
+        assert_eq!(rows[1].as_ref().unwrap().name, "Bea");
+    }
+
+    #[test]
+    fn parse_csv_rejects_records_that_are_not_utf8() {
+        let input = b"name,email,phone,address\n\
+            Andr\xe9,andrew@email.com,+001133334444,Dawn St.\n\
+            Bea,bea@email.com,+001133334444,Dusk Av.\n";
+
+        let rows: Vec<_> = parse_csv(&input[..]).collect();
+
+        assert_eq!(rows.len(), 2);
+        assert!(
+            rows[0]
+                .as_ref()
+                .is_err_and(|err| matches!(err, ImportRowError::Invalid(reason) if reason.starts_with("Malformed CSV record")))
+        );
+        assert_eq!(rows[1].as_ref().unwrap().name, "Bea");
+    }
+
+    #[test]
+    fn parsing_stops_at_a_read_error() {
+        let (sender, receiver) = mpsc::channel(4);
+
+        sender
+            .try_send(Ok(Bytes::from_static(
+                b"{\"name\":\"Andrew\",\"email\":\"andrew@email.com\",\"phone\":\"+001133334444\",\"address\":\"Dawn St.\"}\n{\"name\":",
+            )))
+            .unwrap();
+        sender
+            .try_send(Err(io::Error::other("The upload was interrupted")))
+            .unwrap();
+        drop(sender);
+
+        let rows: Vec<_> = parse_ndjson(BodyReader::new(receiver)).collect();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].as_ref().unwrap().name, "Andrew");
+        assert_eq!(
+            rows[1].as_ref().unwrap_err(),
+            &ImportRowError::Interrupted("The upload was interrupted".to_string())
+        );
+    }
+
+    #[test]
+    fn body_reader_reads_across_chunks() {
+        let (sender, receiver) = mpsc::channel(4);
+
+        sender
+            .try_send(Ok(Bytes::from_static(b"name,email\nAnd")))
+            .unwrap();
+        sender.try_send(Ok(Bytes::new())).unwrap();
+        sender
+            .try_send(Ok(Bytes::from_static(b"rew,a@email.com\n")))
+            .unwrap();
+        drop(sender);
+
+        let mut body = String::new();
+        BodyReader::new(receiver).read_to_string(&mut body).unwrap();
+
+        assert_eq!(body, "name,email\nAndrew,a@email.com\n");
+    }
+
+    #[test]
+    fn report_counts_and_serializes_rows() -> Result<(), Box<dyn std::error::Error>> {
+        let report = UserImportReportDTO::new(
+            false,
+            vec![
+                ImportRowResult {
+                    row: 1,
+                    outcome: ImportRowOutcome::Created(42),
+                },
+                ImportRowResult {
+                    row: 2,
+                    outcome: ImportRowOutcome::Invalid(vec!["name must not be empty".to_string()]),
+                },
+            ],
+        );
+
+        assert_eq!(
+            serde_json::to_value(&report)?,
+            json!({
+                "dry_run": false,
+                "created": 1,
+                "valid": 0,
+                "duplicate": 0,
+                "invalid": 1,
+                "rows": [
+                    { "row": 1, "status": "created", "id": 42 },
+                    { "row": 2, "status": "invalid", "reasons": ["name must not be empty"] },
+                ],
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt;
+
+use actix_web::{HttpResponse, ResponseError, body::BoxBody};
+
+use crate::application::errors::api_key_application_error::ApiKeyApplicationError;
+
+#[derive(Debug, PartialEq)]
+pub enum ApiKeyHttpError {
+    Invalid(String),
+    Forbidden(String),
+    NotFound(String),
+    Internal(String),
+}
+
+impl fmt::Display for ApiKeyHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiKeyHttpError::Invalid(msg) => {
+                write!(f, "An invalid request was made for the API key: {msg}")
+            }
+            ApiKeyHttpError::Forbidden(msg) => {
+                write!(f, "The operation is forbidden for the API key: {msg}")
+            }
+            ApiKeyHttpError::NotFound(msg) => {
+                write!(f, "The API key was not found: {msg}")
+            }
+            ApiKeyHttpError::Internal(msg) => {
+                write!(f, "An internal error occurred for the API key: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApiKeyHttpError {}
+
+impl From<ApiKeyApplicationError> for ApiKeyHttpError {
+    fn from(value: ApiKeyApplicationError) -> Self {
+        match value {
+            ApiKeyApplicationError::Invalid(err) => Self::Invalid(err),
+            ApiKeyApplicationError::Forbidden(err) => Self::Forbidden(err),
+            err @ ApiKeyApplicationError::NotFound(_) => Self::NotFound(err.to_string()),
+            ApiKeyApplicationError::Unexpected(err) => Self::Internal(err),
+        }
+    }
+}
+
+impl ResponseError for ApiKeyHttpError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        match self {
+            ApiKeyHttpError::Invalid(_) => {
+                HttpResponse::UnprocessableEntity().json(self.to_string())
+            }
+            ApiKeyHttpError::Forbidden(_) => HttpResponse::Forbidden().json(self.to_string()),
+            ApiKeyHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
+            ApiKeyHttpError::Internal(_) => {
+                HttpResponse::InternalServerError().json(self.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{ResponseError, http::StatusCode};
+
+    use crate::application::errors::api_key_application_error::ApiKeyApplicationError;
+
+    use super::ApiKeyHttpError;
+
+    #[test]
+    fn from_api_key_application_error() {
+        assert_eq!(
+            ApiKeyHttpError::from(ApiKeyApplicationError::Invalid("x".to_string())),
+            ApiKeyHttpError::Invalid("x".to_string())
+        );
+        assert_eq!(
+            ApiKeyHttpError::from(ApiKeyApplicationError::Forbidden("x".to_string())),
+            ApiKeyHttpError::Forbidden("x".to_string())
+        );
+        assert_eq!(
+            ApiKeyHttpError::from(ApiKeyApplicationError::NotFound(7)),
+            ApiKeyHttpError::NotFound("No active API key was found by ID: 7".to_string())
+        );
+        assert_eq!(
+            ApiKeyHttpError::from(ApiKeyApplicationError::Unexpected("x".to_string())),
+            ApiKeyHttpError::Internal("x".to_string())
+        );
+    }
+
+    #[test]
+    fn error_response_status() {
+        let cases = [
+            (
+                ApiKeyHttpError::Invalid("x".to_string()),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                ApiKeyHttpError::Forbidden("x".to_string()),
+                StatusCode::FORBIDDEN,
+            ),
+            (
+                ApiKeyHttpError::NotFound("x".to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                ApiKeyHttpError::Internal("x".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ];
+
+        for (err, status) in cases {
+            assert_eq!(err.error_response().status(), status);
+        }
+    }
+}
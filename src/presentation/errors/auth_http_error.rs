@@ -0,0 +1,147 @@
+use std::fmt;
+
+use actix_web::{
+    HttpResponse, ResponseError,
+    body::BoxBody,
+    http::{StatusCode, header},
+};
+use serde_json::json;
+
+use crate::application::errors::authentication_error::AuthenticationError;
+
+#[derive(Debug, PartialEq)]
+pub enum AuthHttpError {
+    Unauthorized(String),
+    Internal(String),
+}
+
+impl fmt::Display for AuthHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthHttpError::Unauthorized(msg) => {
+                write!(f, "The request could not be authenticated: {msg}")
+            }
+            AuthHttpError::Internal(msg) => {
+                write!(f, "An internal error occurred when authenticating: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AuthHttpError {}
+
+impl From<AuthenticationError> for AuthHttpError {
+    fn from(value: AuthenticationError) -> Self {
+        match value {
+            AuthenticationError::Unexpected(err) => Self::Internal(err),
+            err => Self::Unauthorized(err.to_string()),
+        }
+    }
+}
+
+impl ResponseError for AuthHttpError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AuthHttpError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AuthHttpError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let status = self.status_code();
+
+        let mut response = HttpResponse::build(status);
+
+        if let AuthHttpError::Unauthorized(_) = self {
+            response.insert_header((header::WWW_AUTHENTICATE, "Bearer"));
+        }
+
+        response
+            .content_type("application/problem+json")
+            .json(json!({
+                "type": "about:blank",
+                "title": status.canonical_reason(),
+                "status": status.as_u16(),
+                "detail": self.to_string(),
+            }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{
+        ResponseError,
+        body::MessageBody,
+        http::{StatusCode, header},
+    };
+
+    use crate::application::errors::authentication_error::AuthenticationError;
+
+    use super::AuthHttpError;
+
+    #[test]
+    fn display_unauthorized() {
+        let err_msg = "token expired";
+        let err = AuthHttpError::Unauthorized(err_msg.to_string()).to_string();
+
+        assert_eq!(
+            err,
+            format!("The request could not be authenticated: {err_msg}")
+        );
+    }
+
+    #[test]
+    fn from_authentication_error() {
+        let err: AuthHttpError = AuthenticationError::MissingCredentials.into();
+
+        assert_eq!(
+            err,
+            AuthHttpError::Unauthorized(AuthenticationError::MissingCredentials.to_string())
+        );
+    }
+
+    #[test]
+    fn from_unexpected_authentication_error() {
+        let err: AuthHttpError =
+            AuthenticationError::Unexpected("Connection lost".to_string()).into();
+
+        assert_eq!(err, AuthHttpError::Internal("Connection lost".to_string()));
+    }
+
+    #[test]
+    fn internal_error_response() {
+        let err = AuthHttpError::Internal("Connection lost".to_string());
+
+        let result = err.error_response();
+
+        assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(result.headers().get(header::WWW_AUTHENTICATE).is_none());
+    }
+
+    #[test]
+    fn unauthorized_error_response() -> Result<(), Box<dyn std::error::Error>> {
+        let err = AuthHttpError::Unauthorized("token expired".to_string());
+
+        let result = err.error_response();
+
+        let result_status = result.status();
+        let result_headers = result.headers().clone();
+        let result_body = result.into_body().try_into_bytes().unwrap();
+        let result_body: serde_json::Value = serde_json::from_slice(&result_body)?;
+
+        assert_eq!(result_status, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            result_headers.get(header::CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+        assert_eq!(
+            result_headers.get(header::WWW_AUTHENTICATE).unwrap(),
+            "Bearer"
+        );
+        assert_eq!(result_body["status"], 401);
+        assert_eq!(result_body["title"], "Unauthorized");
+        assert_eq!(result_body["detail"], err.to_string());
+
+        Ok(())
+    }
+}
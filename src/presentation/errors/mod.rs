@@ -1 +1,3 @@
+pub mod api_key_http_error;
+pub mod auth_http_error;
 pub mod user_http_error;
@@ -6,16 +6,28 @@ use crate::application::errors::user_application_error::UserApplicationError;
 
 #[derive(Debug, PartialEq)]
 pub enum UserHttpError {
+    BadRequest(String),
     Constraint(String),
+    Forbidden(String),
+    NotFound(String),
     Internal(String),
 }
 
 impl fmt::Display for UserHttpError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            UserHttpError::BadRequest(msg) => {
+                write!(f, "The request is invalid for the user: {msg}")
+            }
             UserHttpError::Constraint(msg) => {
                 write!(f, "A constraint error occurred for the user: {msg}")
             }
+            UserHttpError::Forbidden(msg) => {
+                write!(f, "The operation is forbidden for the user: {msg}")
+            }
+            UserHttpError::NotFound(msg) => {
+                write!(f, "The user could not be found: {msg}")
+            }
             UserHttpError::Internal(msg) => {
                 write!(f, "An internal error occurred for the user: {msg}")
             }
@@ -29,6 +41,9 @@ impl From<UserApplicationError> for UserHttpError {
     fn from(value: UserApplicationError) -> Self {
         match value {
             UserApplicationError::Conflict(err) => Self::Constraint(err),
+            UserApplicationError::Forbidden(err) => Self::Forbidden(err),
+            UserApplicationError::Invalid(err) => Self::BadRequest(err),
+            UserApplicationError::NotFound(err) => Self::NotFound(err),
             UserApplicationError::Unexpected(err) => Self::Internal(err),
         }
     }
@@ -37,9 +52,12 @@ impl From<UserApplicationError> for UserHttpError {
 impl ResponseError for UserHttpError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
         match self {
+            UserHttpError::BadRequest(_) => HttpResponse::BadRequest().json(self.to_string()),
             UserHttpError::Constraint(_) => {
                 HttpResponse::UnprocessableEntity().json(self.to_string())
             }
+            UserHttpError::Forbidden(_) => HttpResponse::Forbidden().json(self.to_string()),
+            UserHttpError::NotFound(_) => HttpResponse::NotFound().json(self.to_string()),
             UserHttpError::Internal(_) => {
                 HttpResponse::InternalServerError().json(self.to_string())
             }
@@ -67,6 +85,18 @@ mod test {
         );
     }
 
+    #[test]
+    fn display_forbidden_error() {
+        let err_msg = "Admin role required";
+        let err = UserHttpError::Forbidden(err_msg.to_string());
+        let err = err.to_string();
+
+        assert_eq!(
+            err,
+            format!("The operation is forbidden for the user: {err_msg}")
+        );
+    }
+
     #[test]
     fn display_internal_error() {
         let err_msg = "Database error";
@@ -88,6 +118,33 @@ mod test {
         assert_eq!(err, UserHttpError::Constraint(err_msg.to_string()));
     }
 
+    #[test]
+    fn from_user_application_forbidden_error() {
+        let err_msg = "Admin role required";
+        let application_err = UserApplicationError::Forbidden(err_msg.to_string());
+        let err: UserHttpError = application_err.into();
+
+        assert_eq!(err, UserHttpError::Forbidden(err_msg.to_string()));
+    }
+
+    #[test]
+    fn from_user_application_invalid_error() {
+        let err_msg = "the name is empty";
+        let application_err = UserApplicationError::Invalid(err_msg.to_string());
+        let err: UserHttpError = application_err.into();
+
+        assert_eq!(err, UserHttpError::BadRequest(err_msg.to_string()));
+    }
+
+    #[test]
+    fn from_user_application_not_found_error() {
+        let err_msg = "no user with ID 42";
+        let application_err = UserApplicationError::NotFound(err_msg.to_string());
+        let err: UserHttpError = application_err.into();
+
+        assert_eq!(err, UserHttpError::NotFound(err_msg.to_string()));
+    }
+
     #[test]
     fn from_user_application_internal_error() {
         let err_msg = "Database error";
@@ -113,6 +170,54 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn forbidden_error_response() -> Result<(), Box<dyn std::error::Error>> {
+        let err = UserHttpError::Forbidden("Admin role required".to_string());
+
+        let result = err.error_response();
+
+        let result_status = result.status();
+        let result_body = result.into_body().try_into_bytes().unwrap();
+        let result_body = std::str::from_utf8(&result_body)?;
+
+        assert_eq!(result_status, StatusCode::FORBIDDEN);
+        assert_eq!(result_body.replace("\"", ""), err.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_error_response() -> Result<(), Box<dyn std::error::Error>> {
+        let err = UserHttpError::BadRequest("the name is empty".to_string());
+
+        let result = err.error_response();
+
+        let result_status = result.status();
+        let result_body = result.into_body().try_into_bytes().unwrap();
+        let result_body = std::str::from_utf8(&result_body)?;
+
+        assert_eq!(result_status, StatusCode::BAD_REQUEST);
+        assert_eq!(result_body.replace("\"", ""), err.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_found_error_response() -> Result<(), Box<dyn std::error::Error>> {
+        let err = UserHttpError::NotFound("no user with ID 42".to_string());
+
+        let result = err.error_response();
+
+        let result_status = result.status();
+        let result_body = result.into_body().try_into_bytes().unwrap();
+        let result_body = std::str::from_utf8(&result_body)?;
+
+        assert_eq!(result_status, StatusCode::NOT_FOUND);
+        assert_eq!(result_body.replace("\"", ""), err.to_string());
+
+        Ok(())
+    }
+
     #[test]
     fn internal_error_response() -> Result<(), Box<dyn std::error::Error>> {
         let err = UserHttpError::Internal("Database error".to_string());
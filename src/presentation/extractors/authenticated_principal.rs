@@ -0,0 +1,25 @@
+use std::future::{Ready, ready};
+
+use actix_web::{FromRequest, HttpMessage, HttpRequest, dev::Payload};
+
+use crate::{
+    application::{
+        auth::authenticated_principal::AuthenticatedPrincipal,
+        errors::authentication_error::AuthenticationError,
+    },
+    presentation::errors::auth_http_error::AuthHttpError,
+};
+
+impl FromRequest for AuthenticatedPrincipal {
+    type Error = AuthHttpError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            req.extensions()
+                .get::<AuthenticatedPrincipal>()
+                .cloned()
+                .ok_or_else(|| AuthenticationError::MissingCredentials.into()),
+        )
+    }
+}
@@ -0,0 +1,70 @@
+use async_graphql::{Error, ErrorExtensions};
+
+use crate::application::{
+    auth::request_context::RequestContext, errors::user_application_error::UserApplicationError,
+};
+
+impl ErrorExtensions for UserApplicationError {
+    fn extend(&self) -> Error {
+        let code = match self {
+            UserApplicationError::Conflict(_) => "CONFLICT",
+            UserApplicationError::Forbidden(_) => "FORBIDDEN",
+            UserApplicationError::Invalid(_) => "BAD_USER_INPUT",
+            UserApplicationError::NotFound(_) => "NOT_FOUND",
+            UserApplicationError::Unexpected(_) => "INTERNAL_SERVER_ERROR",
+        };
+
+        Error::new(self.to_string()).extend_with(|_, extensions| {
+            extensions.set("code", code);
+
+            if let Some(request_id) = RequestContext::current().request_id {
+                extensions.set("request_id", request_id);
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use async_graphql::{ErrorExtensions, Value};
+
+    use crate::application::{
+        auth::request_context::RequestContext, errors::user_application_error::UserApplicationError,
+    };
+
+    #[tokio::test]
+    async fn extend_with_the_code_and_request_id() {
+        let err = RequestContext::anonymous("req-1".to_string())
+            .scope(async { UserApplicationError::NotFound("42".to_string()).extend() })
+            .await;
+
+        let extensions = err.extensions.unwrap();
+
+        assert_eq!(err.message, "The user was not found: 42");
+        assert_eq!(extensions.get("code"), Some(&Value::from("NOT_FOUND")));
+        assert_eq!(extensions.get("request_id"), Some(&Value::from("req-1")));
+    }
+
+    #[test]
+    fn extend_maps_every_kind_to_a_code() {
+        let codes: Vec<_> = [
+            UserApplicationError::Conflict(String::new()),
+            UserApplicationError::Forbidden(String::new()),
+            UserApplicationError::Invalid(String::new()),
+            UserApplicationError::Unexpected(String::new()),
+        ]
+        .iter()
+        .map(|err| err.extend().extensions.unwrap().get("code").cloned())
+        .collect();
+
+        assert_eq!(
+            codes,
+            vec![
+                Some(Value::from("CONFLICT")),
+                Some(Value::from("FORBIDDEN")),
+                Some(Value::from("BAD_USER_INPUT")),
+                Some(Value::from("INTERNAL_SERVER_ERROR")),
+            ]
+        );
+    }
+}
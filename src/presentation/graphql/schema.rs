@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use async_graphql::{
+    Context, EmptySubscription, ErrorExtensions, InputObject, Object, Result, Schema,
+    connection::{Connection, Edge},
+};
+use chrono::{DateTime, Utc};
+
+use crate::{
+    application::{
+        auth::authenticated_principal::AuthenticatedPrincipal,
+        errors::user_application_error::UserApplicationError,
+        use_cases::{
+            find_user_by_email::FindUserByEmailUseCase, find_user_by_id::FindUserByIdUseCase,
+            list_users::ListUsersUseCase, register_user::RegisterUserUseCase,
+            update_user::UpdateUserUseCase,
+        },
+    },
+    domain::{entities::user::User, value_objects::user_cursor::UserCursor},
+    infrastructure::{
+        metrics::app_metrics::observed,
+        repositories::caching_user_repository::CachedPostgresUserRepository,
+    },
+    presentation::dtos::user_dto::{CreateUserDTO, LoadedUserDTO, UpdateUserDTO},
+};
+
+const DEFAULT_PAGE_SIZE: i32 = 20;
+const MAX_PAGE_SIZE: i32 = 100;
+const MAX_DEPTH: usize = 10;
+
+pub type UserSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+#[derive(InputObject, Default)]
+pub struct UserFilter {
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+}
+
+fn context<'a>(
+    ctx: &'a Context<'_>,
+) -> Result<(
+    Arc<CachedPostgresUserRepository>,
+    &'a AuthenticatedPrincipal,
+)> {
+    Ok((
+        ctx.data::<Arc<CachedPostgresUserRepository>>()?.clone(),
+        ctx.data::<AuthenticatedPrincipal>()?,
+    ))
+}
+
+fn loaded(user: Option<User>) -> Option<LoadedUserDTO> {
+    user.and_then(Option::<LoadedUserDTO>::from)
+}
+
+fn invalid(message: String) -> async_graphql::Error {
+    UserApplicationError::Invalid(message).extend()
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn user(&self, ctx: &Context<'_>, id: i32) -> Result<Option<LoadedUserDTO>> {
+        let (repo, principal) = context(ctx)?;
+
+        observed(
+            "find_user_by_id",
+            FindUserByIdUseCase::new(repo).execute(principal, id),
+        )
+        .await
+        .map(loaded)
+        .map_err(|err| err.extend())
+    }
+
+    async fn user_by_email(
+        &self,
+        ctx: &Context<'_>,
+        email: String,
+    ) -> Result<Option<LoadedUserDTO>> {
+        let (repo, principal) = context(ctx)?;
+
+        observed(
+            "find_user_by_email",
+            FindUserByEmailUseCase::new(repo).execute(principal, email),
+        )
+        .await
+        .map(loaded)
+        .map_err(|err| err.extend())
+    }
+
+    async fn users(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<UserFilter>,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<Connection<String, LoadedUserDTO>> {
+        let first = first.unwrap_or(DEFAULT_PAGE_SIZE);
+        if !(1..=MAX_PAGE_SIZE).contains(&first) {
+            return Err(invalid(format!(
+                "first must be between 1 and {MAX_PAGE_SIZE}, got {first}"
+            )));
+        }
+
+        let after = after
+            .map(|cursor| cursor.parse::<UserCursor>())
+            .transpose()
+            .map_err(|err| UserApplicationError::from(err).extend())?;
+
+        let (repo, principal) = context(ctx)?;
+        let filter = filter.unwrap_or_default();
+
+        let mut users = observed(
+            "list_users",
+            ListUsersUseCase::new(repo).execute(
+                principal,
+                filter.created_from,
+                filter.created_to,
+                after.clone(),
+                Some(i64::from(first) + 1),
+            ),
+        )
+        .await
+        .map_err(|err| err.extend())?;
+
+        let has_next_page = users.len() > first as usize;
+        users.truncate(first as usize);
+
+        let mut connection = Connection::new(after.is_some(), has_next_page);
+        connection
+            .edges
+            .extend(users.into_iter().filter_map(|user| {
+                let cursor = UserCursor::of(&user)?.to_string();
+
+                Option::<LoadedUserDTO>::from(user).map(|user| Edge::new(cursor, user))
+            }));
+
+        Ok(connection)
+    }
+}
+
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    async fn register_user(&self, ctx: &Context<'_>, input: CreateUserDTO) -> Result<i32> {
+        let (repo, principal) = context(ctx)?;
+
+        observed(
+            "register_user",
+            RegisterUserUseCase::new(repo).execute(principal, input),
+        )
+        .await
+        .map_err(|err| err.extend())
+    }
+
+    async fn update_user(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        input: UpdateUserDTO,
+    ) -> Result<Option<LoadedUserDTO>> {
+        let (repo, principal) = context(ctx)?;
+
+        observed(
+            "update_user",
+            UpdateUserUseCase::new(repo).execute(principal, id, input),
+        )
+        .await
+        .map(|user| loaded(Some(user)))
+        .map_err(|err| err.extend())
+    }
+}
+
+pub fn build_schema(repo: Arc<CachedPostgresUserRepository>) -> UserSchema {
+    Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+        .data(repo)
+        .limit_depth(MAX_DEPTH)
+        .finish()
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use async_graphql::{Request, Value};
+    use diesel::{PgConnection, r2d2::ConnectionManager};
+
+    use crate::{
+        application::auth::authenticated_principal::AuthenticatedPrincipal,
+        infrastructure::bootstrap::user_repository,
+    };
+
+    use super::{MAX_DEPTH, UserSchema, build_schema};
+
+    fn schema() -> UserSchema {
+        let pool = r2d2::Pool::builder()
+            .build_unchecked(ConnectionManager::<PgConnection>::new("postgres://unused"));
+
+        build_schema(Arc::new(user_repository(pool)))
+    }
+
+    fn admin() -> AuthenticatedPrincipal {
+        AuthenticatedPrincipal::new("1".to_string(), None, vec!["admin".to_string()])
+    }
+
+    #[test]
+    fn sdl_exposes_the_user_operations() {
+        let sdl = schema().sdl();
+
+        for expected in [
+            "user(id: Int!): User",
+            "userByEmail(email: String!): User",
+            "users(filter: UserFilter, first: Int, after: String): UserConnection!",
+            "registerUser(input: RegisterUserInput!): Int!",
+            "updateUser(id: Int!, input: UpdateUserInput!): User",
+            "createdAt: DateTime",
+        ] {
+            assert!(sdl.contains(expected), "missing {expected:?} in:\n{sdl}");
+        }
+    }
+
+    #[tokio::test]
+    async fn users_rejects_a_malformed_cursor() {
+        let response = schema()
+            .execute(Request::new(r#"{ users(after: "nope") { edges { cursor } } }"#).data(admin()))
+            .await;
+
+        let extensions = response.errors[0].extensions.as_ref().unwrap();
+
+        assert_eq!(
+            response.errors[0].message,
+            "The user input is invalid: An invalid cursor was given for users: nope"
+        );
+        assert_eq!(extensions.get("code"), Some(&Value::from("BAD_USER_INPUT")));
+    }
+
+    #[tokio::test]
+    async fn queries_deeper_than_the_limit_are_rejected() {
+        let of_types = "ofType { ".repeat(MAX_DEPTH);
+        let query = format!(
+            "{{ __schema {{ types {{ {of_types}name{} }} }} }}",
+            " }".repeat(MAX_DEPTH)
+        );
+
+        let response = schema().execute(Request::new(query).data(admin())).await;
+
+        assert_eq!(response.errors[0].message, "Query is nested too deep.");
+    }
+}
@@ -0,0 +1,78 @@
+use tonic::{Code, Status};
+
+use crate::application::errors::{
+    authentication_error::AuthenticationError, user_application_error::UserApplicationError,
+};
+
+impl From<UserApplicationError> for Status {
+    fn from(value: UserApplicationError) -> Self {
+        let code = match value {
+            UserApplicationError::Conflict(_) => Code::AlreadyExists,
+            UserApplicationError::Forbidden(_) => Code::PermissionDenied,
+            UserApplicationError::Invalid(_) => Code::InvalidArgument,
+            UserApplicationError::NotFound(_) => Code::NotFound,
+            UserApplicationError::Unexpected(_) => Code::Internal,
+        };
+
+        Status::new(code, value.to_string())
+    }
+}
+
+impl From<AuthenticationError> for Status {
+    fn from(value: AuthenticationError) -> Self {
+        match value {
+            AuthenticationError::Unexpected(_) => Status::internal(value.to_string()),
+            err => Status::unauthenticated(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tonic::{Code, Status};
+
+    use crate::application::errors::{
+        authentication_error::AuthenticationError, user_application_error::UserApplicationError,
+    };
+
+    #[test]
+    fn from_user_application_error_maps_every_kind_to_a_code() {
+        let codes: Vec<_> = [
+            UserApplicationError::Conflict(String::new()),
+            UserApplicationError::Forbidden(String::new()),
+            UserApplicationError::Invalid(String::new()),
+            UserApplicationError::NotFound(String::new()),
+            UserApplicationError::Unexpected(String::new()),
+        ]
+        .into_iter()
+        .map(|err| Status::from(err).code())
+        .collect();
+
+        assert_eq!(
+            codes,
+            vec![
+                Code::AlreadyExists,
+                Code::PermissionDenied,
+                Code::InvalidArgument,
+                Code::NotFound,
+                Code::Internal,
+            ]
+        );
+    }
+
+    #[test]
+    fn from_user_application_error_keeps_the_message() {
+        let status = Status::from(UserApplicationError::NotFound("42".to_string()));
+
+        assert_eq!(status.message(), "The user was not found: 42");
+    }
+
+    #[test]
+    fn from_authentication_error() {
+        let missing = Status::from(AuthenticationError::MissingCredentials);
+        let unexpected = Status::from(AuthenticationError::Unexpected("boom".to_string()));
+
+        assert_eq!(missing.code(), Code::Unauthenticated);
+        assert_eq!(unexpected.code(), Code::Internal);
+    }
+}
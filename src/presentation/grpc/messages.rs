@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use prost_types::Timestamp;
+
+use crate::{
+    application::errors::user_application_error::UserApplicationError,
+    presentation::{
+        dtos::user_dto::{CreateUserDTO, LoadedUserDTO},
+        grpc::proto,
+    },
+};
+
+pub fn timestamp(value: DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: value.timestamp(),
+        nanos: value.timestamp_subsec_nanos() as i32,
+    }
+}
+
+pub fn datetime(
+    field: &str,
+    value: Option<Timestamp>,
+) -> Result<Option<DateTime<Utc>>, UserApplicationError> {
+    value
+        .map(|value| {
+            u32::try_from(value.nanos)
+                .ok()
+                .and_then(|nanos| DateTime::from_timestamp(value.seconds, nanos))
+                .ok_or_else(|| {
+                    UserApplicationError::Invalid(format!("{field} is not a valid timestamp"))
+                })
+        })
+        .transpose()
+}
+
+impl From<LoadedUserDTO> for proto::User {
+    fn from(value: LoadedUserDTO) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            email: value.email,
+            phone: value.phone,
+            address: value.address,
+            created_at: value.created_at.map(timestamp),
+            updated_at: value.updated_at.map(timestamp),
+        }
+    }
+}
+
+impl From<proto::RegisterUserRequest> for CreateUserDTO {
+    fn from(value: proto::RegisterUserRequest) -> Self {
+        Self {
+            name: value.name,
+            email: value.email,
+            phone: value.phone,
+            address: value.address,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+    use prost_types::Timestamp;
+
+    use crate::application::errors::user_application_error::UserApplicationError;
+
+    use super::{datetime, timestamp};
+
+    #[test]
+    fn timestamp_round_trips() {
+        let at = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+
+        assert_eq!(datetime("at", Some(timestamp(at))), Ok(Some(at)));
+    }
+
+    #[test]
+    fn datetime_rejects_negative_nanos() {
+        let result = datetime(
+            "created_from",
+            Some(Timestamp {
+                seconds: 0,
+                nanos: -1,
+            }),
+        );
+
+        assert_eq!(
+            result,
+            Err(UserApplicationError::Invalid(
+                "created_from is not a valid timestamp".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn datetime_without_value() {
+        assert_eq!(datetime("created_to", None), Ok(None));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod errors;
+pub mod messages;
+pub mod user_service;
+
+pub mod proto {
+    tonic::include_proto!("users.v1");
+}
@@ -0,0 +1,509 @@
+use std::sync::Arc;
+
+use futures_util::{
+    StreamExt,
+    stream::{self, BoxStream},
+};
+use tonic::{
+    Request, Response, Status,
+    metadata::{MetadataMap, MetadataValue},
+};
+
+use crate::{
+    application::{
+        auth::{authenticated_principal::AuthenticatedPrincipal, request_context::RequestContext},
+        errors::{
+            authentication_error::AuthenticationError, user_application_error::UserApplicationError,
+        },
+        use_cases::{
+            authenticate_api_key::AuthenticateApiKeyUseCase,
+            find_user_by_email::FindUserByEmailUseCase,
+            find_user_by_id::FindUserByIdUseCase,
+            list_users::{ListUsersUseCase, MAX_LIMIT},
+            register_user::RegisterUserUseCase,
+        },
+    },
+    domain::{
+        entities::user::User,
+        services::pii_redactor::{PiiField, redact},
+        value_objects::user_cursor::UserCursor,
+    },
+    infrastructure::{
+        auth::jwt_validator::JwtValidator,
+        metrics::app_metrics::observed,
+        repositories::{
+            caching_user_repository::CachedPostgresUserRepository,
+            postgres_api_key_repository::PostgresApiKeyRepository,
+        },
+    },
+    presentation::{
+        dtos::user_dto::LoadedUserDTO,
+        grpc::{
+            messages::datetime,
+            proto::{
+                self, GetUserByEmailRequest, GetUserRequest, ListUsersRequest, RegisterUserRequest,
+                RegisterUserResponse,
+                user_service_server::{UserService, UserServiceServer},
+            },
+        },
+        middlewares::{
+            auth_middleware::{API_KEY_HEADER, parse_bearer},
+            request_id_middleware::{accept_request_id, generate_request_id},
+        },
+    },
+};
+
+const AUTHORIZATION_KEY: &str = "authorization";
+const REQUEST_ID_KEY: &str = "x-request-id";
+
+pub struct UserGrpcService {
+    user_repo: Arc<CachedPostgresUserRepository>,
+    api_key_repo: Arc<PostgresApiKeyRepository>,
+    jwt_validator: Arc<JwtValidator>,
+}
+
+impl UserGrpcService {
+    pub fn new(
+        user_repo: Arc<CachedPostgresUserRepository>,
+        api_key_repo: Arc<PostgresApiKeyRepository>,
+        jwt_validator: Arc<JwtValidator>,
+    ) -> Self {
+        Self {
+            user_repo,
+            api_key_repo,
+            jwt_validator,
+        }
+    }
+
+    pub fn into_server(self) -> UserServiceServer<Self> {
+        UserServiceServer::new(self)
+    }
+
+    async fn authenticate(
+        &self,
+        metadata: &MetadataMap,
+    ) -> Result<AuthenticatedPrincipal, AuthenticationError> {
+        if let Some(key) = metadata.get(API_KEY_HEADER) {
+            let key = key
+                .to_str()
+                .map_err(|err| AuthenticationError::InvalidCredentials(err.to_string()))?;
+
+            return AuthenticateApiKeyUseCase::new(self.api_key_repo.clone())
+                .execute(key)
+                .await;
+        }
+
+        let value = metadata
+            .get(AUTHORIZATION_KEY)
+            .ok_or(AuthenticationError::MissingCredentials)?
+            .to_str()
+            .map_err(|err| AuthenticationError::InvalidCredentials(err.to_string()))?;
+
+        parse_bearer(value).and_then(|token| self.jwt_validator.validate(token))
+    }
+
+    async fn call<T, R, F>(&self, request: Request<T>, handle: F) -> Result<Response<R>, Status>
+    where
+        F: AsyncFnOnce(AuthenticatedPrincipal, T) -> Result<R, UserApplicationError>,
+    {
+        let request_id = request
+            .metadata()
+            .get(REQUEST_ID_KEY)
+            .and_then(|value| value.to_str().ok())
+            .and_then(accept_request_id)
+            .unwrap_or_else(generate_request_id);
+
+        let result = match self.authenticate(request.metadata()).await {
+            Ok(principal) => {
+                RequestContext::new(principal.subject.clone(), Some(request_id.clone()))
+                    .scope(handle(principal, request.into_inner()))
+                    .await
+                    .map(Response::new)
+                    .map_err(Status::from)
+            }
+            Err(err) => Err(Status::from(err)),
+        };
+
+        let request_id = MetadataValue::try_from(request_id).map_err(|err| {
+            Status::internal(format!("The request id could not be echoed: {err}"))
+        })?;
+
+        match result {
+            Ok(mut response) => {
+                response.metadata_mut().insert(REQUEST_ID_KEY, request_id);
+                Ok(response)
+            }
+            Err(mut status) => {
+                status.metadata_mut().insert(REQUEST_ID_KEY, request_id);
+                Err(status)
+            }
+        }
+    }
+}
+
+fn found(
+    user: Option<User>,
+    not_found: impl ToString,
+) -> Result<proto::User, UserApplicationError> {
+    user.and_then(Option::<LoadedUserDTO>::from)
+        .map(proto::User::from)
+        .ok_or_else(|| UserApplicationError::NotFound(not_found.to_string()))
+}
+
+#[tonic::async_trait]
+impl UserService for UserGrpcService {
+    type ListUsersStream = BoxStream<'static, Result<proto::User, Status>>;
+
+    async fn register_user(
+        &self,
+        request: Request<RegisterUserRequest>,
+    ) -> Result<Response<RegisterUserResponse>, Status> {
+        self.call(request, async |principal, request| {
+            observed(
+                "register_user",
+                RegisterUserUseCase::new(self.user_repo.clone())
+                    .execute(&principal, request.into()),
+            )
+            .await
+            .map(|id| RegisterUserResponse { id })
+        })
+        .await
+    }
+
+    async fn get_user(
+        &self,
+        request: Request<GetUserRequest>,
+    ) -> Result<Response<proto::User>, Status> {
+        self.call(request, async |principal, request| {
+            observed(
+                "find_user_by_id",
+                FindUserByIdUseCase::new(self.user_repo.clone()).execute(&principal, request.id),
+            )
+            .await
+            .and_then(|user| found(user, request.id))
+        })
+        .await
+    }
+
+    async fn get_user_by_email(
+        &self,
+        request: Request<GetUserByEmailRequest>,
+    ) -> Result<Response<proto::User>, Status> {
+        self.call(request, async |principal, request| {
+            let not_found = format!("by email {}", redact(PiiField::Email, &request.email));
+
+            observed(
+                "find_user_by_email",
+                FindUserByEmailUseCase::new(self.user_repo.clone())
+                    .execute(&principal, request.email),
+            )
+            .await
+            .and_then(|user| found(user, not_found))
+        })
+        .await
+    }
+
+    async fn list_users(
+        &self,
+        request: Request<ListUsersRequest>,
+    ) -> Result<Response<Self::ListUsersStream>, Status> {
+        self.call(request, async |principal, request| {
+            let created_from = datetime("created_from", request.created_from)?;
+            let created_to = datetime("created_to", request.created_to)?;
+
+            let user_repo = self.user_repo.clone();
+            let list = move |principal: AuthenticatedPrincipal, after| {
+                let user_repo = user_repo.clone();
+
+                async move {
+                    observed(
+                        "list_users",
+                        ListUsersUseCase::new(user_repo).execute(
+                            &principal,
+                            created_from,
+                            created_to,
+                            after,
+                            Some(MAX_LIMIT),
+                        ),
+                    )
+                    .await
+                }
+            };
+
+            // The first page is read up front so that a refused or invalid
+            // listing fails the call rather than the stream.
+            let first = list(principal.clone(), None).await?;
+
+            let pages = stream::try_unfold((Some(first), None), move |(page, after)| {
+                let list = list.clone();
+                let principal = principal.clone();
+
+                async move {
+                    let users = match (page, after) {
+                        (Some(users), _) => users,
+                        (None, Some(after)) => list(principal, Some(after)).await?,
+                        (None, None) => return Ok::<_, UserApplicationError>(None),
+                    };
+
+                    let after = match users.last() {
+                        Some(last) if users.len() as i64 == MAX_LIMIT => UserCursor::of(last),
+                        _ => None,
+                    };
+
+                    Ok(Some((users, (None, after))))
+                }
+            });
+
+            Ok(pages
+                .flat_map(|page| {
+                    stream::iter(match page {
+                        Ok(users) => users
+                            .into_iter()
+                            .filter_map(Option::<LoadedUserDTO>::from)
+                            .map(|user| Ok(proto::User::from(user)))
+                            .collect(),
+                        Err(err) => vec![Err(Status::from(err))],
+                    })
+                })
+                .boxed())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use diesel::{PgConnection, r2d2::ConnectionManager};
+    use hyper_util::rt::TokioIo;
+    use jsonwebtoken::{EncodingKey, Header, encode};
+    use prost_types::Timestamp;
+    use serde_json::{Value, json};
+    use tonic::{
+        Code, Request,
+        transport::{Channel, Endpoint, Server, Uri},
+    };
+    use tower::service_fn;
+
+    use crate::{
+        infrastructure::{
+            auth::jwt_validator::JwtValidator, bootstrap::user_repository,
+            repositories::postgres_api_key_repository::PostgresApiKeyRepository,
+        },
+        presentation::grpc::proto::{
+            ListUsersRequest, RegisterUserRequest, user_service_client::UserServiceClient,
+        },
+    };
+
+    use super::UserGrpcService;
+
+    const SECRET: &[u8] = b"super-secret";
+
+    async fn client() -> UserServiceClient<Channel> {
+        let pool = r2d2::Pool::builder()
+            .build_unchecked(ConnectionManager::<PgConnection>::new("postgres://unused"));
+        let service = UserGrpcService::new(
+            Arc::new(user_repository(pool.clone())),
+            Arc::new(PostgresApiKeyRepository::new(pool)),
+            Arc::new(JwtValidator::new(None, None).with_hs256_secret(SECRET)),
+        );
+
+        let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+
+        tokio::spawn(
+            Server::builder()
+                .add_service(service.into_server())
+                .serve_with_incoming(tokio_stream::once(Ok::<_, std::io::Error>(server_io))),
+        );
+
+        let mut client_io = Some(client_io);
+        let channel = Endpoint::from_static("http://in-process")
+            .connect_with_connector(service_fn(move |_: Uri| {
+                let client_io = client_io.take();
+
+                async move {
+                    client_io
+                        .map(TokioIo::new)
+                        .ok_or_else(|| std::io::Error::other("The channel is already connected"))
+                }
+            }))
+            .await
+            .unwrap();
+
+        UserServiceClient::new(channel)
+    }
+
+    fn authorized<T>(message: T, claims: Value) -> Request<T> {
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(SECRET),
+        )
+        .unwrap();
+
+        let mut request = Request::new(message);
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        request
+    }
+
+    fn admin<T>(message: T) -> Request<T> {
+        authorized(
+            message,
+            json!({ "sub": "1", "roles": ["admin"], "exp": 4102444800u64 }),
+        )
+    }
+
+    fn register_request(email: &str) -> RegisterUserRequest {
+        RegisterUserRequest {
+            name: "Jane Doe".to_string(),
+            email: email.to_string(),
+            phone: "+1 555 0100".to_string(),
+            address: "1 Main St.".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn missing_credentials_are_unauthenticated() {
+        let mut client = client().await;
+
+        let status = client
+            .register_user(register_request("jane@example.com"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::Unauthenticated);
+        assert_eq!(
+            status.message(),
+            "No credentials were given for the request"
+        );
+        assert_eq!(status.metadata().get("x-request-id").unwrap().len(), 32);
+    }
+
+    #[tokio::test]
+    async fn invalid_bearer_token_is_unauthenticated() {
+        let mut client = client().await;
+
+        let mut request = Request::new(register_request("jane@example.com"));
+        request
+            .metadata_mut()
+            .insert("authorization", "Bearer not-a-jwt".parse().unwrap());
+
+        let status = client.register_user(request).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn register_user_without_the_role_is_permission_denied() {
+        let mut client = client().await;
+
+        let request = authorized(
+            register_request("jane@example.com"),
+            json!({ "sub": "42", "exp": 4102444800u64 }),
+        );
+
+        let status = client.register_user(request).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn register_user_with_invalid_input_echoes_the_request_id() {
+        let mut client = client().await;
+
+        let mut request = admin(register_request("not-an-email"));
+        request
+            .metadata_mut()
+            .insert("x-request-id", "req-1".parse().unwrap());
+
+        let status = client.register_user(request).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert!(status.message().starts_with("The user input is invalid"));
+        assert_eq!(status.metadata().get("x-request-id").unwrap(), "req-1");
+    }
+
+    #[tokio::test]
+    async fn list_users_rejects_an_inverted_range() {
+        let mut client = client().await;
+
+        let request = admin(ListUsersRequest {
+            created_from: Some(Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 0,
+            }),
+            created_to: Some(Timestamp {
+                seconds: 1_600_000_000,
+                nanos: 0,
+            }),
+        });
+
+        let status = client.list_users(request).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn list_users_rejects_an_invalid_timestamp() {
+        let mut client = client().await;
+
+        let request = admin(ListUsersRequest {
+            created_from: Some(Timestamp {
+                seconds: 0,
+                nanos: -1,
+            }),
+            created_to: None,
+        });
+
+        let status = client.list_users(request).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(
+            status.message(),
+            "The user input is invalid: created_from is not a valid timestamp"
+        );
+    }
+
+    #[tokio::test]
+    async fn list_users_is_open_to_support() {
+        let mut client = client().await;
+
+        let request = authorized(
+            ListUsersRequest {
+                created_from: Some(Timestamp {
+                    seconds: 1_700_000_000,
+                    nanos: 0,
+                }),
+                created_to: Some(Timestamp {
+                    seconds: 1_600_000_000,
+                    nanos: 0,
+                }),
+            },
+            json!({ "sub": "7", "roles": ["support"], "exp": 4102444800u64 }),
+        );
+
+        let status = client.list_users(request).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn list_users_without_the_role_is_permission_denied() {
+        let mut client = client().await;
+
+        let request = authorized(
+            ListUsersRequest {
+                created_from: None,
+                created_to: None,
+            },
+            json!({ "sub": "42", "exp": 4102444800u64 }),
+        );
+
+        let status = client.list_users(request).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::PermissionDenied);
+    }
+}
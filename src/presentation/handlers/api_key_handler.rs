@@ -0,0 +1,74 @@
+use crate::{
+    application::{
+        auth::authenticated_principal::AuthenticatedPrincipal,
+        use_cases::{
+            create_api_key::CreateApiKeyUseCase, list_api_keys::ListApiKeysUseCase,
+            revoke_api_key::RevokeApiKeyUseCase,
+        },
+    },
+    infrastructure::repositories::postgres_api_key_repository::PostgresApiKeyRepository,
+    presentation::{
+        dtos::api_key_dto::{CreateApiKeyDTO, CreatedApiKeyDTO, LoadedApiKeyDTO},
+        errors::api_key_http_error::ApiKeyHttpError,
+    },
+};
+use actix_web::{
+    HttpResponse, ResponseError, delete, get, post,
+    web::{self, Path},
+};
+
+#[post("")]
+pub async fn create_api_key_handler(
+    repo: web::Data<PostgresApiKeyRepository>,
+    principal: AuthenticatedPrincipal,
+    input: web::Json<CreateApiKeyDTO>,
+) -> HttpResponse {
+    match CreateApiKeyUseCase::new(repo.into_inner())
+        .execute(&principal, input.into_inner())
+        .await
+    {
+        Ok((api_key, key)) => {
+            let api_key: Option<LoadedApiKeyDTO> = api_key.into();
+            match api_key {
+                Some(api_key) => HttpResponse::Created().json(CreatedApiKeyDTO { key, api_key }),
+                None => HttpResponse::InternalServerError().finish(),
+            }
+        }
+        Err(err) => ApiKeyHttpError::from(err).error_response(),
+    }
+}
+
+#[get("")]
+pub async fn list_api_keys_handler(
+    repo: web::Data<PostgresApiKeyRepository>,
+    principal: AuthenticatedPrincipal,
+) -> HttpResponse {
+    match ListApiKeysUseCase::new(repo.into_inner())
+        .execute(&principal)
+        .await
+    {
+        Ok(api_keys) => {
+            let api_keys: Vec<LoadedApiKeyDTO> = api_keys
+                .into_iter()
+                .filter_map(|api_key| api_key.into())
+                .collect();
+            HttpResponse::Ok().json(api_keys)
+        }
+        Err(err) => ApiKeyHttpError::from(err).error_response(),
+    }
+}
+
+#[delete("/{id}")]
+pub async fn revoke_api_key_handler(
+    repo: web::Data<PostgresApiKeyRepository>,
+    principal: AuthenticatedPrincipal,
+    path: Path<i32>,
+) -> HttpResponse {
+    match RevokeApiKeyUseCase::new(repo.into_inner())
+        .execute(&principal, path.into_inner())
+        .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(err) => ApiKeyHttpError::from(err).error_response(),
+    }
+}
@@ -0,0 +1,88 @@
+use actix_web::{HttpResponse, get, post, web};
+use async_graphql::http::GraphiQLSource;
+
+use crate::{
+    application::auth::authenticated_principal::AuthenticatedPrincipal,
+    presentation::graphql::schema::UserSchema,
+};
+
+#[post("")]
+pub async fn graphql_handler(
+    schema: web::Data<UserSchema>,
+    principal: AuthenticatedPrincipal,
+    request: web::Json<async_graphql::Request>,
+) -> HttpResponse {
+    HttpResponse::Ok().json(schema.execute(request.into_inner().data(principal)).await)
+}
+
+#[get("/graphiql")]
+pub async fn graphiql_handler() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(GraphiQLSource::build().endpoint("/graphql").finish())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use actix_web::{App, HttpMessage, http::StatusCode, test, web};
+    use diesel::{PgConnection, r2d2::ConnectionManager};
+    use serde_json::{Value, json};
+
+    use crate::{
+        application::auth::authenticated_principal::AuthenticatedPrincipal,
+        infrastructure::bootstrap::user_repository,
+        presentation::graphql::schema::{UserSchema, build_schema},
+    };
+
+    use super::{graphiql_handler, graphql_handler};
+
+    fn schema() -> web::Data<UserSchema> {
+        let pool = r2d2::Pool::builder()
+            .build_unchecked(ConnectionManager::<PgConnection>::new("postgres://unused"));
+
+        web::Data::new(build_schema(Arc::new(user_repository(pool))))
+    }
+
+    #[actix_web::test]
+    async fn graphql_errors_carry_an_extension_code() {
+        let app = test::init_service(
+            App::new()
+                .app_data(schema())
+                .service(web::scope("/graphql").service(graphql_handler)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/graphql")
+            .set_json(json!({ "query": "{ users(first: 0) { edges { cursor } } }" }))
+            .to_request();
+        req.extensions_mut().insert(AuthenticatedPrincipal::new(
+            "1".to_string(),
+            None,
+            vec!["admin".to_string()],
+        ));
+
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(result.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(result).await;
+
+        assert_eq!(body["data"], Value::Null);
+        assert_eq!(body["errors"][0]["extensions"]["code"], "BAD_USER_INPUT");
+        assert_eq!(body["errors"][0]["path"], json!(["users"]));
+    }
+
+    #[actix_web::test]
+    async fn graphiql_points_at_the_endpoint() {
+        let app = test::init_service(App::new().service(graphiql_handler)).await;
+
+        let req = test::TestRequest::get().uri("/graphiql").to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        let body = String::from_utf8_lossy(&body);
+
+        assert!(body.contains("/graphql"));
+    }
+}
@@ -0,0 +1,87 @@
+use actix_web::{HttpResponse, get, web};
+use serde_json::json;
+
+use crate::infrastructure::health::readiness::{HealthStatus, Readiness};
+
+#[get("/live")]
+pub async fn liveness_handler() -> HttpResponse {
+    HttpResponse::Ok().json(json!({ "status": HealthStatus::Up }))
+}
+
+#[get("/ready")]
+pub async fn readiness_handler(readiness: web::Data<Readiness>) -> HttpResponse {
+    let report = readiness.report().await;
+
+    match report.status {
+        HealthStatus::Up => HttpResponse::Ok().json(report),
+        HealthStatus::Down => HttpResponse::ServiceUnavailable().json(report),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, time::Duration};
+
+    use actix_web::{App, http::StatusCode, test, web};
+    use serde_json::Value;
+
+    use crate::infrastructure::health::{
+        health_check::{HealthCheck, MockHealthCheck},
+        readiness::Readiness,
+    };
+
+    use super::{liveness_handler, readiness_handler};
+
+    fn failing_database() -> Arc<dyn HealthCheck> {
+        let mut mock = MockHealthCheck::new();
+
+        mock.expect_name().return_const("database".to_string());
+        mock.expect_check()
+            .return_const(Err("Connection refused".to_string()));
+
+        Arc::new(mock)
+    }
+
+    #[actix_web::test]
+    async fn liveness_does_not_depend_on_checks() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(Readiness::new(
+                    vec![failing_database()],
+                    Duration::from_secs(1),
+                )))
+                .service(web::scope("/health").service(liveness_handler)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/health/live").to_request();
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(result.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn readiness_reports_failing_checks() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(Readiness::new(
+                    vec![failing_database()],
+                    Duration::from_secs(1),
+                )))
+                .service(web::scope("/health").service(readiness_handler)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/health/ready").to_request();
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(result.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let body: Value = test::read_body_json(result).await;
+
+        assert_eq!(body["status"], "down");
+        assert_eq!(body["checks"][0]["name"], "database");
+        assert_eq!(body["checks"][0]["error"], "Connection refused");
+        assert!(body["checks"][0]["latency_ms"].is_number());
+    }
+}
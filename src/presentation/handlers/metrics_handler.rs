@@ -0,0 +1,22 @@
+use actix_web::{HttpResponse, get, web};
+
+use crate::infrastructure::{
+    db::connection::DBPool, metrics::app_metrics::metrics,
+    repositories::caching_user_repository::CachedPostgresUserRepository,
+};
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[get("/metrics")]
+pub async fn metrics_handler(
+    pool: web::Data<DBPool>,
+    repo: web::Data<CachedPostgresUserRepository>,
+) -> HttpResponse {
+    metrics().observe_pool_state(pool.state());
+    metrics().observe_user_cache(repo.stats());
+
+    match metrics().render() {
+        Ok(body) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(body),
+        Err(err) => HttpResponse::InternalServerError().json(err),
+    }
+}
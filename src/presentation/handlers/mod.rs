@@ -1 +1,5 @@
+pub mod api_key_handler;
+pub mod graphql_handler;
+pub mod health_handler;
+pub mod metrics_handler;
 pub mod user_handler;
@@ -1,20 +1,73 @@
 use crate::{
-    application::use_cases::{
-        find_user_by_email::FindUserByEmailUseCase, register_user::RegisterUserUseCase,
+    application::{
+        auth::authenticated_principal::AuthenticatedPrincipal,
+        errors::user_application_error::UserApplicationError,
+        use_cases::{
+            change_email::ChangeEmailUseCase,
+            erase_user::EraseUserUseCase,
+            export_user_data::ExportUserDataUseCase,
+            export_users::ExportUsersUseCase,
+            find_duplicate_users::FindDuplicateUsersUseCase,
+            find_user_by_email::FindUserByEmailUseCase,
+            get_user_history::GetUserHistoryUseCase,
+            import_users::{IMPORT_BATCH_SIZE, ImportUsersUseCase},
+            list_users::ListUsersUseCase,
+            merge_users::MergeUsersUseCase,
+            register_user::RegisterUserUseCase,
+            search_users::SearchUsersUseCase,
+        },
+    },
+    domain::{
+        services::pii_redactor::{PiiField, redact},
+        value_objects::duplicate_candidate::DuplicateCandidate,
+    },
+    infrastructure::{
+        metrics::app_metrics::observed,
+        notifications::smtp_email_notifier::SmtpEmailNotifier,
+        repositories::{
+            caching_user_repository::CachedPostgresUserRepository,
+            postgres_email_change_repository::PostgresEmailChangeRepository,
+            postgres_user_audit_repository::PostgresUserAuditRepository,
+        },
     },
-    infrastructure::repositories::postgres_user_repository::PostgresUserRepository,
     presentation::{
-        dtos::user_dto::{CreateUserDTO, LoadedUserDTO},
+        dtos::error_dto::{ErrorDTO, ProblemDTO},
+        dtos::user_dto::{
+            ChangeEmailDTO, ConfirmEmailChangeDTO, CreateUserDTO, ListUsersQuery, LoadedUserDTO,
+            MergeUsersDTO, SearchUsersQuery, UserAuditEntryDTO, UserDataExportDTO,
+            UserSearchHitDTO,
+        },
+        dtos::user_export_dto::{ExportUsersQuery, UserExportEncoder, encode_export},
+        dtos::user_import_dto::{
+            BodyReader, ImportRows, UserImportReportDTO, parse_csv, parse_ndjson,
+        },
         errors::user_http_error::UserHttpError,
     },
     schema::users,
 };
 use actix_web::{
-    HttpResponse, ResponseError, get, post,
+    HttpMessage, HttpRequest, HttpResponse, ResponseError, get,
+    http::header::{CONTENT_LENGTH, ContentDisposition, DispositionParam, DispositionType},
+    post,
     web::{self, Path},
 };
 use diesel::prelude::Insertable;
+use futures_util::{StreamExt, future::join};
 use serde::Deserialize;
+use std::io;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use utoipa::IntoParams;
+
+const IMPORT_BODY_LIMIT: usize = 16 * 1024 * 1024;
+const IMPORT_CHUNK_BUFFER: usize = 4;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ImportUsersQuery {
+    #[serde(default)]
+    pub dry_run: bool,
+}
 
 #[derive(Debug, Clone, Deserialize, Insertable)]
 #[diesel(table_name = users)]
@@ -25,30 +78,308 @@ pub struct NewUser {
     pub address: String,
 }
 
+#[utoipa::path(
+    tag = "users",
+    responses(
+        (status = 200, description = "The id of the registered user", body = i32),
+        (status = 400, description = "The input is invalid", body = ErrorDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 422, description = "The operation conflicts with existing users", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
 #[post("")]
 pub async fn register_user_handler(
-    repo: web::Data<PostgresUserRepository>,
+    repo: web::Data<CachedPostgresUserRepository>,
+    principal: AuthenticatedPrincipal,
     input: web::Json<CreateUserDTO>,
 ) -> HttpResponse {
-    match RegisterUserUseCase::new(repo.into_inner())
-        .execute(input.into_inner())
-        .await
+    match observed(
+        "register_user",
+        RegisterUserUseCase::new(repo.into_inner()).execute(&principal, input.into_inner()),
+    )
+    .await
     {
         Ok(id) => HttpResponse::Ok().json(id),
         Err(err) => UserHttpError::from(err).error_response(),
     }
 }
 
+#[utoipa::path(
+    tag = "users",
+    params(ImportUsersQuery),
+    request_body(content((String = "text/csv"), (String = "application/x-ndjson")), description = "One user per CSV record or JSON line"),
+    responses(
+        (status = 200, description = "The outcome of every row", body = UserImportReportDTO),
+        (status = 413, description = "The import is too large, the error tells which rows were processed before the limit", body = ErrorDTO),
+        (status = 415, description = "The content type is not supported", body = ErrorDTO),
+        (status = 400, description = "The input is invalid or the upload broke off, the error tells which rows were processed before it", body = ErrorDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[post("/import")]
+pub async fn import_users_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    principal: AuthenticatedPrincipal,
+    req: HttpRequest,
+    query: web::Query<ImportUsersQuery>,
+    mut payload: web::Payload,
+) -> HttpResponse {
+    let parse: fn(BodyReader) -> ImportRows = match req.content_type() {
+        "text/csv" => parse_csv::<BodyReader>,
+        "application/x-ndjson" | "application/ndjson" => parse_ndjson::<BodyReader>,
+        other => {
+            return HttpResponse::UnsupportedMediaType().json(format!(
+                "Expected text/csv or application/x-ndjson, got {other:?}"
+            ));
+        }
+    };
+
+    if req
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|length| length.to_str().ok()?.parse::<usize>().ok())
+        .is_some_and(|length| length > IMPORT_BODY_LIMIT)
+    {
+        return HttpResponse::PayloadTooLarge().json(format!(
+            "The import must be at most {IMPORT_BODY_LIMIT} bytes"
+        ));
+    }
+
+    let (chunk_sender, chunk_receiver) = mpsc::channel(IMPORT_CHUNK_BUFFER);
+    let (row_sender, row_receiver) = mpsc::channel(IMPORT_BATCH_SIZE);
+
+    tokio::task::spawn_blocking(move || {
+        for row in parse(BodyReader::new(chunk_receiver)) {
+            if row_sender.blocking_send(row).is_err() {
+                return;
+            }
+        }
+    });
+
+    // Resolves to whether the body went over the limit, which turns the
+    // interrupted import into a 413 rather than a 400.
+    let upload = async move {
+        let mut received = 0;
+
+        while let Some(chunk) = payload.next().await {
+            let too_large = chunk.as_ref().is_ok_and(|chunk| {
+                received += chunk.len();
+                received > IMPORT_BODY_LIMIT
+            });
+            let chunk = match chunk {
+                _ if too_large => Err(io::Error::other(format!(
+                    "The import must be at most {IMPORT_BODY_LIMIT} bytes"
+                ))),
+                chunk => chunk.map_err(io::Error::other),
+            };
+            let failed = chunk.is_err();
+
+            if chunk_sender.send(chunk).await.is_err() || failed {
+                return too_large;
+            }
+        }
+
+        false
+    };
+
+    let dry_run = query.dry_run;
+
+    let (too_large, result) = join(
+        upload,
+        observed(
+            "import_users",
+            ImportUsersUseCase::new(repo.into_inner()).execute(
+                &principal,
+                ReceiverStream::new(row_receiver),
+                dry_run,
+            ),
+        ),
+    )
+    .await;
+
+    match result {
+        Ok(results) => HttpResponse::Ok().json(UserImportReportDTO::new(dry_run, results)),
+        Err(UserApplicationError::Invalid(reason)) if too_large => {
+            HttpResponse::PayloadTooLarge().json(reason)
+        }
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    params(ListUsersQuery),
+    responses(
+        (status = 200, description = "The users created in the range", body = [LoadedUserDTO]),
+        (status = 400, description = "The input is invalid", body = ErrorDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[get("")]
+pub async fn list_users_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    principal: AuthenticatedPrincipal,
+    query: web::Query<ListUsersQuery>,
+) -> HttpResponse {
+    let query = query.into_inner();
+
+    match observed(
+        "list_users",
+        ListUsersUseCase::new(repo.into_inner()).execute(
+            &principal,
+            query.created_from,
+            query.created_to,
+            None,
+            query.limit,
+        ),
+    )
+    .await
+    {
+        Ok(users) => HttpResponse::Ok().json(
+            users
+                .into_iter()
+                .filter_map(Option::<LoadedUserDTO>::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    params(SearchUsersQuery),
+    responses(
+        (status = 200, description = "The best matching users", body = [UserSearchHitDTO]),
+        (status = 400, description = "The input is invalid", body = ErrorDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[get("/search")]
+pub async fn search_users_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    principal: AuthenticatedPrincipal,
+    query: web::Query<SearchUsersQuery>,
+) -> HttpResponse {
+    match observed(
+        "search_users",
+        SearchUsersUseCase::new(repo.into_inner()).execute(&principal, &query.q, query.limit),
+    )
+    .await
+    {
+        Ok(hits) => HttpResponse::Ok().json(
+            hits.into_iter()
+                .filter_map(Option::<UserSearchHitDTO>::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    params(ExportUsersQuery),
+    responses(
+        (status = 200, description = "Every user created in the range, streamed in the requested format", content((String = "text/csv"), (String = "application/x-ndjson"), (Vec<u8> = "application/vnd.apache.parquet"))),
+        (status = 400, description = "The input is invalid", body = ErrorDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[get("/export")]
+pub async fn export_users_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    principal: AuthenticatedPrincipal,
+    query: web::Query<ExportUsersQuery>,
+) -> HttpResponse {
+    let query = query.into_inner();
+
+    let encoder = match UserExportEncoder::new(query.format) {
+        Ok(encoder) => encoder,
+        Err(err) => return err.error_response(),
+    };
+
+    match observed(
+        "export_users",
+        ExportUsersUseCase::new(repo.into_inner()).execute(
+            &principal,
+            query.created_from,
+            query.created_to,
+        ),
+    )
+    .await
+    {
+        Ok(batches) => HttpResponse::Ok()
+            .content_type(query.format.content_type())
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename(format!(
+                    "users.{}",
+                    query.format.extension()
+                ))],
+            })
+            .streaming(encode_export(batches, encoder)),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    responses(
+        (status = 200, description = "Pairs of users that are likely duplicates", body = [DuplicateCandidate]),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[get("/duplicates/candidates")]
+pub async fn find_duplicate_users_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    principal: AuthenticatedPrincipal,
+) -> HttpResponse {
+    match observed(
+        "find_duplicate_users",
+        FindDuplicateUsersUseCase::new(repo.into_inner()).execute(&principal),
+    )
+    .await
+    {
+        Ok(candidates) => HttpResponse::Ok().json(candidates),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    params(("email" = String, Path, description = "The email of the user")),
+    responses(
+        (status = 200, description = "The user", body = LoadedUserDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 404, description = "The user does not exist", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
 #[get("/{email}")]
 pub async fn get_by_email(
-    repo: web::Data<PostgresUserRepository>,
+    repo: web::Data<CachedPostgresUserRepository>,
+    principal: AuthenticatedPrincipal,
     path: Path<String>,
 ) -> HttpResponse {
     let email = path.into_inner();
 
-    let result = FindUserByEmailUseCase::new(repo.into_inner())
-        .execute(email.clone())
-        .await;
+    let result = observed(
+        "find_user_by_email",
+        FindUserByEmailUseCase::new(repo.into_inner()).execute(&principal, email.clone()),
+    )
+    .await;
 
     match result {
         Ok(user) => {
@@ -56,9 +387,303 @@ pub async fn get_by_email(
                 let loaded_user: Option<LoadedUserDTO> = user.into();
                 HttpResponse::Ok().json(loaded_user)
             } else {
-                HttpResponse::NotFound().json(format!("User not found by email: {email}"))
+                HttpResponse::NotFound().json(format!(
+                    "User not found by email: {}",
+                    redact(PiiField::Email, &email)
+                ))
             }
         }
         Err(err) => UserHttpError::from(err).error_response(),
     }
 }
+
+#[utoipa::path(
+    tag = "users",
+    params(("id" = i32, Path, description = "The id of the user")),
+    responses(
+        (status = 200, description = "Everything stored about the user", body = UserDataExportDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 404, description = "The user does not exist", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[get("/{id}/export")]
+pub async fn export_user_data_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    audit_repo: web::Data<PostgresUserAuditRepository>,
+    principal: AuthenticatedPrincipal,
+    path: Path<i32>,
+) -> HttpResponse {
+    let id = path.into_inner();
+
+    match observed(
+        "export_user_data",
+        ExportUserDataUseCase::new(repo.into_inner(), audit_repo.into_inner())
+            .execute(&principal, id),
+    )
+    .await
+    {
+        Ok(export) => HttpResponse::Ok()
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename(format!("user-{id}-export.json"))],
+            })
+            .json(UserDataExportDTO::from(export)),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    params(("id" = i32, Path, description = "The id of the user")),
+    responses(
+        (status = 204, description = "The personal data of the user was erased"),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 404, description = "The user does not exist", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[post("/{id}/erase")]
+pub async fn erase_user_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    principal: AuthenticatedPrincipal,
+    path: Path<i32>,
+) -> HttpResponse {
+    match observed(
+        "erase_user",
+        EraseUserUseCase::new(repo.into_inner()).execute(&principal, path.into_inner()),
+    )
+    .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    params(("id" = i32, Path, description = "The id of the surviving user")),
+    responses(
+        (status = 200, description = "The merged user", body = LoadedUserDTO),
+        (status = 400, description = "The input is invalid", body = ErrorDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 404, description = "The user does not exist", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[post("/{id}/merge")]
+pub async fn merge_users_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    principal: AuthenticatedPrincipal,
+    path: Path<i32>,
+    body: web::Json<MergeUsersDTO>,
+) -> HttpResponse {
+    match observed(
+        "merge_users",
+        MergeUsersUseCase::new(repo.into_inner()).execute(
+            &principal,
+            path.into_inner(),
+            body.duplicate_id,
+        ),
+    )
+    .await
+    {
+        Ok(user) => HttpResponse::Ok().json(Option::<LoadedUserDTO>::from(user)),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    params(("id" = i32, Path, description = "The id of the user")),
+    responses(
+        (status = 200, description = "The audit history of the user", body = [UserAuditEntryDTO]),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[get("/{id}/history")]
+pub async fn get_user_history_handler(
+    audit_repo: web::Data<PostgresUserAuditRepository>,
+    principal: AuthenticatedPrincipal,
+    path: Path<i32>,
+) -> HttpResponse {
+    match observed(
+        "get_user_history",
+        GetUserHistoryUseCase::new(audit_repo.into_inner()).execute(&principal, path.into_inner()),
+    )
+    .await
+    {
+        Ok(history) => HttpResponse::Ok().json(
+            history
+                .into_iter()
+                .map(UserAuditEntryDTO::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    params(("id" = i32, Path, description = "The id of the user")),
+    responses(
+        (status = 202, description = "A confirmation token was sent to the new email"),
+        (status = 400, description = "The input is invalid", body = ErrorDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 404, description = "The user does not exist", body = ErrorDTO),
+        (status = 422, description = "The operation conflicts with existing users", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[post("/{id}/email-change")]
+pub async fn request_email_change_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    email_change_repo: web::Data<PostgresEmailChangeRepository>,
+    notifier: web::Data<SmtpEmailNotifier>,
+    principal: AuthenticatedPrincipal,
+    path: Path<i32>,
+    input: web::Json<ChangeEmailDTO>,
+) -> HttpResponse {
+    match observed(
+        "request_email_change",
+        ChangeEmailUseCase::new(
+            repo.into_inner(),
+            email_change_repo.into_inner(),
+            notifier.into_inner(),
+        )
+        .request(&principal, path.into_inner(), input.into_inner().email),
+    )
+    .await
+    {
+        Ok(()) => HttpResponse::Accepted().finish(),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[utoipa::path(
+    tag = "users",
+    responses(
+        (status = 204, description = "The email was changed"),
+        (status = 400, description = "The input is invalid", body = ErrorDTO),
+        (status = 401, description = "Missing or invalid credentials", body = ProblemDTO, content_type = "application/problem+json"),
+        (status = 403, description = "The principal may not perform the operation", body = ErrorDTO),
+        (status = 404, description = "The user does not exist", body = ErrorDTO),
+        (status = 422, description = "The operation conflicts with existing users", body = ErrorDTO),
+        (status = 500, description = "Unexpected failure", body = ErrorDTO),
+    )
+)]
+#[post("/email-change/confirm")]
+pub async fn confirm_email_change_handler(
+    repo: web::Data<CachedPostgresUserRepository>,
+    email_change_repo: web::Data<PostgresEmailChangeRepository>,
+    notifier: web::Data<SmtpEmailNotifier>,
+    principal: AuthenticatedPrincipal,
+    input: web::Json<ConfirmEmailChangeDTO>,
+) -> HttpResponse {
+    match observed(
+        "confirm_email_change",
+        ChangeEmailUseCase::new(
+            repo.into_inner(),
+            email_change_repo.into_inner(),
+            notifier.into_inner(),
+        )
+        .confirm(&principal, &input.token),
+    )
+    .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(err) => UserHttpError::from(err).error_response(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::pin::Pin;
+
+    use actix_web::{
+        App, HttpMessage,
+        dev::Payload,
+        error::PayloadError,
+        http::{StatusCode, header::CONTENT_TYPE},
+        test, web,
+        web::Bytes,
+    };
+    use diesel::{PgConnection, r2d2::ConnectionManager};
+    use futures_util::{Stream, stream};
+    use serde_json::Value;
+
+    use crate::{
+        application::auth::authenticated_principal::AuthenticatedPrincipal,
+        infrastructure::bootstrap::user_repository,
+    };
+
+    use super::{IMPORT_BODY_LIMIT, import_users_handler};
+
+    type Chunks = Pin<Box<dyn Stream<Item = Result<Bytes, PayloadError>>>>;
+
+    // Every line is malformed, so no row reaches the repository and the pool
+    // never has to connect.
+    async fn import(chunks: Chunks) -> (StatusCode, Value) {
+        let pool = r2d2::Pool::builder()
+            .build_unchecked(ConnectionManager::<PgConnection>::new("postgres://unused"));
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(user_repository(pool)))
+                .service(import_users_handler),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/import")
+            .insert_header((CONTENT_TYPE, "application/x-ndjson"))
+            .to_request();
+        req.extensions_mut().insert(AuthenticatedPrincipal::new(
+            "1".to_string(),
+            None,
+            vec!["support".to_string()],
+        ));
+        let (req, _) = req.replace_payload(Payload::from(chunks));
+
+        let result = test::call_service(&app, req).await;
+
+        (result.status(), test::read_body_json(result).await)
+    }
+
+    #[actix_web::test]
+    async fn import_over_the_limit_while_streaming_is_too_large() {
+        let line = Bytes::from(format!("{}\n", "x".repeat(1024 * 1024 - 1)));
+        let chunks =
+            stream::iter((0..=IMPORT_BODY_LIMIT / line.len()).map(move |_| Ok(line.clone())));
+
+        let (status, body) = import(Box::pin(chunks)).await;
+
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(
+            body,
+            format!("The import must be at most {IMPORT_BODY_LIMIT} bytes. No row was imported")
+        );
+    }
+
+    #[actix_web::test]
+    async fn import_with_a_broken_payload_is_a_bad_request() {
+        let chunks = stream::iter([
+            Ok(Bytes::from_static(b"{}\n")),
+            Err(PayloadError::Incomplete(None)),
+        ]);
+
+        let (status, body) = import(Box::pin(chunks)).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(
+            body.as_str()
+                .is_some_and(|message| message.ends_with("No row was imported")),
+            "{body}"
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use actix_web::{
+    Error, HttpMessage, ResponseError,
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{self, HeaderMap},
+    middleware::Next,
+    web,
+};
+
+use crate::{
+    application::{
+        auth::{authenticated_principal::AuthenticatedPrincipal, request_context::RequestContext},
+        errors::authentication_error::AuthenticationError,
+        use_cases::authenticate_api_key::AuthenticateApiKeyUseCase,
+    },
+    infrastructure::{
+        auth::jwt_validator::JwtValidator,
+        repositories::postgres_api_key_repository::PostgresApiKeyRepository,
+    },
+    presentation::{
+        errors::auth_http_error::AuthHttpError,
+        middlewares::request_id_middleware::REQUEST_ID_HEADER,
+    },
+};
+
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+
+fn bearer_token(headers: &HeaderMap) -> Result<&str, AuthenticationError> {
+    let value = headers
+        .get(header::AUTHORIZATION)
+        .ok_or(AuthenticationError::MissingCredentials)?
+        .to_str()
+        .map_err(|err| AuthenticationError::InvalidCredentials(err.to_string()))?;
+
+    parse_bearer(value)
+}
+
+pub fn parse_bearer(value: &str) -> Result<&str, AuthenticationError> {
+    match value.split_once(' ') {
+        Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") && !token.is_empty() => {
+            Ok(token.trim())
+        }
+        _ => Err(AuthenticationError::InvalidCredentials(
+            "Expected a Bearer authorization scheme".to_string(),
+        )),
+    }
+}
+
+fn authenticate_bearer(req: &ServiceRequest) -> Result<AuthenticatedPrincipal, AuthHttpError> {
+    let validator = req
+        .app_data::<web::Data<JwtValidator>>()
+        .ok_or_else(|| AuthHttpError::Unauthorized("No JWT validator configured".to_string()))?;
+
+    bearer_token(req.headers())
+        .and_then(|token| validator.validate(token))
+        .map_err(AuthHttpError::from)
+}
+
+async fn authenticate_api_key(
+    req: &ServiceRequest,
+    key: &str,
+) -> Result<AuthenticatedPrincipal, AuthHttpError> {
+    let repo = req
+        .app_data::<web::Data<PostgresApiKeyRepository>>()
+        .ok_or_else(|| {
+            AuthHttpError::Unauthorized("API key authentication is not configured".to_string())
+        })?;
+
+    AuthenticateApiKeyUseCase::new(repo.clone().into_inner())
+        .execute(key)
+        .await
+        .map_err(AuthHttpError::from)
+}
+
+pub async fn auth_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let api_key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .map(|value| value.to_str().map(str::to_string));
+
+    let principal = match api_key {
+        Some(Ok(key)) => authenticate_api_key(&req, &key).await,
+        Some(Err(err)) => Err(AuthenticationError::InvalidCredentials(err.to_string()).into()),
+        None => authenticate_bearer(&req),
+    };
+
+    match principal {
+        Ok(principal) => {
+            let request_id = RequestContext::current().request_id.or_else(|| {
+                req.headers()
+                    .get(REQUEST_ID_HEADER)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_string)
+            });
+
+            let context = RequestContext::new(principal.subject.clone(), request_id);
+
+            req.extensions_mut().insert(principal);
+            context
+                .scope(next.call(req))
+                .await
+                .map(ServiceResponse::map_into_left_body)
+        }
+        Err(err) => Ok(req.into_response(err.error_response().map_into_right_body())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{
+        App, HttpResponse,
+        http::{StatusCode, header},
+        middleware::from_fn,
+        test, web,
+    };
+    use jsonwebtoken::{EncodingKey, Header, encode};
+    use serde_json::json;
+
+    use crate::{
+        application::auth::{
+            authenticated_principal::AuthenticatedPrincipal, request_context::RequestContext,
+        },
+        infrastructure::auth::jwt_validator::JwtValidator,
+    };
+
+    use super::auth_middleware;
+
+    const SECRET: &[u8] = b"super-secret";
+
+    async fn whoami(principal: AuthenticatedPrincipal) -> HttpResponse {
+        HttpResponse::Ok().body(principal.subject)
+    }
+
+    async fn context() -> HttpResponse {
+        let context = RequestContext::current();
+
+        HttpResponse::Ok().body(format!(
+            "{}/{}",
+            context.actor,
+            context.request_id.unwrap_or_default()
+        ))
+    }
+
+    fn app() -> App<
+        impl actix_web::dev::ServiceFactory<
+            actix_web::dev::ServiceRequest,
+            Config = (),
+            Response = actix_web::dev::ServiceResponse<impl actix_web::body::MessageBody>,
+            Error = actix_web::Error,
+            InitError = (),
+        >,
+    > {
+        App::new()
+            .app_data(web::Data::new(
+                JwtValidator::new(None, None).with_hs256_secret(SECRET),
+            ))
+            .service(
+                web::scope("/protected")
+                    .wrap(from_fn(auth_middleware))
+                    .route("", web::get().to(whoami))
+                    .route("/context", web::get().to(context)),
+            )
+    }
+
+    #[actix_web::test]
+    async fn missing_authorization_header() {
+        let app = test::init_service(app()).await;
+
+        let req = test::TestRequest::get().uri("/protected").to_request();
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            result.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+    }
+
+    #[actix_web::test]
+    async fn non_bearer_authorization_header() {
+        let app = test::init_service(app()).await;
+
+        let req = test::TestRequest::get()
+            .uri("/protected")
+            .insert_header((header::AUTHORIZATION, "Basic YWRtaW46YWRtaW4="))
+            .to_request();
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn invalid_token() {
+        let app = test::init_service(app()).await;
+
+        let req = test::TestRequest::get()
+            .uri("/protected")
+            .insert_header((header::AUTHORIZATION, "Bearer not-a-jwt"))
+            .to_request();
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn api_key_without_repository() {
+        let app = test::init_service(app()).await;
+
+        let req = test::TestRequest::get()
+            .uri("/protected")
+            .insert_header(("X-Api-Key", "ak_0123abcdef"))
+            .to_request();
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn valid_token() {
+        let app = test::init_service(app()).await;
+
+        let token = encode(
+            &Header::default(),
+            &json!({ "sub": "42", "exp": 4102444800u64 }),
+            &EncodingKey::from_secret(SECRET),
+        )
+        .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri("/protected")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+        let result = test::call_and_read_body(&app, req).await;
+
+        assert_eq!(result, "42");
+    }
+
+    #[actix_web::test]
+    async fn valid_token_scopes_the_request_context() {
+        let app = test::init_service(app()).await;
+
+        let token = encode(
+            &Header::default(),
+            &json!({ "sub": "42", "exp": 4102444800u64 }),
+            &EncodingKey::from_secret(SECRET),
+        )
+        .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri("/protected/context")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {token}")))
+            .insert_header(("X-Request-Id", "req-1"))
+            .to_request();
+        let result = test::call_and_read_body(&app, req).await;
+
+        assert_eq!(result, "42/req-1");
+    }
+}
@@ -0,0 +1,260 @@
+use actix_web::{
+    Error, HttpResponse,
+    body::{BoxBody, MessageBody, to_bytes},
+    dev::{ServiceRequest, ServiceResponse},
+    error::InternalError,
+    http::header::{self, HeaderMap, HeaderName, HeaderValue},
+    middleware::Next,
+};
+use serde_json::{Value, json};
+use tracing::{Span, error};
+
+use crate::application::auth::request_context::RequestContext;
+
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+const PROBLEM_JSON: &str = "application/problem+json";
+
+fn accepted_request_id(headers: &HeaderMap) -> Option<String> {
+    accept_request_id(headers.get(REQUEST_ID_HEADER)?.to_str().ok()?)
+}
+
+pub fn accept_request_id(value: &str) -> Option<String> {
+    let valid = !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LENGTH
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'));
+
+    valid.then(|| value.to_string())
+}
+
+pub fn generate_request_id() -> String {
+    hex::encode(rand::random::<[u8; 16]>())
+}
+
+fn error_body(body: &[u8], reason: &str, request_id: &str) -> Value {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(Value::Object(mut fields)) => {
+            fields.insert("request_id".to_string(), json!(request_id));
+            Value::Object(fields)
+        }
+        Ok(Value::String(message)) => json!({ "error": message, "request_id": request_id }),
+        Ok(other) => json!({ "error": other, "request_id": request_id }),
+        Err(_) if body.is_empty() => json!({ "error": reason, "request_id": request_id }),
+        Err(_) => json!({
+            "error": String::from_utf8_lossy(body),
+            "request_id": request_id,
+        }),
+    }
+}
+
+async fn with_request_id(res: HttpResponse<BoxBody>, request_id: &str) -> HttpResponse<BoxBody> {
+    let status = res.status();
+
+    let mut res = if status.is_client_error() || status.is_server_error() {
+        let is_problem = res
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .is_some_and(|value| value == PROBLEM_JSON);
+
+        let (mut res, body) = res.into_parts();
+        let body = to_bytes(body).await.unwrap_or_default();
+        let body = error_body(
+            &body,
+            status.canonical_reason().unwrap_or_default(),
+            request_id,
+        );
+
+        if status.is_server_error()
+            && let Some(error) = body["error"].as_str()
+        {
+            error!(status = status.as_u16(), error, "Request failed");
+        }
+
+        let content_type = if is_problem {
+            PROBLEM_JSON
+        } else {
+            "application/json"
+        };
+        res.headers_mut()
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+
+        res.set_body(BoxBody::new(body.to_string()))
+    } else {
+        res
+    };
+
+    if let Ok(value) = HeaderValue::from_str(request_id) {
+        res.headers_mut()
+            .insert(HeaderName::from_static("x-request-id"), value);
+    }
+
+    res
+}
+
+pub async fn request_id_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    let request_id = accepted_request_id(req.headers()).unwrap_or_else(generate_request_id);
+
+    Span::current().record("request_id", request_id.as_str());
+
+    match RequestContext::anonymous(request_id.clone())
+        .scope(next.call(req))
+        .await
+    {
+        Ok(res) => {
+            let (req, res) = res.map_into_boxed_body().into_parts();
+
+            Ok(ServiceResponse::new(
+                req,
+                with_request_id(res, &request_id).await,
+            ))
+        }
+        Err(err) => {
+            let res = with_request_id(err.error_response(), &request_id).await;
+
+            Err(InternalError::from_response(err, res).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{
+        App, HttpResponse,
+        http::{StatusCode, header},
+        middleware::from_fn,
+        test, web,
+    };
+    use serde_json::{Value, json};
+
+    use crate::{
+        application::auth::request_context::RequestContext,
+        presentation::errors::user_http_error::UserHttpError,
+    };
+
+    use super::request_id_middleware;
+
+    async fn context() -> HttpResponse {
+        HttpResponse::Ok().body(RequestContext::current().request_id.unwrap_or_default())
+    }
+
+    async fn failing() -> Result<HttpResponse, UserHttpError> {
+        Err(UserHttpError::Internal("Database error".to_string()))
+    }
+
+    async fn problem() -> HttpResponse {
+        HttpResponse::Unauthorized()
+            .content_type("application/problem+json")
+            .json(json!({ "status": 401, "detail": "token expired" }))
+    }
+
+    fn app() -> App<
+        impl actix_web::dev::ServiceFactory<
+            actix_web::dev::ServiceRequest,
+            Config = (),
+            Response = actix_web::dev::ServiceResponse<impl actix_web::body::MessageBody>,
+            Error = actix_web::Error,
+            InitError = (),
+        >,
+    > {
+        App::new()
+            .wrap(from_fn(request_id_middleware))
+            .route("/context", web::get().to(context))
+            .route("/failing", web::get().to(failing))
+            .route("/problem", web::get().to(problem))
+    }
+
+    #[actix_web::test]
+    async fn accepts_and_echoes_the_request_id() {
+        let app = test::init_service(app()).await;
+
+        let req = test::TestRequest::get()
+            .uri("/context")
+            .insert_header(("X-Request-Id", "req-1"))
+            .to_request();
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(result.headers().get("X-Request-Id").unwrap(), "req-1");
+        assert_eq!(test::read_body(result).await, "req-1");
+    }
+
+    #[actix_web::test]
+    async fn generates_a_request_id_when_missing_or_invalid() {
+        let app = test::init_service(app()).await;
+
+        for request_id in [None, Some("bad id\twith spaces")] {
+            let mut req = test::TestRequest::get().uri("/context");
+            if let Some(request_id) = request_id {
+                req = req.insert_header(("X-Request-Id", request_id));
+            }
+
+            let result = test::call_service(&app, req.to_request()).await;
+            let echoed = result.headers().get("X-Request-Id").unwrap().clone();
+            let body = test::read_body(result).await;
+
+            assert_eq!(echoed.len(), 32);
+            assert_eq!(echoed.as_bytes(), body);
+        }
+    }
+
+    #[actix_web::test]
+    async fn error_bodies_include_the_request_id() {
+        let app = test::init_service(app()).await;
+
+        let req = test::TestRequest::get()
+            .uri("/failing")
+            .insert_header(("X-Request-Id", "req-1"))
+            .to_request();
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body: Value = test::read_body_json(result).await;
+
+        assert_eq!(
+            body,
+            json!({
+                "error": "An internal error occurred for the user: Database error",
+                "request_id": "req-1",
+            })
+        );
+    }
+
+    #[actix_web::test]
+    async fn problem_details_keep_their_shape() {
+        let app = test::init_service(app()).await;
+
+        let req = test::TestRequest::get()
+            .uri("/problem")
+            .insert_header(("X-Request-Id", "req-1"))
+            .to_request();
+        let result = test::call_service(&app, req).await;
+
+        assert_eq!(
+            result.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+
+        let body: Value = test::read_body_json(result).await;
+
+        assert_eq!(body["detail"], "token expired");
+        assert_eq!(body["request_id"], "req-1");
+    }
+
+    #[actix_web::test]
+    async fn unmatched_routes_get_an_error_body() {
+        let app = test::init_service(app()).await;
+
+        let req = test::TestRequest::get()
+            .uri("/nope")
+            .insert_header(("X-Request-Id", "req-1"))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(body, json!({ "error": "Not Found", "request_id": "req-1" }));
+    }
+}
@@ -0,0 +1,101 @@
+use actix_web::{
+    Error,
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::HeaderMap,
+    middleware::Next,
+};
+use opentelemetry::{
+    global,
+    propagation::Extractor,
+    trace::{Status, TraceContextExt},
+};
+use tracing::{Instrument, field::Empty, info_span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::domain::services::pii_redactor;
+
+struct RequestHeaders<'a>(&'a HeaderMap);
+
+impl Extractor for RequestHeaders<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+pub async fn tracing_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let parent = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&RequestHeaders(req.headers()))
+    });
+
+    let span = info_span!(
+        "http_request",
+        otel.name = format!("{} {}", req.method(), req.match_pattern().unwrap_or_default()),
+        otel.kind = "server",
+        http.request.method = %req.method(),
+        http.route = req.match_pattern(),
+        url.path = pii_redactor::redact_text(req.path()),
+        http.response.status_code = Empty,
+        request_id = Empty,
+        trace_id = Empty,
+    );
+
+    let _ = span.set_parent(parent);
+
+    let span_context = span.context().span().span_context().clone();
+    if span_context.is_valid() {
+        span.record("trace_id", span_context.trace_id().to_string());
+    }
+
+    let result = next.call(req).instrument(span.clone()).await;
+
+    let status = match &result {
+        Ok(res) => res.status(),
+        Err(err) => err.as_response_error().status_code(),
+    };
+
+    span.record("http.response.status_code", status.as_u16());
+    if status.is_server_error() {
+        span.set_status(Status::error(status.to_string()));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::{http::header::HeaderValue, test::TestRequest};
+    use opentelemetry::{
+        propagation::TextMapPropagator,
+        trace::{TraceContextExt, TraceId},
+    };
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+
+    use super::RequestHeaders;
+
+    #[test]
+    fn extracts_the_w3c_trace_context() {
+        let req = TestRequest::default()
+            .insert_header((
+                "traceparent",
+                HeaderValue::from_static("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            ))
+            .to_srv_request();
+
+        let context = TraceContextPropagator::new().extract(&RequestHeaders(req.headers()));
+        let span = context.span();
+
+        assert_eq!(
+            span.span_context().trace_id(),
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap()
+        );
+        assert!(span.span_context().is_remote());
+    }
+}
@@ -1,4 +1,10 @@
+pub mod cli;
 pub mod dtos;
 pub mod errors;
+pub mod extractors;
+pub mod graphql;
+pub mod grpc;
 pub mod handlers;
+pub mod middlewares;
+pub mod openapi;
 pub mod routes;
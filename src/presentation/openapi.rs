@@ -0,0 +1,102 @@
+use utoipa::{
+    Modify, OpenApi,
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+};
+
+use crate::presentation::{
+    dtos::error_dto::{ErrorDTO, ProblemDTO},
+    handlers::user_handler,
+};
+
+struct SecuritySchemes;
+
+impl Modify for SecuritySchemes {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-Api-Key"))),
+        );
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    user_handler::register_user_handler,
+    user_handler::import_users_handler,
+    user_handler::list_users_handler,
+    user_handler::search_users_handler,
+    user_handler::export_users_handler,
+    user_handler::find_duplicate_users_handler,
+    user_handler::get_by_email,
+    user_handler::export_user_data_handler,
+    user_handler::erase_user_handler,
+    user_handler::merge_users_handler,
+    user_handler::get_user_history_handler,
+    user_handler::request_email_change_handler,
+    user_handler::confirm_email_change_handler,
+))]
+struct UserApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "User service",
+        description = "Register, look up and manage users. Every error body carries the request id."
+    ),
+    nest((path = "/api/v1/users", api = UserApi)),
+    components(schemas(ErrorDTO, ProblemDTO)),
+    modifiers(&SecuritySchemes),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    tags((name = "users", description = "User management"))
+)]
+pub struct ApiDoc;
+
+#[cfg(test)]
+mod test {
+    use utoipa::OpenApi;
+
+    use super::ApiDoc;
+
+    const SNAPSHOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/openapi.json");
+
+    #[test]
+    fn spec_matches_the_committed_snapshot() -> Result<(), Box<dyn std::error::Error>> {
+        let spec = ApiDoc::openapi().to_pretty_json()? + "\n";
+
+        if std::env::var_os("UPDATE_OPENAPI_SNAPSHOT").is_some() {
+            std::fs::write(SNAPSHOT, &spec)?;
+        }
+
+        let snapshot = std::fs::read_to_string(SNAPSHOT).unwrap_or_default();
+
+        assert!(
+            snapshot == spec,
+            "The OpenAPI spec drifted from docs/openapi.json, \
+             rerun with UPDATE_OPENAPI_SNAPSHOT=1 and commit the result"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn spec_is_openapi_3_1_with_every_user_route() -> Result<(), Box<dyn std::error::Error>> {
+        let spec = ApiDoc::openapi();
+
+        assert_eq!(serde_json::to_value(&spec)?["openapi"], "3.1.0");
+        assert_eq!(spec.paths.paths.len(), 12);
+        assert!(spec.paths.paths.contains_key("/api/v1/users/{id}/merge"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::presentation::{
+    handlers::api_key_handler::{
+        create_api_key_handler, list_api_keys_handler, revoke_api_key_handler,
+    },
+    middlewares::auth_middleware::auth_middleware,
+};
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/api/v1/api-keys")
+            .wrap(from_fn(auth_middleware))
+            .service(create_api_key_handler)
+            .service(list_api_keys_handler)
+            .service(revoke_api_key_handler),
+    );
+}
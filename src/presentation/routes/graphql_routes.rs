@@ -0,0 +1,16 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::presentation::{
+    handlers::graphql_handler::{graphiql_handler, graphql_handler},
+    middlewares::auth_middleware::auth_middleware,
+};
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config
+        .service(
+            web::scope("/graphql")
+                .wrap(from_fn(auth_middleware))
+                .service(graphql_handler),
+        )
+        .service(graphiql_handler);
+}
@@ -0,0 +1,11 @@
+use actix_web::web;
+
+use crate::presentation::handlers::health_handler::{liveness_handler, readiness_handler};
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/health")
+            .service(liveness_handler)
+            .service(readiness_handler),
+    );
+}
@@ -0,0 +1,7 @@
+use actix_web::web;
+
+use crate::presentation::handlers::metrics_handler::metrics_handler;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(metrics_handler);
+}
@@ -1 +1,6 @@
+pub mod api_key_routes;
+pub mod graphql_routes;
+pub mod health_routes;
+pub mod metrics_routes;
+pub mod openapi_routes;
 pub mod user_routes;
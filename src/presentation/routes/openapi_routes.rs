@@ -0,0 +1,9 @@
+use actix_web::web;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::presentation::openapi::ApiDoc;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+    config.service(SwaggerUi::new("/api/docs/{_:.*}").url("/api/openapi.json", ApiDoc::openapi()));
+}
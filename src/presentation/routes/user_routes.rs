@@ -1,11 +1,31 @@
-use actix_web::web;
+use actix_web::{middleware::from_fn, web};
 
-use crate::presentation::handlers::user_handler::{get_by_email, register_user_handler};
+use crate::presentation::{
+    handlers::user_handler::{
+        confirm_email_change_handler, erase_user_handler, export_user_data_handler,
+        export_users_handler, find_duplicate_users_handler, get_by_email, get_user_history_handler,
+        import_users_handler, list_users_handler, merge_users_handler, register_user_handler,
+        request_email_change_handler, search_users_handler,
+    },
+    middlewares::auth_middleware::auth_middleware,
+};
 
 pub fn routes(config: &mut web::ServiceConfig) {
     config.service(
         web::scope("/api/v1/users")
+            .wrap(from_fn(auth_middleware))
             .service(register_user_handler)
-            .service(get_by_email),
+            .service(import_users_handler)
+            .service(list_users_handler)
+            .service(search_users_handler)
+            .service(export_users_handler)
+            .service(find_duplicate_users_handler)
+            .service(get_by_email)
+            .service(export_user_data_handler)
+            .service(erase_user_handler)
+            .service(merge_users_handler)
+            .service(get_user_history_handler)
+            .service(request_email_change_handler)
+            .service(confirm_email_change_handler),
     );
 }
@@ -1,5 +1,61 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    api_keys (id) {
+        id -> Int4,
+        name -> Varchar,
+        key_prefix -> Varchar,
+        key_hash -> Varchar,
+        scopes -> Array<Text>,
+        created_at -> Timestamptz,
+        last_used_at -> Nullable<Timestamptz>,
+        revoked_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    pending_email_changes (id) {
+        id -> Int4,
+        user_id -> Int4,
+        new_email -> Varchar,
+        token_hash -> Varchar,
+        expires_at -> Timestamptz,
+        created_at -> Timestamptz,
+        confirmed_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    user_audit_log (id) {
+        id -> Int4,
+        user_id -> Int4,
+        actor -> Varchar,
+        action -> Varchar,
+        request_id -> Nullable<Varchar>,
+        changes -> Jsonb,
+        recorded_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    user_erasures (id) {
+        id -> Int4,
+        user_id -> Int4,
+        requested_by -> Varchar,
+        erased_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    user_merges (id) {
+        id -> Int4,
+        survivor_id -> Int4,
+        merged_id -> Int4,
+        requested_by -> Varchar,
+        merged_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     users (id) {
         id -> Int4,
@@ -7,5 +63,22 @@ diesel::table! {
         email -> Varchar,
         phone -> Varchar,
         address -> Varchar,
+        email_bidx -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        suspended_at -> Nullable<Timestamptz>,
     }
 }
+
+diesel::joinable!(pending_email_changes -> users (user_id));
+diesel::joinable!(user_audit_log -> users (user_id));
+diesel::joinable!(user_erasures -> users (user_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    api_keys,
+    pending_email_changes,
+    user_audit_log,
+    user_erasures,
+    user_merges,
+    users,
+);